@@ -23,13 +23,13 @@ fi
 (echo "Checking documentation..." && cargo doc --workspace --no-deps --document-private-items) || exit 1
 
 # Lint
-(echo "Linting code..." && cargo clippy --all-targets --all-features -- -D warnings) || exit 1
+(echo "Linting code..." && cargo clippy --workspace --all-targets --all-features -- -D warnings) || exit 1
 
 # Build
-(echo "Building code..." && cargo build --tests) || exit 1
+(echo "Building code..." && cargo build --workspace --tests) || exit 1
 
 # Run tests
-(echo "Running tests..." && cargo test --all-targets --all-features) || exit 1
+(echo "Running tests..." && cargo test --workspace --all-targets --all-features) || exit 1
 
 # Run doc-tests
 (echo "Running doc-tests..." && cargo test --all-features --doc) || exit 1
@@ -11,6 +11,8 @@
 //! - **Bring Your Own Client:** Integrate an existing [`reqwest::Client`] or a pre-configured [`reqwest_middleware::ClientWithMiddleware`].
 //! - **Middleware Ready:** Leverage [`reqwest-middleware`] for retries, logging, and more.
 //! - **Ergonomic Request Building:** Provides methods for interacting with GLEIF API endpoints using [`crate::request_builder::GleifRequestBuilder`].
+//! - **Golden Copy Downloads:** List and download Golden Copy publications with `GleifClient::latest_golden_copy` and `GleifClient::download_golden_copy` (requires the `golden-copy` feature), from a configurable [`crate::client::GleifClientBuilder::golden_copy_url`].
+//! - **Hypermedia Navigation:** Dereference links returned in responses with [`crate::client::GleifClient::follow`] and [`crate::client::GleifClient::fetch_url`].
 //!
 //! Below are various ways to create and configure your [`GleifClient`].
 //!
@@ -132,10 +134,15 @@
 //! All methods return [`crate::error::Result`]. See the [`crate::error`] module for details.
 
 use crate::{
-    DEFAULT_BASE_URL, error::GleifError, error::Result, request_builder::GleifRequestBuilder,
+    DEFAULT_BASE_URL, DEFAULT_GOLDEN_COPY_URL,
+    error::{GleifError, ParseErrorKind, Result},
+    field::FieldCatalog,
+    model::common::RelatedLink,
+    request_builder::GleifRequestBuilder,
 };
 use reqwest::{Client as ReqwestClient, Method};
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
+use serde::de::DeserializeOwned;
 use std::sync::Arc;
 use url::Url;
 
@@ -146,6 +153,8 @@ use url::Url;
 pub struct GleifClient {
     client: Arc<ClientWithMiddleware>,
     base_url: Url,
+    golden_copy_url: Url,
+    field_catalog: Option<Arc<FieldCatalog>>,
 }
 
 impl Default for GleifClient {
@@ -179,12 +188,15 @@ impl GleifClient {
     ///
     /// # Panics
     ///
-    /// Panics if the default base URL is invalid. This should never happen unless the constant is changed to an invalid value.
+    /// Panics if the default base URLs are invalid. This should never happen unless the constants are changed to invalid values.
     #[must_use]
     pub fn from_middleware_client(client: ClientWithMiddleware) -> Self {
         Self {
             client: Arc::new(client),
             base_url: Url::parse(DEFAULT_BASE_URL).expect("Default base URL should be valid"),
+            golden_copy_url: Url::parse(DEFAULT_GOLDEN_COPY_URL)
+                .expect("Default Golden Copy URL should be valid"),
+            field_catalog: None,
         }
     }
 
@@ -201,16 +213,140 @@ impl GleifClient {
         &self.base_url
     }
 
+    /// Returns the base URL for the Golden Copy publication API.
+    #[must_use]
+    pub fn golden_copy_url(&self) -> &Url {
+        &self.golden_copy_url
+    }
+
     /// Returns a reference to the underlying `reqwest_middleware` client.
     #[must_use]
     pub fn client(&self) -> &Arc<ClientWithMiddleware> {
         &self.client
     }
 
+    /// Returns the field catalogue `lei-records` queries are validated against before they are sent, if any.
+    #[must_use]
+    pub fn field_catalog(&self) -> Option<&FieldCatalog> {
+        self.field_catalog.as_deref()
+    }
+
+    /// Returns a copy of this client that validates every query of the `lei-records` collection against
+    /// the given field catalogue before sending it (see
+    /// [`crate::request_builder::GleifRequestBuilder::validate_with`]). Other endpoints have fields of
+    /// their own and are not validated.
+    ///
+    /// The catalogue is typically loaded with [`GleifClient::load_field_catalog`].
+    #[must_use]
+    pub fn with_field_catalog(mut self, catalog: FieldCatalog) -> Self {
+        self.field_catalog = Some(Arc::new(catalog));
+        self
+    }
+
     /// Returns a request builder for the given endpoint path.
     pub(crate) fn request(&self, path: &str) -> GleifRequestBuilder {
         GleifRequestBuilder::new(self.clone(), Method::GET, path)
     }
+
+    /// Fetches an absolute API URL, such as a link returned in a response, and deserializes the target.
+    ///
+    /// The URL must point under the configured base URL. URLs under the public GLEIF API
+    /// ([`DEFAULT_BASE_URL`]) are rewritten to the configured base URL, so that links returned by a mirror
+    /// or proxy serving the same data are fetched through it. Query parameters of the URL (e.g. pagination)
+    /// are preserved, and the request goes through the configured middleware.
+    ///
+    /// # Parameters
+    ///
+    /// - `url` - The absolute URL to fetch.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`crate::error::GleifError`] in the following cases:
+    /// - The URL cannot be parsed or does not point under the configured base URL.
+    /// - The request could not be completed due to network or server issues.
+    /// - The response body could not be deserialized into the expected type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let next: LeiRecordList = client.fetch_url(&list.links.unwrap().next.unwrap()).await?;
+    /// ```
+    pub async fn fetch_url<R>(&self, url: &str) -> Result<R>
+    where
+        R: DeserializeOwned,
+    {
+        self.url_request(url)?.send().await
+    }
+
+    /// Follows a [`RelatedLink`] returned in a response, fetching and deserializing its target.
+    ///
+    /// The primary URL of the link is used (see [`RelatedLink::url`]); fetching follows the rules of
+    /// [`GleifClient::fetch_url`].
+    ///
+    /// # Parameters
+    ///
+    /// - `link` - The link to follow.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`crate::error::GleifError`] in the following cases:
+    /// - The link carries no URL, or its URL does not point under the configured base URL.
+    /// - The request could not be completed due to network or server issues.
+    /// - The response body could not be deserialized into the expected type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let record: LeiRecord = client.lei_record_by_id("5493001KJTIIGC8Y1R12").await?;
+    /// if let Some(parent) = &record.data.relationships.direct_parent {
+    ///     let parent: LeiRecord = client.follow(&parent.links).await?;
+    /// }
+    /// ```
+    pub async fn follow<R>(&self, link: &RelatedLink) -> Result<R>
+    where
+        R: DeserializeOwned,
+    {
+        let url = link.url().ok_or_else(|| GleifError::ParseError {
+            kind: ParseErrorKind::Value,
+            message: "Related link does not contain a URL".to_string(),
+        })?;
+        self.fetch_url(url).await
+    }
+
+    /// Private helper building a request for an absolute URL under the configured or default base URL.
+    fn url_request(&self, url: &str) -> Result<GleifRequestBuilder> {
+        let mut url = Url::parse(url)?;
+        let query: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        url.set_query(None);
+        url.set_fragment(None);
+
+        let default_base = Url::parse(DEFAULT_BASE_URL)?;
+        let path = [&self.base_url, &default_base]
+            .into_iter()
+            .find_map(|base| {
+                let base = base.as_str();
+                let rest = url.as_str().strip_prefix(base)?;
+                // Only accept a match on a whole path segment.
+                (base.ends_with('/') || rest.is_empty() || rest.starts_with('/'))
+                    .then(|| rest.trim_start_matches('/').to_string())
+            })
+            .ok_or_else(|| GleifError::ParseError {
+                kind: ParseErrorKind::Value,
+                message: format!(
+                    "URL {url} is not under the configured base URL {}",
+                    self.base_url
+                ),
+            })?;
+
+        Ok(query
+            .iter()
+            .fold(self.request(&path), |request, (key, value)| {
+                request.param(key, value)
+            }))
+    }
 }
 
 /// Builder for configuring and constructing a [`GleifClient`].
@@ -221,6 +357,8 @@ pub struct GleifClientBuilder {
     middleware_builder: Option<ClientBuilder>,
     reqwest_client: Option<ReqwestClient>,
     base_url: String,
+    golden_copy_url: String,
+    field_catalog: Option<FieldCatalog>,
 }
 
 impl Default for GleifClientBuilder {
@@ -230,6 +368,8 @@ impl Default for GleifClientBuilder {
             middleware_builder: None,
             reqwest_client: None,
             base_url: String::from(DEFAULT_BASE_URL),
+            golden_copy_url: String::from(DEFAULT_GOLDEN_COPY_URL),
+            field_catalog: None,
         }
     }
 }
@@ -248,6 +388,16 @@ impl GleifClientBuilder {
         self
     }
 
+    /// Set a custom base URL for the Golden Copy publication API (replacing the default).
+    ///
+    /// See the `golden_copy` module (requires the `golden-copy` feature) for downloading Golden Copy
+    /// files.
+    #[must_use]
+    pub fn golden_copy_url(mut self, url: impl Into<String>) -> Self {
+        self.golden_copy_url = url.into();
+        self
+    }
+
     /// Use a custom reqwest client for the underlying HTTP operations.
     #[must_use]
     pub fn reqwest_client(mut self, client: ReqwestClient) -> Self {
@@ -264,13 +414,22 @@ impl GleifClientBuilder {
         self
     }
 
+    /// Validate every `lei-records` query against the given field catalogue before sending it.
+    ///
+    /// See [`GleifClient::with_field_catalog`].
+    #[must_use]
+    pub fn field_catalog(mut self, catalog: FieldCatalog) -> Self {
+        self.field_catalog = Some(catalog);
+        self
+    }
+
     /// Build the [`GleifClient`] with the configured settings.
     ///
     /// Consumes the builder to prevent accidental reuse.
     ///
     /// # Errors
     ///
-    /// Returns a [`GleifError`] if the base URL is invalid or the client cannot be constructed.
+    /// Returns a [`GleifError`] if a base URL is invalid or the client cannot be constructed.
     pub fn build(self) -> Result<GleifClient> {
         // Use the provided reqwest client or create a new one if not provided.
         let reqwest_client = self.reqwest_client.unwrap_or_default();
@@ -284,10 +443,14 @@ impl GleifClientBuilder {
 
         // Parse the base URL. If invalid, return an error.
         let base_url = Url::parse(&self.base_url).map_err(GleifError::UrlParseError)?;
+        let golden_copy_url =
+            Url::parse(&self.golden_copy_url).map_err(GleifError::UrlParseError)?;
 
         Ok(GleifClient {
             client: Arc::new(client),
             base_url,
+            golden_copy_url,
+            field_catalog: self.field_catalog.map(Arc::new),
         })
     }
 }
@@ -330,6 +493,85 @@ mod tests {
             .build()
             .expect("Client build should succeed");
         assert_eq!(client.base_url().as_str(), custom_url);
+
+        let client = GleifClient::builder()
+            .golden_copy_url("http://localhost:8080/api/v2/")
+            .build()
+            .expect("Client build should succeed");
+        assert_eq!(client.base_url().as_str(), DEFAULT_BASE_URL);
+        assert_eq!(
+            client.golden_copy_url().as_str(),
+            "http://localhost:8080/api/v2/"
+        );
+        assert!(
+            GleifClient::builder()
+                .golden_copy_url("not a url")
+                .build()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_url_request() {
+        let client = GleifClient::new();
+        let request = client
+            .url_request(
+                "https://api.gleif.org/api/v1/lei-records/5493001KJTIIGC8Y1R12/direct-parent",
+            )
+            .expect("URL under the base URL should be accepted");
+        assert_eq!(
+            request.get_path(),
+            "lei-records/5493001KJTIIGC8Y1R12/direct-parent"
+        );
+
+        let request = client
+            .url_request(
+                "https://api.gleif.org/api/v1/lei-records?page%5Bnumber%5D=2&page%5Bsize%5D=10",
+            )
+            .expect("URL with query should be accepted");
+        assert_eq!(request.get_path(), "lei-records");
+        assert_eq!(request.get_query().get("page[number]").unwrap(), "2");
+        assert_eq!(request.get_query().get("page[size]").unwrap(), "10");
+
+        assert!(
+            client
+                .url_request("https://example.com/api/v1/lei-records")
+                .is_err()
+        );
+        assert!(client.url_request("not a url").is_err());
+    }
+
+    #[test]
+    fn test_url_request_rewrites_default_base_url_for_mirrors() {
+        let client = GleifClient::builder()
+            .base_url("https://mirror.example.com/gleif/")
+            .build()
+            .expect("Client build should succeed");
+        let request = client
+            .url_request("https://api.gleif.org/api/v1/lei-records/5493001KJTIIGC8Y1R12")
+            .expect("Default base URL should be rewritten");
+        assert_eq!(request.get_path(), "lei-records/5493001KJTIIGC8Y1R12");
+        let request = client
+            .url_request("https://mirror.example.com/gleif/lei-records/5493001KJTIIGC8Y1R12")
+            .expect("Mirror URL should be accepted");
+        assert_eq!(request.get_path(), "lei-records/5493001KJTIIGC8Y1R12");
+        assert!(
+            client
+                .url_request("https://mirror.example.com/other/lei-records")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_related_link_url() {
+        let link = RelatedLink {
+            reporting_exception: None,
+            relationship_record: Some("https://api.gleif.org/api/v1/relationship".to_string()),
+            relationship_records: None,
+            related: Some("https://api.gleif.org/api/v1/related".to_string()),
+            lei_record: None,
+        };
+        assert_eq!(link.url(), Some("https://api.gleif.org/api/v1/related"));
     }
 
     #[test]
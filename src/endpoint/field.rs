@@ -35,7 +35,9 @@
 //! Errors may occur during network communication, server-side issues, or deserialization of the response.
 //! These errors are returned as [`crate::error::GleifError`] instances. All methods in this module are asynchronous and return a `Result` type, making them suitable for use in async Rust applications.
 
-use crate::{client::GleifClient, error::Result, request_builder::GleifRequestBuilder};
+use crate::{
+    client::GleifClient, error::Result, field::FieldCatalog, request_builder::GleifRequestBuilder,
+};
 use serde::de::DeserializeOwned;
 
 impl GleifClient {
@@ -86,4 +88,33 @@ impl GleifClient {
     {
         self.request(&format!("fields/{id}")).send().await
     }
+
+    /// Loads the complete field catalogue (`/fields`), following pagination.
+    ///
+    /// The returned [`FieldCatalog`] can be attached to a client with [`GleifClient::with_field_catalog`]
+    /// so that requests are validated against the live field metadata before they are sent.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`crate::error::GleifError`] in the following cases:
+    /// - The request could not be completed due to network or server issues.
+    /// - The response body could not be deserialized into a [`crate::model::FieldList`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let catalog = client.load_field_catalog().await?;
+    /// let client = client.with_field_catalog(catalog);
+    /// ```
+    pub async fn load_field_catalog(&self) -> Result<FieldCatalog> {
+        let fields = self
+            .fields()
+            .page_size(FIELD_CATALOG_PAGE_SIZE)
+            .send_all()
+            .await?;
+        Ok(FieldCatalog::new(fields))
+    }
 }
+
+/// Page size used when loading the field catalogue.
+const FIELD_CATALOG_PAGE_SIZE: usize = 200;
@@ -18,6 +18,12 @@
 //! - `/lei-records/{lei}/direct-children`: Fetches LEI records for the direct children of a specific entity.
 //! - `/lei-records/{lei}/associated-entity`: Fetches the LEI record for the manager of a specific legal entity (fund).
 //! - `/lei-records/{lei}/successor-entity`: Fetches the LEI record for the successor entity of a specific legal entity.
+//! - `/lei-records/{lei}/successor-entities`: Fetches LEI records for all successor entities of a specific legal entity.
+//! - `/lei-records/{lei}/head-office`: Fetches the LEI record for the head office of a specific branch.
+//! - `/lei-records/{lei}/branches`: Fetches LEI records for the international branches of a specific entity.
+//! - `/lei-records/{lei}/fund-manager`: Fetches the LEI record for the fund manager of a specific fund.
+//! - `/lei-records/{lei}/umbrella-fund`: Fetches the LEI record for the umbrella fund of a specific sub-fund.
+//! - `/lei-records/{lei}/managed-funds`: Fetches LEI records for the funds managed by a specific fund manager.
 //! - `/lei-records/{lei}/managing-lou`: Fetches the LEI record for the managing Local Operating Unit (LOU) of a specific entity.
 //!
 //! ## Examples
@@ -39,6 +45,10 @@
 //! // Fetching parent and child relationships
 //! let parent: LeiRecord = client.ultimate_parent("5493000IBP32UQZ0KL24").await?;
 //! let children: LeiRecordList = client.direct_children("5493000IBP32UQZ0KL24").send().await?;
+//!
+//! // Fetching branches and managed funds (paginated)
+//! let branches: LeiRecordList = client.branches("5493000IBP32UQZ0KL24").page_size(50).send().await?;
+//! let funds: LeiRecordList = client.managed_funds("5493000IBP32UQZ0KL24").send().await?;
 //! # Ok(()) }
 //! ```
 //!
@@ -281,6 +291,175 @@ impl GleifClient {
             .await
     }
 
+    /// Retrieves LEI (Legal Entity Identifier) records for all successor entities of a specific legal entity.
+    ///
+    /// This method sends a request to the `/lei-records/{lei}/successor-entities` endpoint to fetch LEI records
+    /// of the entities that continue or replace the registration of the given entity, e.g. after a demerger
+    /// into several successors. Use [`GleifClient::successor_entity`] when only a single successor is reported.
+    ///
+    /// # Parameters
+    ///
+    /// - `lei` - A string slice representing the LEI identifier.
+    ///
+    /// # Errors
+    ///
+    /// This method does not itself return errors. However, errors may occur when sending the request or processing
+    /// the response using the returned request builder (e.g., network failures or deserialization issues).
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let records: LeiRecordList = client.successor_entities("5493000IBP32UQZ0KL24").send().await?; // strongly typed
+    /// let records: serde_json::Value = client.successor_entities("5493000IBP32UQZ0KL24").send().await?; // raw JSON
+    /// ```
+    #[must_use]
+    pub fn successor_entities(&self, lei: &str) -> GleifRequestBuilder {
+        self.request(&format!("lei-records/{lei}/successor-entities"))
+    }
+
+    /// Retrieves the LEI (Legal Entity Identifier) record for the head office of a specific branch.
+    ///
+    /// This method sends a request to the `/lei-records/{lei}/head-office` endpoint to fetch the LEI record
+    /// of the entity of which the given branch is an international branch. If the given entity is not a branch,
+    /// the request will return a resource not found response.
+    ///
+    /// # Parameters
+    ///
+    /// - `lei` - A string slice representing the LEI identifier.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`crate::error::GleifError`] in the following cases:
+    /// - The request could not be completed due to network or server issues.
+    /// - The response body could not be deserialized into the expected type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let record: LeiRecord = client.head_office("5493000IBP32UQZ0KL24").await?; // strongly typed
+    /// let record: serde_json::Value = client.head_office("5493000IBP32UQZ0KL24").await?; // raw JSON
+    /// ```
+    pub async fn head_office<R>(&self, lei: &str) -> Result<R>
+    where
+        R: DeserializeOwned,
+    {
+        self.request(&format!("lei-records/{lei}/head-office"))
+            .send()
+            .await
+    }
+
+    /// Retrieves LEI (Legal Entity Identifier) records for the international branches of a specific entity.
+    ///
+    /// This method sends a request to the `/lei-records/{lei}/branches` endpoint to fetch LEI records
+    /// of the branches reporting the given entity as their head office.
+    ///
+    /// # Parameters
+    ///
+    /// - `lei` - A string slice representing the LEI identifier.
+    ///
+    /// # Errors
+    ///
+    /// This method does not itself return errors. However, errors may occur when sending the request or processing
+    /// the response using the returned request builder (e.g., network failures or deserialization issues).
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let records: LeiRecordList = client.branches("5493000IBP32UQZ0KL24").send().await?; // strongly typed
+    /// let records: serde_json::Value = client.branches("5493000IBP32UQZ0KL24").send().await?; // raw JSON
+    /// ```
+    #[must_use]
+    pub fn branches(&self, lei: &str) -> GleifRequestBuilder {
+        self.request(&format!("lei-records/{lei}/branches"))
+    }
+
+    /// Retrieves the LEI (Legal Entity Identifier) record for the fund manager of a specific fund.
+    ///
+    /// This method sends a request to the `/lei-records/{lei}/fund-manager` endpoint to fetch the LEI record
+    /// of the entity reported as managing the given fund. If no fund manager is reported, the request will
+    /// return a resource not found response.
+    ///
+    /// # Parameters
+    ///
+    /// - `lei` - A string slice representing the LEI identifier.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`crate::error::GleifError`] in the following cases:
+    /// - The request could not be completed due to network or server issues.
+    /// - The response body could not be deserialized into the expected type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let record: LeiRecord = client.fund_manager("5493000IBP32UQZ0KL24").await?; // strongly typed
+    /// let record: serde_json::Value = client.fund_manager("5493000IBP32UQZ0KL24").await?; // raw JSON
+    /// ```
+    pub async fn fund_manager<R>(&self, lei: &str) -> Result<R>
+    where
+        R: DeserializeOwned,
+    {
+        self.request(&format!("lei-records/{lei}/fund-manager"))
+            .send()
+            .await
+    }
+
+    /// Retrieves the LEI (Legal Entity Identifier) record for the umbrella fund of a specific sub-fund.
+    ///
+    /// This method sends a request to the `/lei-records/{lei}/umbrella-fund` endpoint to fetch the LEI record
+    /// of the umbrella fund under whose legal responsibility the given sub-fund is set up. If the given
+    /// entity is not a sub-fund, the request will return a resource not found response.
+    ///
+    /// # Parameters
+    ///
+    /// - `lei` - A string slice representing the LEI identifier.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`crate::error::GleifError`] in the following cases:
+    /// - The request could not be completed due to network or server issues.
+    /// - The response body could not be deserialized into the expected type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let record: LeiRecord = client.umbrella_fund("5493000IBP32UQZ0KL24").await?; // strongly typed
+    /// let record: serde_json::Value = client.umbrella_fund("5493000IBP32UQZ0KL24").await?; // raw JSON
+    /// ```
+    pub async fn umbrella_fund<R>(&self, lei: &str) -> Result<R>
+    where
+        R: DeserializeOwned,
+    {
+        self.request(&format!("lei-records/{lei}/umbrella-fund"))
+            .send()
+            .await
+    }
+
+    /// Retrieves LEI (Legal Entity Identifier) records for the funds managed by a specific fund manager.
+    ///
+    /// This method sends a request to the `/lei-records/{lei}/managed-funds` endpoint to fetch LEI records
+    /// of the funds reporting the given entity as their fund manager.
+    ///
+    /// # Parameters
+    ///
+    /// - `lei` - A string slice representing the LEI identifier.
+    ///
+    /// # Errors
+    ///
+    /// This method does not itself return errors. However, errors may occur when sending the request or processing
+    /// the response using the returned request builder (e.g., network failures or deserialization issues).
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let records: LeiRecordList = client.managed_funds("5493000IBP32UQZ0KL24").send().await?; // strongly typed
+    /// let records: serde_json::Value = client.managed_funds("5493000IBP32UQZ0KL24").send().await?; // raw JSON
+    /// ```
+    #[must_use]
+    pub fn managed_funds(&self, lei: &str) -> GleifRequestBuilder {
+        self.request(&format!("lei-records/{lei}/managed-funds"))
+    }
+
     /// Retrieves the LEI (Legal Entity Identifier) record for the managing Local Operating Unit (LOU) of a specific entity.
     ///
     /// This method sends a request to the `/lei-records/{lei}/managing-lou` endpoint to fetch the LEI record
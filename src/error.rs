@@ -21,6 +21,9 @@
 //!       structure, or if there's an error parsing it (typically wrapped in [`serde_json::Error`]).
 //!     - **Middleware Errors:** Errors originating from the [`reqwest-middleware`](https://docs.rs/reqwest-middleware/) stack,
 //!       such as retry policies being exhausted.
+//! - **Bulk File Errors:** Malformed Golden Copy files, reported as `quick_xml` or `csv` errors for
+//!   broken XML or CSV, or as parse errors for missing elements and invalid values (with the
+//!   `golden-copy` feature).
 //! - **Request Building Errors:** Issues that occur before a request is sent, such as
 //!   invalid URL formation or header construction problems.
 //!
@@ -71,7 +74,10 @@ use url::Url;
 pub type Result<T> = std::result::Result<T, GleifError>;
 
 /// Errors that can occur when using the GLEIF API client.
+///
+/// Cargo features add variants for the errors of their dependencies, so the enum is non-exhaustive.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum GleifError {
     /// Error from reqwest directly
     ReqwestError(reqwest::Error),
@@ -88,9 +94,35 @@ pub enum GleifError {
     /// Error from `std::io`
     IoError(std::io::Error),
 
+    /// Error from `quick_xml` while reading a Golden Copy XML file
+    #[cfg(feature = "golden-copy")]
+    XmlError(quick_xml::Error),
+
+    /// Error from `csv` while reading a Golden Copy CSV file or writing an export
+    #[cfg(feature = "csv")]
+    CsvError(csv::Error),
+
+    /// Error from `arrow` while building a record batch
+    #[cfg(feature = "arrow")]
+    ArrowError(arrow_schema::ArrowError),
+
+    /// Error from `parquet` while writing a Parquet file
+    #[cfg(feature = "arrow")]
+    ParquetError(parquet::errors::ParquetError),
+
     /// Error with attached response content
     ResponseError(ResponseContent),
 
+    /// Error when a downloaded file does not have its published size
+    SizeMismatchError {
+        /// The URL the file was downloaded from.
+        url: String,
+        /// The published size in bytes.
+        expected: u64,
+        /// The number of bytes downloaded.
+        actual: u64,
+    },
+
     /// Error when parsing a field or value fails
     ParseError {
         /// The kind of parse error.
@@ -126,7 +158,23 @@ impl fmt::Display for GleifError {
             GleifError::UrlParseError(e) => ("url", e.to_string()),
             GleifError::SerdeError(e) => ("serde", e.to_string()),
             GleifError::IoError(e) => ("IO", e.to_string()),
+            #[cfg(feature = "golden-copy")]
+            GleifError::XmlError(e) => ("xml", e.to_string()),
+            #[cfg(feature = "csv")]
+            GleifError::CsvError(e) => ("csv", e.to_string()),
+            #[cfg(feature = "arrow")]
+            GleifError::ArrowError(e) => ("arrow", e.to_string()),
+            #[cfg(feature = "arrow")]
+            GleifError::ParquetError(e) => ("parquet", e.to_string()),
             GleifError::ResponseError(e) => ("response", format!("status code {}", e.status)),
+            GleifError::SizeMismatchError {
+                url,
+                expected,
+                actual,
+            } => (
+                "download",
+                format!("downloaded {actual} bytes from {url}, expected {expected}"),
+            ),
             GleifError::ParseError { kind, message } => match kind {
                 ParseErrorKind::Field => ("field", message.to_owned()),
                 ParseErrorKind::Value => ("value", message.to_owned()),
@@ -143,8 +191,18 @@ impl error::Error for GleifError {
             GleifError::ReqwestMiddlewareError(e) => Some(e),
             GleifError::SerdeError(e) => Some(e),
             GleifError::IoError(e) => Some(e),
+            #[cfg(feature = "golden-copy")]
+            GleifError::XmlError(e) => Some(e),
+            #[cfg(feature = "csv")]
+            GleifError::CsvError(e) => Some(e),
+            #[cfg(feature = "arrow")]
+            GleifError::ArrowError(e) => Some(e),
+            #[cfg(feature = "arrow")]
+            GleifError::ParquetError(e) => Some(e),
             GleifError::UrlParseError(e) => Some(e),
-            GleifError::ParseError { .. } | GleifError::ResponseError(_) => None,
+            GleifError::ParseError { .. }
+            | GleifError::ResponseError(_)
+            | GleifError::SizeMismatchError { .. } => None,
         }
     }
 }
@@ -179,6 +237,34 @@ impl From<std::io::Error> for GleifError {
     }
 }
 
+#[cfg(feature = "golden-copy")]
+impl From<quick_xml::Error> for GleifError {
+    fn from(e: quick_xml::Error) -> Self {
+        GleifError::XmlError(e)
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for GleifError {
+    fn from(e: csv::Error) -> Self {
+        GleifError::CsvError(e)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl From<arrow_schema::ArrowError> for GleifError {
+    fn from(e: arrow_schema::ArrowError) -> Self {
+        GleifError::ArrowError(e)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl From<parquet::errors::ParquetError> for GleifError {
+    fn from(e: parquet::errors::ParquetError) -> Self {
+        GleifError::ParquetError(e)
+    }
+}
+
 impl GleifError {
     /// Returns the URL associated with this error, if available.
     ///
@@ -318,6 +404,10 @@ impl GleifError {
             GleifError::ReqwestError(inner) => inner.is_decode(),
             GleifError::ReqwestMiddlewareError(inner) => inner.is_decode(),
             GleifError::ParseError { .. } => true,
+            #[cfg(feature = "golden-copy")]
+            GleifError::XmlError(_) => true,
+            #[cfg(feature = "csv")]
+            GleifError::CsvError(_) => true,
             _ => false,
         }
     }
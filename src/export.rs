@@ -0,0 +1,58 @@
+//! # Record Export (`CsvExporter`) - Spreadsheets From Query Results
+//!
+//! This module writes LEI records to CSV files for use in spreadsheets and other tabular tools. The
+//! columns are chosen with the same [`Field`](crate::field::Field) identifiers that drive API queries
+//! and are read with [`LeiRecord::get`](crate::model::lei_record::LeiRecord::get), so a query and its export are
+//! described in the same terms. The CSV exporter requires the `csv` feature, the Arrow and Parquet
+//! conversions the `arrow` feature.
+//!
+//! ## Key Features
+//!
+//! - **Configurable Columns:** Export any selection of fields, in any order, with the field names as
+//!   the header row.
+//! - **Multi-Valued Fields:** Join the values of fields such as BICs, other names or address lines
+//!   into one cell, or spread them over numbered columns with `CsvExporter::spread`.
+//! - **Incremental Output:** Records are written as they are passed in, from an iterator with
+//!   `CsvExporter::write_all` or from the stream of a paginated query with
+//!   `CsvExporter::write_stream`, so exports of any size need bounded memory.
+//! - **RFC 4180:** Fields are quoted where needed and rows end with CRLF.
+//! - **Arrow and Parquet:** With the `arrow` feature, convert LEI records, relationship records and
+//!   ISINs into Arrow record batches with `ArrowRecord`, using a stable schema with structs for
+//!   addresses and lists for names, and write them to Parquet files with `ParquetExporter`.
+//!
+//! ## Example
+//!
+//! ```rust, ignore
+//! use gleif_rs::{
+//!     client::GleifClient, export::CsvExporter, field::Field, model::LeiRecord,
+//!     value::EntityCategory,
+//! };
+//! use std::{fs::File, io::BufWriter};
+//!
+//! let records = GleifClient::new()
+//!     .lei_records()
+//!     .filter_eq(Field::EntityCategory, EntityCategory::Fund)
+//!     .page_size(200)
+//!     .stream_all::<LeiRecord>();
+//! let file = BufWriter::new(File::create("funds.csv")?);
+//! let mut exporter = CsvExporter::new(
+//!     file,
+//!     [Field::Lei, Field::EntityLegalName, Field::EntityLegalAddressAddressLines, Field::Bic],
+//! )
+//! .spread(Field::Bic, 3);
+//! let count = exporter.write_stream(records).await?;
+//! exporter.finish()?;
+//! println!("Exported {count} funds");
+//! ```
+
+#[cfg(feature = "arrow")]
+mod columnar;
+#[cfg(feature = "csv")]
+mod delimited;
+
+#[cfg(feature = "arrow")]
+pub use columnar::{ArrowRecord, ParquetExporter};
+#[cfg(feature = "csv")]
+pub use delimited::CsvExporter;
+#[cfg(feature = "arrow")]
+pub use {arrow_array, arrow_schema, parquet};
@@ -0,0 +1,1005 @@
+//! Conversion of records into Arrow record batches and Parquet files (requires the `arrow`
+//! feature).
+
+use crate::{
+    error::Result,
+    model::{
+        isin::{Isin, IsinAttributes},
+        lei_record::{Address, Entity, LeiRecord, LeiRecordAttributes, OtherAddress, Registration},
+        relationship_record::{RelationshipRecord, RelationshipRecordAttributes},
+    },
+    value::FieldValue,
+};
+use arrow_array::{
+    ArrayRef, Float64Array, ListArray, RecordBatch, StringArray, StructArray,
+    TimestampMicrosecondArray,
+};
+use arrow_buffer::{NullBuffer, OffsetBuffer};
+use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, SchemaRef, TimeUnit};
+use chrono::{DateTime, Utc};
+use parquet::{arrow::ArrowWriter, basic::Compression, file::properties::WriterProperties};
+use serde::Serialize;
+use std::{
+    fmt,
+    fs::File,
+    io::{BufWriter, Write},
+    marker::PhantomData,
+    path::Path,
+    sync::Arc,
+};
+
+/// The time zone of all timestamp columns.
+const UTC: &str = "UTC";
+
+/// A record type with a fixed Arrow schema, convertible into [`RecordBatch`]es.
+///
+/// The schemas are part of the public API: columns are only ever added at the end, so downstream
+/// tables and queries keep working across versions. Codes are written as their API values (e.g.
+/// `ISSUED`), timestamps as microseconds in UTC, repeated elements such as names as lists, and
+/// addresses as structs. The schema of each record type is documented on its implementation.
+pub trait ArrowRecord: Sized {
+    /// Returns the schema of the record batches.
+    fn schema() -> SchemaRef;
+
+    /// Converts records into a record batch with one row per record.
+    ///
+    /// # Parameters
+    ///
+    /// - `records` - The records to convert.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch cannot be assembled.
+    fn to_record_batch(records: &[Self]) -> Result<RecordBatch>;
+}
+
+/// The schema of LEI records:
+///
+/// | Column | Type |
+/// |--------|------|
+/// | `lei` | `Utf8` |
+/// | `legal_name`, `legal_name_language`? | `Utf8` |
+/// | `other_names`, `transliterated_other_names` | `List<Struct<name, language?, type>>` |
+/// | `legal_address`, `headquarters_address` | `Struct<language?, address_lines: List<Utf8>, address_number?, address_number_within_building?, mail_routing?, city, region?, country, postal_code?>` |
+/// | `other_addresses`, `transliterated_other_addresses` | `List<Struct<type, ...address>>` |
+/// | `registration_authority_id`, `registration_authority_other`?, `registered_as`? | `Utf8` |
+/// | `jurisdiction`, `category`, `sub_category`? | `Utf8` |
+/// | `legal_form_code`, `legal_form_other`? | `Utf8` |
+/// | `associated_entity_lei`?, `associated_entity_name`?, `associated_entity_type`? | `Utf8` |
+/// | `entity_status` | `Utf8` |
+/// | `entity_creation_date`?, `entity_expiration_date`? | `Timestamp` |
+/// | `entity_expiration_reason`? | `Utf8` |
+/// | `successor_entities` | `List<Struct<lei?, name?>>` |
+/// | `legal_entity_events` | `List<Struct<group_type, type, status, effective_date, recorded_date, validation_documents, validation_reference?>>` |
+/// | `initial_registration_date`, `last_update_date` | `Timestamp` |
+/// | `registration_status` | `Utf8` |
+/// | `next_renewal_date` | `Timestamp` |
+/// | `managing_lou`, `corroboration_level` | `Utf8` |
+/// | `validation_authority_id`, `validation_authority_other`?, `validated_as`? | `Utf8` |
+/// | `other_validation_authorities` | `List<Struct<id, other?, validated_as>>` |
+/// | `bic`?, `mic`? | `List<Utf8>` |
+/// | `ocid`?, `qcc`? | `Utf8` |
+/// | `spglobal`? | `List<Utf8>` |
+/// | `conformity_flag`? | `Utf8` |
+///
+/// Columns marked `?` are nullable. The events of all event groups are listed in order, each with
+/// the `group_type` of its group. Records reporting a single `successor_entity` instead of a list
+/// have it as the only item of `successor_entities`.
+impl ArrowRecord for LeiRecordAttributes {
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            text("lei", false),
+            text("legal_name", false),
+            text("legal_name_language", true),
+            list("other_names", name_fields(), false),
+            list("transliterated_other_names", name_fields(), false),
+            structure("legal_address", address_fields(false)),
+            structure("headquarters_address", address_fields(false)),
+            list("other_addresses", address_fields(true), false),
+            list(
+                "transliterated_other_addresses",
+                address_fields(true),
+                false,
+            ),
+            text("registration_authority_id", false),
+            text("registration_authority_other", true),
+            text("registered_as", true),
+            text("jurisdiction", false),
+            text("category", false),
+            text("sub_category", true),
+            text("legal_form_code", false),
+            text("legal_form_other", true),
+            text("associated_entity_lei", true),
+            text("associated_entity_name", true),
+            text("associated_entity_type", true),
+            text("entity_status", false),
+            timestamp("entity_creation_date", true),
+            timestamp("entity_expiration_date", true),
+            text("entity_expiration_reason", true),
+            list("successor_entities", successor_fields(), false),
+            list("legal_entity_events", event_fields(), false),
+            timestamp("initial_registration_date", false),
+            timestamp("last_update_date", false),
+            text("registration_status", false),
+            timestamp("next_renewal_date", false),
+            text("managing_lou", false),
+            text("corroboration_level", false),
+            text("validation_authority_id", false),
+            text("validation_authority_other", true),
+            text("validated_as", true),
+            list(
+                "other_validation_authorities",
+                validation_authority_fields(),
+                false,
+            ),
+            text_list("bic", true),
+            text_list("mic", true),
+            text("ocid", true),
+            text("qcc", true),
+            text_list("spglobal", true),
+            text("conformity_flag", true),
+        ]))
+    }
+
+    fn to_record_batch(records: &[Self]) -> Result<RecordBatch> {
+        lei_batch(&records.iter().collect::<Vec<_>>())
+    }
+}
+
+/// The schema of [`LeiRecordAttributes`], for records returned by the API.
+impl ArrowRecord for LeiRecord {
+    fn schema() -> SchemaRef {
+        LeiRecordAttributes::schema()
+    }
+
+    fn to_record_batch(records: &[Self]) -> Result<RecordBatch> {
+        lei_batch(
+            &records
+                .iter()
+                .map(|record| &record.attributes)
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+/// The schema of relationship records:
+///
+/// | Column | Type |
+/// |--------|------|
+/// | `start_node_id`, `start_node_type`, `end_node_id`, `end_node_type` | `Utf8` |
+/// | `relationship_type`, `relationship_status` | `Utf8` |
+/// | `periods` | `List<Struct<start_date, end_date?, type>>` |
+/// | `qualifiers` | `List<Struct<dimension, category>>` |
+/// | `quantifiers` | `List<Struct<measurement_method, quantity: Float64, units?>>` |
+/// | `initial_registration_date`, `last_update_date`? | `Timestamp` |
+/// | `registration_status` | `Utf8` |
+/// | `next_renewal_date` | `Timestamp` |
+/// | `managing_lou`, `corroboration_level`, `corroboration_documents`, `corroboration_reference`? | `Utf8` |
+/// | `valid_from`, `valid_to`?, `deleted_at`? | `Timestamp` |
+///
+/// Columns marked `?` are nullable.
+impl ArrowRecord for RelationshipRecordAttributes {
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            text("start_node_id", false),
+            text("start_node_type", false),
+            text("end_node_id", false),
+            text("end_node_type", false),
+            text("relationship_type", false),
+            text("relationship_status", false),
+            list("periods", period_fields(), false),
+            list("qualifiers", qualifier_fields(), false),
+            list("quantifiers", quantifier_fields(), false),
+            timestamp("initial_registration_date", false),
+            timestamp("last_update_date", true),
+            text("registration_status", false),
+            timestamp("next_renewal_date", false),
+            text("managing_lou", false),
+            text("corroboration_level", false),
+            text("corroboration_documents", false),
+            text("corroboration_reference", true),
+            timestamp("valid_from", false),
+            timestamp("valid_to", true),
+            timestamp("deleted_at", true),
+        ]))
+    }
+
+    fn to_record_batch(records: &[Self]) -> Result<RecordBatch> {
+        relationship_batch(&records.iter().collect::<Vec<_>>())
+    }
+}
+
+/// The schema of [`RelationshipRecordAttributes`], for records returned by the API.
+impl ArrowRecord for RelationshipRecord {
+    fn schema() -> SchemaRef {
+        RelationshipRecordAttributes::schema()
+    }
+
+    fn to_record_batch(records: &[Self]) -> Result<RecordBatch> {
+        relationship_batch(
+            &records
+                .iter()
+                .map(|record| &record.attributes)
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+/// The schema of LEI-to-ISIN pairs: the `lei` and `isin` columns, both `Utf8`.
+impl ArrowRecord for IsinAttributes {
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![text("lei", false), text("isin", false)]))
+    }
+
+    fn to_record_batch(records: &[Self]) -> Result<RecordBatch> {
+        isin_batch(&records.iter().collect::<Vec<_>>())
+    }
+}
+
+/// The schema of [`IsinAttributes`], for ISINs returned by the API.
+impl ArrowRecord for Isin {
+    fn schema() -> SchemaRef {
+        IsinAttributes::schema()
+    }
+
+    fn to_record_batch(records: &[Self]) -> Result<RecordBatch> {
+        isin_batch(
+            &records
+                .iter()
+                .map(|isin| &isin.attributes)
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+/// Builds a non-nullable `Utf8` field or a nullable one.
+fn text(name: &str, nullable: bool) -> Field {
+    Field::new(name, DataType::Utf8, nullable)
+}
+
+/// Builds a `Timestamp` field in microseconds and UTC.
+fn timestamp(name: &str, nullable: bool) -> Field {
+    Field::new(
+        name,
+        DataType::Timestamp(TimeUnit::Microsecond, Some(UTC.into())),
+        nullable,
+    )
+}
+
+/// Builds a non-nullable struct field.
+fn structure(name: &str, fields: Fields) -> Field {
+    Field::new(name, DataType::Struct(fields), false)
+}
+
+/// Builds a field holding a list of structs.
+fn list(name: &str, fields: Fields, nullable: bool) -> Field {
+    Field::new_list(
+        name,
+        Field::new_list_field(DataType::Struct(fields), false),
+        nullable,
+    )
+}
+
+/// Builds a field holding a list of strings.
+fn text_list(name: &str, nullable: bool) -> Field {
+    Field::new_list(name, Field::new_list_field(DataType::Utf8, false), nullable)
+}
+
+/// The fields of other and transliterated names.
+fn name_fields() -> Fields {
+    Fields::from(vec![
+        text("name", false),
+        text("language", true),
+        text("type", false),
+    ])
+}
+
+/// The fields of an address, led by its `type` for other addresses.
+fn address_fields(typed: bool) -> Fields {
+    let mut fields = Vec::new();
+    if typed {
+        fields.push(text("type", false));
+    }
+    fields.extend([
+        text("language", true),
+        text_list("address_lines", false),
+        text("address_number", true),
+        text("address_number_within_building", true),
+        text("mail_routing", true),
+        text("city", false),
+        text("region", true),
+        text("country", false),
+        text("postal_code", true),
+    ]);
+    Fields::from(fields)
+}
+
+/// The fields of a successor entity.
+fn successor_fields() -> Fields {
+    Fields::from(vec![text("lei", true), text("name", true)])
+}
+
+/// The fields of a legal entity event.
+fn event_fields() -> Fields {
+    Fields::from(vec![
+        text("group_type", false),
+        text("type", false),
+        text("status", false),
+        timestamp("effective_date", false),
+        timestamp("recorded_date", false),
+        text("validation_documents", false),
+        text("validation_reference", true),
+    ])
+}
+
+/// The fields of an other validation authority.
+fn validation_authority_fields() -> Fields {
+    Fields::from(vec![
+        text("id", false),
+        text("other", true),
+        text("validated_as", false),
+    ])
+}
+
+/// The fields of a relationship period.
+fn period_fields() -> Fields {
+    Fields::from(vec![
+        timestamp("start_date", false),
+        timestamp("end_date", true),
+        text("type", false),
+    ])
+}
+
+/// The fields of a relationship qualifier.
+fn qualifier_fields() -> Fields {
+    Fields::from(vec![text("dimension", false), text("category", false)])
+}
+
+/// The fields of a relationship quantifier.
+fn quantifier_fields() -> Fields {
+    Fields::from(vec![
+        text("measurement_method", false),
+        Field::new("quantity", DataType::Float64, false),
+        text("units", true),
+    ])
+}
+
+/// Builds a string column from a value of each row.
+fn strings<'a, R>(rows: &'a [R], value: impl Fn(&'a R) -> Option<&'a str>) -> ArrayRef {
+    Arc::new(rows.iter().map(value).collect::<StringArray>())
+}
+
+/// Builds a string column from a code list value of each row.
+fn codes<'a, R, T: Serialize + 'a>(
+    rows: &'a [R],
+    value: impl Fn(&'a R) -> Option<&'a T>,
+) -> ArrayRef {
+    let codes: Vec<_> = rows
+        .iter()
+        .map(|row| {
+            value(row)
+                .and_then(FieldValue::variant)
+                .map(|code| code.to_string())
+        })
+        .collect();
+    strings(&codes, Option::as_deref)
+}
+
+/// Builds a timestamp column from a date of each row.
+fn timestamps<'a, R>(rows: &'a [R], value: impl Fn(&'a R) -> Option<DateTime<Utc>>) -> ArrayRef {
+    let values: TimestampMicrosecondArray = rows
+        .iter()
+        .map(|row| value(row).map(|date| date.timestamp_micros()))
+        .collect();
+    Arc::new(values.with_timezone(UTC))
+}
+
+/// Builds a struct column from the columns of its fields.
+fn structs(fields: Fields, columns: Vec<ArrayRef>) -> Result<ArrayRef> {
+    Ok(Arc::new(StructArray::try_new(fields, columns, None)?))
+}
+
+/// Builds a list column from the number of items per row and the column of all items, with rows
+/// that are null where `valid` is `false`.
+fn lists(
+    item: FieldRef,
+    lengths: impl IntoIterator<Item = usize>,
+    items: ArrayRef,
+    valid: Option<Vec<bool>>,
+) -> Result<ArrayRef> {
+    Ok(Arc::new(ListArray::try_new(
+        item,
+        OffsetBuffer::from_lengths(lengths),
+        items,
+        valid.map(NullBuffer::from),
+    )?))
+}
+
+/// Builds a list column of structs from the items of each row, given a function building the
+/// columns of the struct fields from all items.
+fn struct_lists<'a, R, T: 'a>(
+    fields: Fields,
+    rows: &'a [R],
+    items: impl Fn(&'a R) -> &'a [T],
+    columns: impl FnOnce(&[&'a T]) -> Result<Vec<ArrayRef>>,
+) -> Result<ArrayRef> {
+    let all: Vec<&T> = rows.iter().flat_map(&items).collect();
+    let values = structs(fields.clone(), columns(&all)?)?;
+    lists(
+        Arc::new(Field::new_list_field(DataType::Struct(fields), false)),
+        rows.iter().map(|row| items(row).len()),
+        values,
+        None,
+    )
+}
+
+/// Builds a list column of strings from the values of each row, with null rows for `None`.
+fn string_lists<'a, R>(
+    rows: &'a [R],
+    values: impl Fn(&'a R) -> Option<&'a Vec<String>>,
+) -> Result<ArrayRef> {
+    let rows: Vec<_> = rows.iter().map(values).collect();
+    let items: StringArray = rows
+        .iter()
+        .flatten()
+        .flat_map(|row| row.iter().map(Some))
+        .collect();
+    let valid = rows
+        .iter()
+        .any(Option::is_none)
+        .then(|| rows.iter().map(Option::is_some).collect());
+    lists(
+        Arc::new(Field::new_list_field(DataType::Utf8, false)),
+        rows.iter().map(|row| row.map_or(0, Vec::len)),
+        Arc::new(items),
+        valid,
+    )
+}
+
+/// A borrowed view over [`Address`] and [`OtherAddress`].
+struct AddressParts<'a> {
+    r#type: Option<String>,
+    language: Option<&'a str>,
+    address_lines: &'a Vec<String>,
+    address_number: Option<&'a str>,
+    address_number_within_building: Option<&'a str>,
+    mail_routing: Option<&'a str>,
+    city: &'a str,
+    region: Option<&'a str>,
+    country: &'a str,
+    postal_code: Option<&'a str>,
+}
+
+impl<'a> From<&'a Address> for AddressParts<'a> {
+    fn from(address: &'a Address) -> Self {
+        Self {
+            r#type: None,
+            language: address.language.as_deref(),
+            address_lines: &address.address_lines,
+            address_number: address.address_number.as_deref(),
+            address_number_within_building: address.address_number_within_building.as_deref(),
+            mail_routing: address.mail_routing.as_deref(),
+            city: &address.city,
+            region: address.region.as_deref(),
+            country: &address.country,
+            postal_code: address.postal_code.as_deref(),
+        }
+    }
+}
+
+impl<'a> From<&'a OtherAddress> for AddressParts<'a> {
+    fn from(address: &'a OtherAddress) -> Self {
+        Self {
+            r#type: FieldValue::variant(&address.r#type).map(|code| code.to_string()),
+            language: address.language.as_deref(),
+            address_lines: &address.address_lines,
+            address_number: address.address_number.as_deref(),
+            address_number_within_building: address.address_number_within_building.as_deref(),
+            mail_routing: address.mail_routing.as_deref(),
+            city: &address.city,
+            region: address.region.as_deref(),
+            country: &address.country,
+            postal_code: address.postal_code.as_deref(),
+        }
+    }
+}
+
+/// Builds the columns of addresses, led by their `type` if `typed`.
+fn address_columns(addresses: &[AddressParts<'_>], typed: bool) -> Result<Vec<ArrayRef>> {
+    let mut columns = Vec::new();
+    if typed {
+        columns.push(strings(addresses, |a| a.r#type.as_deref()));
+    }
+    columns.extend([
+        strings(addresses, |a| a.language),
+        string_lists(addresses, |a| Some(a.address_lines))?,
+        strings(addresses, |a| a.address_number),
+        strings(addresses, |a| a.address_number_within_building),
+        strings(addresses, |a| a.mail_routing),
+        strings(addresses, |a| Some(a.city)),
+        strings(addresses, |a| a.region),
+        strings(addresses, |a| Some(a.country)),
+        strings(addresses, |a| a.postal_code),
+    ]);
+    Ok(columns)
+}
+
+/// Builds a struct column of the legal or headquarters addresses of the entities.
+fn address_column<'a>(addresses: impl Iterator<Item = &'a Address>) -> Result<ArrayRef> {
+    let addresses: Vec<AddressParts> = addresses.map(AddressParts::from).collect();
+    structs(address_fields(false), address_columns(&addresses, false)?)
+}
+
+/// Builds a list column of the other or transliterated addresses of the entities.
+fn other_address_column<'a>(
+    entities: &'a [&Entity],
+    addresses: impl Fn(&'a &Entity) -> &'a [OtherAddress],
+) -> Result<ArrayRef> {
+    struct_lists(address_fields(true), entities, addresses, |addresses| {
+        let addresses: Vec<AddressParts> =
+            addresses.iter().map(|a| AddressParts::from(*a)).collect();
+        address_columns(&addresses, true)
+    })
+}
+
+/// Converts LEI records into a record batch.
+fn lei_batch(records: &[&LeiRecordAttributes]) -> Result<RecordBatch> {
+    let entities: Vec<_> = records.iter().map(|record| &record.entity).collect();
+    let registrations: Vec<_> = records.iter().map(|record| &record.registration).collect();
+    let mut columns = vec![strings(records, |r| Some(r.lei.as_str()))];
+    columns.extend(entity_columns(&entities)?);
+    columns.extend(registration_columns(&registrations)?);
+    columns.extend([
+        string_lists(records, |r| r.bic.as_ref())?,
+        string_lists(records, |r| r.mic.as_ref())?,
+        strings(records, |r| r.ocid.as_deref()),
+        strings(records, |r| r.qcc.as_deref()),
+        string_lists(records, |r| r.spglobal.as_ref())?,
+        codes(records, |r| r.conformity_flag.as_ref()),
+    ]);
+    Ok(RecordBatch::try_new(
+        LeiRecordAttributes::schema(),
+        columns,
+    )?)
+}
+
+/// Builds the entity columns of LEI records.
+fn entity_columns(entities: &[&Entity]) -> Result<Vec<ArrayRef>> {
+    let events: Vec<Vec<_>> = entities
+        .iter()
+        .map(|entity| {
+            let groups = entity.event_groups.iter();
+            groups
+                .flat_map(|group| {
+                    group
+                        .events
+                        .iter()
+                        .map(move |event| (&group.group_type, event))
+                })
+                .collect()
+        })
+        .collect();
+    Ok(vec![
+        strings(entities, |e| Some(e.legal_name.name.as_str())),
+        strings(entities, |e| e.legal_name.language.as_deref()),
+        struct_lists(
+            name_fields(),
+            entities,
+            |e| &e.other_names,
+            |names| {
+                Ok(vec![
+                    strings(names, |n| Some(n.name.as_str())),
+                    strings(names, |n| n.language.as_deref()),
+                    codes(names, |n| Some(&n.r#type)),
+                ])
+            },
+        )?,
+        struct_lists(
+            name_fields(),
+            entities,
+            |e| &e.transliterated_other_names,
+            |names| {
+                Ok(vec![
+                    strings(names, |n| Some(n.name.as_str())),
+                    strings(names, |n| n.language.as_deref()),
+                    codes(names, |n| Some(&n.r#type)),
+                ])
+            },
+        )?,
+        address_column(entities.iter().map(|e| &e.legal_address))?,
+        address_column(entities.iter().map(|e| &e.headquarters_address))?,
+        other_address_column(entities, |e| &e.other_addresses)?,
+        other_address_column(entities, |e| {
+            e.transliterated_other_addresses
+                .as_deref()
+                .unwrap_or_default()
+        })?,
+        strings(entities, |e| Some(e.registered_at.id.as_str())),
+        strings(entities, |e| e.registered_at.other.as_deref()),
+        strings(entities, |e| e.registered_as.as_deref()),
+        strings(entities, |e| Some(e.jurisdiction.as_str())),
+        codes(entities, |e| Some(&e.category)),
+        codes(entities, |e| e.sub_category.as_ref()),
+        strings(entities, |e| Some(e.legal_form.id.as_str())),
+        strings(entities, |e| e.legal_form.other.as_deref()),
+        strings(entities, |e| e.associated_entity.lei.as_deref()),
+        strings(entities, |e| e.associated_entity.name.as_deref()),
+        codes(entities, |e| e.associated_entity.r#type.as_ref()),
+        codes(entities, |e| Some(&e.status)),
+        timestamps(entities, |e| e.creation_date),
+        timestamps(entities, |e| e.expiration.date),
+        codes(entities, |e| e.expiration.reason.as_ref()),
+        struct_lists(
+            successor_fields(),
+            entities,
+            |e| e.successors(),
+            |successors| {
+                Ok(vec![
+                    strings(successors, |s| s.lei.as_deref()),
+                    strings(successors, |s| s.name.as_deref()),
+                ])
+            },
+        )?,
+        struct_lists(event_fields(), &events, Vec::as_slice, |events| {
+            Ok(vec![
+                codes(events, |(group_type, _)| Some(*group_type)),
+                codes(events, |(_, event)| Some(&event.r#type)),
+                codes(events, |(_, event)| Some(&event.status)),
+                timestamps(events, |(_, event)| Some(event.effective_date)),
+                timestamps(events, |(_, event)| Some(event.recorded_date)),
+                codes(events, |(_, event)| Some(&event.validation_documents)),
+                strings(events, |(_, event)| event.validation_reference.as_deref()),
+            ])
+        })?,
+    ])
+}
+
+/// Builds the registration columns of LEI records.
+fn registration_columns(registrations: &[&Registration]) -> Result<Vec<ArrayRef>> {
+    Ok(vec![
+        timestamps(registrations, |r| Some(r.initial_registration_date)),
+        timestamps(registrations, |r| Some(r.last_update_date)),
+        codes(registrations, |r| Some(&r.status)),
+        timestamps(registrations, |r| Some(r.next_renewal_date)),
+        strings(registrations, |r| Some(r.managing_lou.as_str())),
+        codes(registrations, |r| Some(&r.corroboration_level)),
+        strings(registrations, |r| Some(r.validated_at.id.as_str())),
+        strings(registrations, |r| r.validated_at.other.as_deref()),
+        strings(registrations, |r| r.validated_as.as_deref()),
+        struct_lists(
+            validation_authority_fields(),
+            registrations,
+            |r| &r.other_validation_authorities,
+            |others| {
+                Ok(vec![
+                    strings(others, |o| Some(o.validated_at.id.as_str())),
+                    strings(others, |o| o.validated_at.other.as_deref()),
+                    strings(others, |o| Some(o.validated_as.as_str())),
+                ])
+            },
+        )?,
+    ])
+}
+
+/// Converts relationship records into a record batch.
+fn relationship_batch(records: &[&RelationshipRecordAttributes]) -> Result<RecordBatch> {
+    let relationships: Vec<_> = records.iter().map(|record| &record.relationship).collect();
+    let registrations: Vec<_> = records.iter().map(|record| &record.registration).collect();
+    let columns = vec![
+        strings(&relationships, |r| Some(r.start_node.id.as_str())),
+        strings(&relationships, |r| Some(r.start_node.r#type.as_str())),
+        strings(&relationships, |r| Some(r.end_node.id.as_str())),
+        strings(&relationships, |r| Some(r.end_node.r#type.as_str())),
+        codes(&relationships, |r| Some(&r.r#type)),
+        codes(&relationships, |r| Some(&r.status)),
+        struct_lists(
+            period_fields(),
+            &relationships,
+            |r| &r.periods,
+            |periods| {
+                Ok(vec![
+                    timestamps(periods, |p| Some(p.start_date)),
+                    timestamps(periods, |p| p.end_date),
+                    codes(periods, |p| Some(&p.r#type)),
+                ])
+            },
+        )?,
+        struct_lists(
+            qualifier_fields(),
+            &relationships,
+            |r| &r.qualifiers,
+            |qualifiers| {
+                Ok(vec![
+                    strings(qualifiers, |q| Some(q.dimension.as_str())),
+                    strings(qualifiers, |q| Some(q.category.as_str())),
+                ])
+            },
+        )?,
+        struct_lists(
+            quantifier_fields(),
+            &relationships,
+            |r| &r.quantifiers,
+            |quantifiers| {
+                let quantities: Float64Array = quantifiers.iter().map(|q| q.quantity).collect();
+                Ok(vec![
+                    strings(quantifiers, |q| Some(q.measurement_method.as_str())),
+                    Arc::new(quantities),
+                    strings(quantifiers, |q| q.units.as_deref()),
+                ])
+            },
+        )?,
+        timestamps(&registrations, |r| Some(r.initial_registration_date)),
+        timestamps(&registrations, |r| r.last_update_date),
+        codes(&registrations, |r| Some(&r.status)),
+        timestamps(&registrations, |r| Some(r.next_renewal_date)),
+        strings(&registrations, |r| Some(r.managing_lou.as_str())),
+        codes(&registrations, |r| Some(&r.corroboration_level)),
+        codes(&registrations, |r| Some(&r.corroboration_documents)),
+        strings(&registrations, |r| r.corroboration_reference.as_deref()),
+        timestamps(records, |r| Some(r.valid_from)),
+        timestamps(records, |r| r.valid_to),
+        timestamps(records, |r| r.extension.deleted_at),
+    ];
+    Ok(RecordBatch::try_new(
+        RelationshipRecordAttributes::schema(),
+        columns,
+    )?)
+}
+
+/// Converts LEI-to-ISIN pairs into a record batch.
+fn isin_batch(isins: &[&IsinAttributes]) -> Result<RecordBatch> {
+    let columns = vec![
+        strings(isins, |i| Some(i.lei.as_str())),
+        strings(isins, |i| Some(i.isin.as_str())),
+    ];
+    Ok(RecordBatch::try_new(IsinAttributes::schema(), columns)?)
+}
+
+/// A writer exporting records of one [`ArrowRecord`] type to a Parquet file.
+///
+/// Each call to [`ParquetExporter::write`] converts a batch of records, such as one page of a query,
+/// with [`ArrowRecord::to_record_batch`]; rows are buffered into row groups and compressed with
+/// Snappy. The file is complete once [`ParquetExporter::finish`] has written its footer.
+pub struct ParquetExporter<T, W: Write + Send> {
+    writer: ArrowWriter<W>,
+    records: PhantomData<fn(&T)>,
+}
+
+impl<T: ArrowRecord> ParquetExporter<T, BufWriter<File>> {
+    /// Creates a Parquet file.
+    ///
+    /// # Parameters
+    ///
+    /// - `path` - The path of the Parquet file, which is replaced if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<T: ArrowRecord, W: Write + Send> ParquetExporter<T, W> {
+    /// Creates an exporter writing a Parquet file with the schema of `T`.
+    ///
+    /// # Parameters
+    ///
+    /// - `writer` - The destination of the Parquet file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file header cannot be written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use futures_util::TryStreamExt;
+    /// use gleif_rs::{client::GleifClient, export::ParquetExporter, model::LeiRecord};
+    ///
+    /// let mut exporter = ParquetExporter::<LeiRecord, _>::create("lei_records.parquet")?;
+    /// let mut pages = GleifClient::new()
+    ///     .lei_records()
+    ///     .page_size(200)
+    ///     .stream_all::<LeiRecord>()
+    ///     .try_chunks(200);
+    /// while let Some(page) = pages.try_next().await.map_err(|error| error.1)? {
+    ///     exporter.write(&page)?;
+    /// }
+    /// exporter.finish()?;
+    /// ```
+    pub fn new(writer: W) -> Result<Self> {
+        let properties = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        Ok(Self {
+            writer: ArrowWriter::try_new(writer, T::schema(), Some(properties))?,
+            records: PhantomData,
+        })
+    }
+
+    /// Writes a batch of records.
+    ///
+    /// # Parameters
+    ///
+    /// - `records` - The records to write.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the records cannot be converted or written.
+    pub fn write(&mut self, records: &[T]) -> Result<()> {
+        self.writer.write(&T::to_record_batch(records)?)?;
+        Ok(())
+    }
+
+    /// Writes the remaining rows and the file footer, and returns the destination.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the destination cannot be written.
+    pub fn finish(self) -> Result<W> {
+        Ok(self.writer.into_inner()?)
+    }
+}
+
+impl<T, W: Write + Send> fmt::Debug for ParquetExporter<T, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParquetExporter")
+            .field("writer", &self.writer)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::sample_records;
+    use arrow_array::{Array, cast::AsArray, types::TimestampMicrosecondType};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use std::fs;
+
+    #[test]
+    fn test_lei_record_batch() {
+        let records = sample_records();
+        let batch = LeiRecord::to_record_batch(&records).unwrap();
+        assert_eq!(batch.num_rows(), records.len());
+        assert_eq!(batch.schema(), LeiRecord::schema());
+
+        let leis = batch.column_by_name("lei").unwrap().as_string::<i32>();
+        assert_eq!(leis.value(0), "254900LNRYNOQ9YPU758");
+        let status = batch
+            .column_by_name("registration_status")
+            .unwrap()
+            .as_string::<i32>();
+        assert_eq!(status.value(1), "LAPSED");
+
+        let other_names = batch
+            .column_by_name("other_names")
+            .unwrap()
+            .as_list::<i32>();
+        assert_eq!(other_names.value(0).len(), 4);
+        assert_eq!(other_names.value(1).len(), 0);
+        let names = other_names.value(0);
+        let names = names.as_struct();
+        assert_eq!(
+            names
+                .column_by_name("name")
+                .unwrap()
+                .as_string::<i32>()
+                .value(0),
+            records[0].attributes.entity.other_names[0].name
+        );
+
+        let address = batch.column_by_name("legal_address").unwrap().as_struct();
+        let cities = address.column_by_name("city").unwrap().as_string::<i32>();
+        assert_eq!(
+            cities.value(1),
+            records[1].attributes.entity.legal_address.city
+        );
+        let lines = address
+            .column_by_name("address_lines")
+            .unwrap()
+            .as_list::<i32>();
+        assert_eq!(
+            lines.value(1).as_string::<i32>().value(0),
+            "15/8, APPLE VALLEY, ANNA SALAI"
+        );
+
+        let bic = batch.column_by_name("bic").unwrap().as_list::<i32>();
+        let puma = records
+            .iter()
+            .position(|record| record.attributes.bic.is_some())
+            .unwrap();
+        assert!(bic.is_null(0));
+        assert_eq!(bic.value(puma).as_string::<i32>().value(0), "PUMADE77XXX");
+
+        let updated = batch
+            .column_by_name("last_update_date")
+            .unwrap()
+            .as_primitive::<TimestampMicrosecondType>();
+        assert_eq!(
+            updated.value(0),
+            records[0]
+                .attributes
+                .registration
+                .last_update_date
+                .timestamp_micros()
+        );
+    }
+
+    #[test]
+    fn test_successor_entity_fallback() {
+        let mut records = sample_records();
+        let entity = &mut records[0].attributes.entity;
+        entity.successor_entities.clear();
+        entity.successor_entity.lei = Some("5493000IBP32UQZ0KL24".to_string());
+        let batch = LeiRecord::to_record_batch(&records[..1]).unwrap();
+        let successors = batch
+            .column_by_name("successor_entities")
+            .unwrap()
+            .as_list::<i32>()
+            .value(0);
+        assert_eq!(successors.len(), 1);
+        let leis = successors.as_struct().column_by_name("lei").unwrap();
+        assert_eq!(leis.as_string::<i32>().value(0), "5493000IBP32UQZ0KL24");
+    }
+
+    #[test]
+    #[cfg(feature = "golden-copy")]
+    fn test_relationship_and_isin_batches() {
+        use crate::golden_copy::RrCdfReader;
+
+        let records: Vec<_> = RrCdfReader::open("tests/data/golden_copy/rr_cdf_sample.xml")
+            .expect("Failed to open sample file")
+            .filter_map(Result::ok)
+            .collect();
+        let batch = RelationshipRecordAttributes::to_record_batch(&records).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        let quantifiers = batch
+            .column_by_name("quantifiers")
+            .unwrap()
+            .as_list::<i32>();
+        let quantifiers = quantifiers.value(1);
+        let quantity = quantifiers
+            .as_struct()
+            .column_by_name("quantity")
+            .unwrap()
+            .as_primitive::<arrow_array::types::Float64Type>()
+            .value(0);
+        assert!((quantity - 62.5).abs() < f64::EPSILON);
+
+        let isins = vec![IsinAttributes {
+            lei: "529900GRZ2BQY5ZM9N49".to_string(),
+            isin: "DE0006969603".to_string(),
+        }];
+        let batch = IsinAttributes::to_record_batch(&isins).unwrap();
+        assert_eq!(batch.num_columns(), 2);
+        assert_eq!(batch.num_rows(), 1);
+        assert_eq!(IsinAttributes::to_record_batch(&[]).unwrap().num_rows(), 0);
+    }
+
+    #[test]
+    fn test_parquet_export() {
+        let records = sample_records();
+        let path =
+            std::env::temp_dir().join(format!("gleif-export-{}.parquet", std::process::id()));
+        let mut exporter = ParquetExporter::<LeiRecord, _>::create(&path).unwrap();
+        exporter.write(&records[..5]).unwrap();
+        exporter.write(&records[5..]).unwrap();
+        exporter.finish().unwrap();
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches: Vec<_> = reader.map(|batch| batch.unwrap()).collect();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(batches[0].schema(), LeiRecord::schema());
+        let rows: usize = batches.iter().map(RecordBatch::num_rows).sum();
+        assert_eq!(rows, records.len());
+        assert_eq!(
+            batches[0],
+            LeiRecord::to_record_batch(&records[..batches[0].num_rows()]).unwrap()
+        );
+    }
+}
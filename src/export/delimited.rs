@@ -0,0 +1,361 @@
+//! Export of LEI records to CSV files (requires the `csv` feature).
+
+use crate::{
+    error::Result,
+    field::Field,
+    model::lei_record::{LeiRecord, LeiRecordAttributes},
+    value::FieldValue,
+};
+use futures_util::{Stream, StreamExt};
+use std::{borrow::Borrow, io::Write, pin::pin};
+
+/// The separator joining the values of a multi-valued field, unless configured otherwise.
+const DEFAULT_LIST_SEPARATOR: &str = "; ";
+
+/// A column of the export.
+#[derive(Clone, Debug)]
+struct Column {
+    field: Field,
+    /// The number of numbered columns the values are spread over, or `None` to join them.
+    spread: Option<usize>,
+}
+
+/// A writer exporting LEI records to CSV, one row per record.
+///
+/// Each [`Field`] passed to [`CsvExporter::new`] becomes a column headed by the field name (e.g.
+/// `entity.legalName`). Values are formatted as by [`FieldValue`]'s `Display`: enums as their API
+/// codes and dates in RFC 3339. Fields a record has no value for are left empty. Multi-valued
+/// fields (e.g. [`Field::Bic`], [`Field::EntityOtherNames`] or
+/// [`Field::EntityLegalAddressAddressLines`]) are joined with `"; "` into a single cell by default;
+/// see [`CsvExporter::list_separator`] and [`CsvExporter::spread`].
+///
+/// The header row is written with the first record, or by [`CsvExporter::finish`] if there are no
+/// records.
+#[derive(Debug)]
+pub struct CsvExporter<W: Write> {
+    writer: csv::Writer<W>,
+    columns: Vec<Column>,
+    separator: String,
+    header_written: bool,
+}
+
+impl<W: Write> CsvExporter<W> {
+    /// Creates an exporter writing the given columns.
+    ///
+    /// # Parameters
+    ///
+    /// - `writer` - The destination of the CSV output; wrap unbuffered destinations such as files in
+    ///   a [`std::io::BufWriter`].
+    /// - `columns` - The fields to export, in column order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use gleif_rs::{export::CsvExporter, field::Field};
+    ///
+    /// let mut exporter = CsvExporter::new(Vec::new(), [Field::Lei, Field::EntityLegalName]);
+    /// exporter.write_all(&records)?;
+    /// let csv = String::from_utf8(exporter.finish()?)?;
+    /// ```
+    pub fn new(writer: W, columns: impl IntoIterator<Item = Field>) -> Self {
+        Self {
+            writer: csv::WriterBuilder::new()
+                .terminator(csv::Terminator::CRLF)
+                .from_writer(writer),
+            columns: columns
+                .into_iter()
+                .map(|field| Column {
+                    field,
+                    spread: None,
+                })
+                .collect(),
+            separator: DEFAULT_LIST_SEPARATOR.to_string(),
+            header_written: false,
+        }
+    }
+
+    /// Sets the separator joining the values of multi-valued fields into one cell.
+    ///
+    /// # Parameters
+    ///
+    /// - `separator` - The separator, `"; "` by default.
+    #[must_use]
+    pub fn list_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Spreads the values of a field over a number of numbered columns instead of joining them.
+    ///
+    /// The columns are headed by the field name and a 1-based number (e.g. `bic.1`, `bic.2`). Values
+    /// beyond the last column are joined into it with the list separator, so no value is lost.
+    /// Single-valued fields fill the first of their columns.
+    ///
+    /// # Parameters
+    ///
+    /// - `field` - A field passed to [`CsvExporter::new`]; other fields are ignored.
+    /// - `count` - The number of columns, at least 1.
+    #[must_use]
+    pub fn spread(mut self, field: Field, count: usize) -> Self {
+        for column in self
+            .columns
+            .iter_mut()
+            .filter(|column| column.field == field)
+        {
+            column.spread = Some(count.max(1));
+        }
+        self
+    }
+
+    /// Returns the header row.
+    #[must_use]
+    pub fn headers(&self) -> Vec<String> {
+        self.columns
+            .iter()
+            .flat_map(|column| match column.spread {
+                None => vec![column.field.to_string()],
+                Some(count) => (1..=count)
+                    .map(|number| format!("{}.{number}", column.field))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Writes a record as a row.
+    ///
+    /// # Parameters
+    ///
+    /// - `record` - The record to write.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the destination cannot be written.
+    pub fn write(&mut self, record: &LeiRecord) -> Result<()> {
+        self.write_attributes(&record.attributes)
+    }
+
+    /// Writes the attributes of a record as a row, e.g. a record read from a Golden Copy file.
+    ///
+    /// # Parameters
+    ///
+    /// - `attributes` - The record attributes to write.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the destination cannot be written.
+    pub fn write_attributes(&mut self, attributes: &LeiRecordAttributes) -> Result<()> {
+        self.write_header()?;
+        let mut row = Vec::new();
+        for column in &self.columns {
+            let value = attributes.get(column.field);
+            match column.spread {
+                None => row.push(self.cell(value)),
+                Some(count) => self.spread_cells(value, count, &mut row),
+            }
+        }
+        self.writer.write_record(&row)?;
+        Ok(())
+    }
+
+    /// Writes every record of an iterator, returning the number of records written.
+    ///
+    /// # Parameters
+    ///
+    /// - `records` - The records to write, owned or borrowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the destination cannot be written.
+    pub fn write_all<I>(&mut self, records: I) -> Result<u64>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<LeiRecord>,
+    {
+        let mut count = 0;
+        for record in records {
+            self.write(record.borrow())?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Writes every record of a stream as it arrives, returning the number of records written.
+    ///
+    /// Use it with [`GleifRequestBuilder::stream_all`](crate::request_builder::GleifRequestBuilder::stream_all)
+    /// to export a paginated query page by page.
+    ///
+    /// # Parameters
+    ///
+    /// - `records` - The stream of records to write.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of the stream, or an error if the destination cannot be written. The
+    /// records before the error have been written.
+    pub async fn write_stream<S>(&mut self, records: S) -> Result<u64>
+    where
+        S: Stream<Item = Result<LeiRecord>>,
+    {
+        let mut records = pin!(records);
+        let mut count = 0;
+        while let Some(record) = records.next().await {
+            self.write(&record?)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Writes the header row if no record has been written, flushes the output and returns the
+    /// destination.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the destination cannot be written or flushed.
+    pub fn finish(mut self) -> Result<W> {
+        self.write_header()?;
+        self.writer
+            .into_inner()
+            .map_err(|error| error.into_error().into())
+    }
+
+    /// Writes the header row before the first row.
+    fn write_header(&mut self) -> Result<()> {
+        if !self.header_written {
+            self.writer.write_record(self.headers())?;
+            self.header_written = true;
+        }
+        Ok(())
+    }
+
+    /// Formats a value for a single cell.
+    fn cell(&self, value: Option<FieldValue>) -> String {
+        match value {
+            Some(FieldValue::List(values)) => values.join(&self.separator),
+            Some(value) => value.to_string(),
+            None => String::new(),
+        }
+    }
+
+    /// Formats a value for `count` numbered cells.
+    fn spread_cells(&self, value: Option<FieldValue>, count: usize, row: &mut Vec<String>) {
+        let mut values = match value {
+            Some(FieldValue::List(values)) => values,
+            Some(value) => vec![value.to_string()],
+            None => Vec::new(),
+        };
+        if values.len() > count {
+            let rest = values.split_off(count - 1).join(&self.separator);
+            values.push(rest);
+        }
+        values.resize(count, String::new());
+        row.extend(values);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::GleifError, test_utils::sample_records};
+    use futures_util::stream;
+    use std::io;
+
+    #[test]
+    fn test_export_csv() {
+        let records = sample_records();
+        let mut exporter = CsvExporter::new(
+            Vec::new(),
+            [
+                Field::Lei,
+                Field::EntityLegalName,
+                Field::EntityLegalAddressAddressLines,
+                Field::EntityOtherNames,
+                Field::RegistrationStatus,
+                Field::Bic,
+            ],
+        );
+        assert_eq!(exporter.write_all(&records[..2]).unwrap(), 2);
+        let csv = String::from_utf8(exporter.finish().unwrap()).unwrap();
+        let rows: Vec<_> = csv.split_terminator("\r\n").collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            rows[0],
+            "lei,entity.legalName,entity.legalAddress.addressLines,entity.otherNames,\
+             registration.status,bic"
+        );
+        assert!(rows[1].starts_with(
+            "254900LNRYNOQ9YPU758,Amazon Digital UK Limited,1 Principal Place; Worship Street,"
+        ));
+        assert_eq!(
+            rows[2],
+            r#"335800CCCPHKUOXPC332,NFA INTERNATIONAL,"15/8, APPLE VALLEY, ANNA SALAI; DINDIGUL",,LAPSED,"#
+        );
+
+        let mut reader = csv::Reader::from_reader(csv.as_bytes());
+        let row = reader.records().next().unwrap().unwrap();
+        assert_eq!(
+            row[3].split("; ").count(),
+            records[0].attributes.entity.other_names.len()
+        );
+    }
+
+    #[test]
+    fn test_export_csv_spread() {
+        let records = sample_records();
+        let exporter = CsvExporter::new(Vec::new(), [Field::Lei, Field::EntityOtherNames])
+            .list_separator("|")
+            .spread(Field::EntityOtherNames, 3);
+        assert_eq!(
+            exporter.headers(),
+            [
+                "lei",
+                "entity.otherNames.1",
+                "entity.otherNames.2",
+                "entity.otherNames.3"
+            ]
+        );
+        let mut exporter = exporter;
+        exporter.write(&records[0]).unwrap();
+        exporter.write(&records[1]).unwrap();
+        let csv = String::from_utf8(exporter.finish().unwrap()).unwrap();
+        let mut reader = csv::Reader::from_reader(csv.as_bytes());
+        let rows: Vec<_> = reader.records().map(|row| row.unwrap()).collect();
+        let names: Vec<_> = records[0]
+            .attributes
+            .entity
+            .other_names
+            .iter()
+            .map(|name| name.name.as_str())
+            .collect();
+        assert_eq!(names.len(), 4);
+        assert_eq!(&rows[0][1], names[0]);
+        assert_eq!(&rows[0][2], names[1]);
+        assert_eq!(rows[0][3], names[2..].join("|"));
+        assert_eq!(rows[1].iter().skip(1).collect::<Vec<_>>(), ["", "", ""]);
+
+        let empty = CsvExporter::new(Vec::new(), [Field::Lei]).finish().unwrap();
+        assert_eq!(empty, b"lei\r\n");
+    }
+
+    #[tokio::test]
+    async fn test_export_csv_stream() {
+        let records = sample_records();
+        let mut exporter = CsvExporter::new(Vec::new(), [Field::Lei]);
+        let stream = stream::iter(records.clone().into_iter().map(Ok));
+        assert_eq!(
+            exporter.write_stream(stream).await.unwrap(),
+            records.len() as u64
+        );
+        let csv = String::from_utf8(exporter.finish().unwrap()).unwrap();
+        assert_eq!(csv.lines().count(), records.len() + 1);
+
+        let mut exporter = CsvExporter::new(Vec::new(), [Field::Lei]);
+        let stream = stream::iter(vec![
+            Ok(records[0].clone()),
+            Err(GleifError::IoError(io::Error::other("page failed"))),
+            Ok(records[1].clone()),
+        ]);
+        assert!(exporter.write_stream(stream).await.is_err());
+        let csv = String::from_utf8(exporter.finish().unwrap()).unwrap();
+        assert_eq!(csv, "lei\r\n254900LNRYNOQ9YPU758\r\n");
+    }
+}
@@ -19,8 +19,11 @@
 //! ## Key Features
 //!
 //! - **Type Safety:** Prevents typos in field names during query construction, catching errors at compile time.
-//! - **Comprehensive Coverage:** Includes a wide array of known GLEIF API v1 field names. The naming convention
-//!   of variants (e.g., `entity.legalName`) helps categorize them by their typical API object.
+//! - **Comprehensive Coverage:** Covers the full GLEIF API v1 field catalogue, including the related-entity
+//!   filters (e.g., `directParent.legalName`). The naming convention of variants (e.g., `entity.legalName`)
+//!   helps categorize them by their typical API object.
+//! - **Field Metadata:** Each field knows its [`ValueKind`] (string, date, enum, ...), whether it is
+//!   sortable, and which filter [`Operator`]s it accepts, so queries can be validated locally.
 //! - **String Conversion:**
 //!     - Easily get the canonical string representation required by the API using the [`as_str`](Field::as_str) method.
 //!     - Implements [`std::fmt::Display`], so it can be seamlessly used in formatted strings (e.g., for logging).
@@ -83,137 +86,270 @@
 //! assert!(field_not_allowed.is_err());
 //! ```
 //!
+//! ## 4. Inspecting Field Metadata
+//!
+//! ```rust
+//! use gleif_rs::field::{Field, Operator, ValueKind};
+//!
+//! assert_eq!(Field::RegistrationInitialRegistrationDate.value_kind(), ValueKind::Date);
+//! assert!(Field::RegistrationInitialRegistrationDate.supports(Operator::GreaterThan));
+//! assert!(!Field::Bic.is_sortable());
+//! assert!(Field::EntityStatus.enum_values().contains(&"ACTIVE"));
+//! ```
+//!
 //! By incorporating [`Field`] into your application, you create more robust, readable,
 //! and maintainable code for interacting with the GLEIF API.
 
-use crate::error::{GleifError, ParseErrorKind, Result};
+use crate::{
+    error::{GleifError, ParseErrorKind, Result},
+    filter::Condition,
+};
 use std::{fmt, str::FromStr};
 
-/// Enum for known GLEIF API field names.
+mod catalog;
+mod runtime;
+
+pub use catalog::Field;
+use catalog::{CATALOG, FieldInfo};
+pub use runtime::FieldCatalog;
+
+/// The kind of value stored in a [`Field`], as reported by the GLEIF field catalogue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueKind {
+    /// Free text.
+    String,
+    /// A date or date/time.
+    Date,
+    /// One of a fixed set of values (see [`Field::enum_values`]).
+    Enum,
+    /// An identifier of another API resource (e.g., a country code or registration authority).
+    Resource,
+    /// A number.
+    Number,
+}
+
+impl ValueKind {
+    /// Returns the canonical string representation used by the field catalogue.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            ValueKind::String => "STRING",
+            ValueKind::Date => "DATE",
+            ValueKind::Enum => "ENUM",
+            ValueKind::Resource => "RESOURCE",
+            ValueKind::Number => "NUMBER",
+        }
+    }
+
+    /// Returns the filter operators assumed for fields of this kind when the catalogue does not list any.
+    ///
+    /// Dates and numbers support comparisons and ranges, enums and resources support set membership,
+    /// and text supports (negated) matches.
+    #[must_use]
+    pub const fn default_operators(&self) -> &'static [Operator] {
+        match self {
+            ValueKind::Date | ValueKind::Number => COMPARABLE_OPERATORS,
+            ValueKind::Enum | ValueKind::Resource => SET_OPERATORS,
+            ValueKind::String => TEXT_OPERATORS,
+        }
+    }
+}
+
+// Enables ergonomic formatting and logging using the catalogue representation.
+impl fmt::Display for ValueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ValueKind {
+    type Err = GleifError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "STRING" => Ok(ValueKind::String),
+            "DATE" => Ok(ValueKind::Date),
+            "ENUM" => Ok(ValueKind::Enum),
+            "RESOURCE" => Ok(ValueKind::Resource),
+            "NUMBER" => Ok(ValueKind::Number),
+            _ => Err(GleifError::ParseError {
+                kind: ParseErrorKind::Value,
+                message: "Unknown ValueKind variant".to_string(),
+            }),
+        }
+    }
+}
+
+/// A filter operator, as reported by the GLEIF field catalogue.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum Field {
-    // Core identifiers
-    /// The Legal Entity Identifier code (20-character alphanumeric code)
-    Lei,
-    /// The Business Identifier Code (`BIC`/`SWIFT` code)
-    Bic,
-    /// The International Securities Identification Number (`ISIN`)
-    Isin,
-
-    // Entity fields
-    /// Legal name of the entity (official registered name)
-    EntityLegalName,
-    /// Other names the entity is known by (trade names, etc.)
-    EntityOtherNames,
-    /// Legal form of the entity (`LLC`, `Inc`, `GmbH`, etc.)
-    EntityLegalForm,
-    /// ID code for the legal form
-    EntityLegalFormId,
-    /// Legal form code according to the entity's local jurisdiction
-    EntityLegalFormCode,
-    /// Category of the legal entity (`FUND`, `BRANCH`, etc.)
-    EntityCategory,
-    /// Country code of the entity's legal address
-    EntityLegalAddressCountry,
-    /// First line of the entity's legal address
-    EntityLegalAddressLine1,
-    /// City of the entity's legal address
-    EntityLegalAddressCity,
-    /// Postal code of the entity's legal address
-    EntityLegalAddressPostalCode,
-    /// Country code of the entity's headquarters address
-    EntityHqAddressCountry,
-    /// First line of the entity's headquarters address
-    EntityHqAddressLine1,
-    /// City of the entity's headquarters address
-    EntityHqAddressCity,
-    /// Postal code of the entity's headquarters address
-    EntityHqAddressPostalCode,
-    /// Business register number for the entity
-    EntityBusinessRegisterNumber,
-    /// Legal jurisdiction of the entity
-    EntityJurisdiction,
-
-    // Registration fields
-    /// Status of the LEI registration (`ISSUED`, `LAPSED`, etc.)
-    RegistrationStatus,
-    /// Initial registration date of the LEI
-    RegistrationInitialRegistrationDate,
-    /// Last update date of the LEI record
-    RegistrationLastUpdateDate,
-    /// Next renewal date for the LEI
-    RegistrationNextRenewalDate,
-    /// The LEI issuer (Local Operating Unit) that manages this LEI
-    RegistrationManagingLou,
-    /// Data quality conformity status flag
-    ConformityFlag,
-
-    // Relationship fields (Level 2 data)
-    /// Filter for relationships where the entity owns others
-    Owns,
-    /// Filter for relationships where the entity is owned by others
-    OwnedBy,
-    /// Start date of a relationship
-    RelationshipStartDate,
-    /// End date of a relationship
-    RelationshipEndDate,
-    /// Status of a relationship (`ACTIVE`, `INACTIVE`)
-    RelationshipStatus,
-    /// Type of relationship between entities
-    RelationshipType,
-
-    // Cross-field search
-    /// Searches all text fields in an LEI record
-    Fulltext,
+pub enum Operator {
+    /// Exact match, including comma-separated lists (`value`, `a,b`).
+    Match,
+    /// Negated match (`!value`).
+    NotMatch,
+    /// Explicit set membership (`a,b,c`).
+    In,
+    /// Greater than (`>value`).
+    GreaterThan,
+    /// Greater than or equal (`>=value`).
+    GreaterThanOrEqual,
+    /// Less than (`<value`).
+    LessThan,
+    /// Less than or equal (`<=value`).
+    LessThanOrEqual,
+    /// Inclusive range (`min..max`).
+    InRange,
 }
 
+impl Operator {
+    /// Returns the canonical string representation used by the field catalogue.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Operator::Match => "MATCH",
+            Operator::NotMatch => "NOT_MATCH",
+            Operator::In => "IN",
+            Operator::GreaterThan => "GREATER_THAN",
+            Operator::GreaterThanOrEqual => "GREATER_THAN_OR_EQUAL",
+            Operator::LessThan => "LESS_THAN",
+            Operator::LessThanOrEqual => "LESS_THAN_OR_EQUAL",
+            Operator::InRange => "IN_RANGE",
+        }
+    }
+}
+
+// Enables ergonomic formatting and logging using the catalogue representation.
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Operator {
+    type Err = GleifError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "MATCH" => Ok(Operator::Match),
+            "NOT_MATCH" => Ok(Operator::NotMatch),
+            "IN" => Ok(Operator::In),
+            "GREATER_THAN" => Ok(Operator::GreaterThan),
+            "GREATER_THAN_OR_EQUAL" => Ok(Operator::GreaterThanOrEqual),
+            "LESS_THAN" => Ok(Operator::LessThan),
+            "LESS_THAN_OR_EQUAL" => Ok(Operator::LessThanOrEqual),
+            "IN_RANGE" => Ok(Operator::InRange),
+            _ => Err(GleifError::ParseError {
+                kind: ParseErrorKind::Value,
+                message: "Unknown Operator variant".to_string(),
+            }),
+        }
+    }
+}
+
+/// Operators assumed for comparable fields the catalogue lists without operators.
+const COMPARABLE_OPERATORS: &[Operator] = &[
+    Operator::Match,
+    Operator::NotMatch,
+    Operator::GreaterThan,
+    Operator::GreaterThanOrEqual,
+    Operator::LessThan,
+    Operator::LessThanOrEqual,
+    Operator::InRange,
+];
+
+/// Operators assumed for enum and resource fields the catalogue lists without operators.
+const SET_OPERATORS: &[Operator] = &[Operator::Match, Operator::NotMatch, Operator::In];
+
+/// Operators assumed for text fields the catalogue lists without operators.
+const TEXT_OPERATORS: &[Operator] = &[Operator::Match, Operator::NotMatch];
+
 impl Field {
+    /// Private helper returning the catalogue entry of the field.
+    const fn info(self) -> &'static FieldInfo {
+        &CATALOG[self as usize]
+    }
+
     /// Returns the canonical string representation for the API.
     #[must_use]
     pub const fn as_str(&self) -> &'static str {
-        match self {
-            // Core identifiers
-            Field::Lei => "lei",
-            Field::Bic => "bic",
-            Field::Isin => "isin",
-
-            // Entity fields
-            Field::EntityLegalName => "entity.legalName",
-            Field::EntityOtherNames => "entity.otherNames",
-            Field::EntityLegalForm => "entity.legalForm",
-            Field::EntityLegalFormId => "entity.legalForm.id",
-            Field::EntityLegalFormCode => "entity.legalForm.code",
-            Field::EntityCategory => "entity.category",
-            Field::EntityLegalAddressCountry => "entity.legalAddress.country",
-            Field::EntityLegalAddressLine1 => "entity.legalAddress.line1",
-            Field::EntityLegalAddressCity => "entity.legalAddress.city",
-            Field::EntityLegalAddressPostalCode => "entity.legalAddress.postalCode",
-            Field::EntityHqAddressCountry => "entity.headquartersAddress.country",
-            Field::EntityHqAddressLine1 => "entity.headquartersAddress.line1",
-            Field::EntityHqAddressCity => "entity.headquartersAddress.city",
-            Field::EntityHqAddressPostalCode => "entity.headquartersAddress.postalCode",
-            Field::EntityBusinessRegisterNumber => "entity.registeredAs",
-            Field::EntityJurisdiction => "entity.jurisdiction",
-
-            // Registration fields
-            Field::RegistrationStatus => "registration.status",
-            Field::RegistrationInitialRegistrationDate => "registration.initialRegistrationDate",
-            Field::RegistrationLastUpdateDate => "registration.lastUpdateDate",
-            Field::RegistrationNextRenewalDate => "registration.nextRenewalDate",
-            Field::RegistrationManagingLou => "registration.managingLou",
-            Field::ConformityFlag => "conformity_flag",
-
-            // Relationship fields
-            Field::Owns => "owns",
-            Field::OwnedBy => "ownedBy",
-            Field::RelationshipStartDate => "relationship.startDate",
-            Field::RelationshipEndDate => "relationship.endDate",
-            Field::RelationshipStatus => "relationship.status",
-            Field::RelationshipType => "relationship.type",
-
-            // Cross-field search
-            Field::Fulltext => "fulltext",
+        self.info().name
+    }
+
+    /// Returns the human-readable label of the field, as reported by the catalogue (may be empty).
+    #[must_use]
+    pub const fn label(&self) -> &'static str {
+        self.info().label
+    }
+
+    /// Returns the `LEI-CDF` or `RR-CDF` `XPath` of the element backing the field, if the catalogue reports one.
+    #[must_use]
+    pub const fn xpath(&self) -> Option<&'static str> {
+        let xpath = self.info().xpath;
+        if xpath.is_empty() { None } else { Some(xpath) }
+    }
+
+    /// Returns the field backed by the element at the given `XPath`, if any.
+    ///
+    /// Positional predicates are ignored when the exact path is not in the catalogue, so that
+    /// `.../lei:OtherAddress[2]/lei:City` resolves like `.../lei:OtherAddress/lei:City`.
+    #[must_use]
+    pub fn from_xpath(xpath: &str) -> Option<Field> {
+        let find = |xpath: &str| {
+            Field::ALL
+                .iter()
+                .copied()
+                .find(|field| field.xpath() == Some(xpath))
+        };
+        find(xpath).or_else(|| {
+            let mut stripped = String::with_capacity(xpath.len());
+            let mut in_predicate = false;
+            for c in xpath.chars() {
+                match c {
+                    '[' => in_predicate = true,
+                    ']' => in_predicate = false,
+                    _ if !in_predicate => stripped.push(c),
+                    _ => {}
+                }
+            }
+            find(&stripped)
+        })
+    }
+
+    /// Returns the kind of value stored in the field.
+    #[must_use]
+    pub const fn value_kind(&self) -> ValueKind {
+        self.info().kind
+    }
+
+    /// Returns `true` if the field can be used in the `sort` parameter.
+    #[must_use]
+    pub const fn is_sortable(&self) -> bool {
+        self.info().sortable
+    }
+
+    /// Returns the possible values of an enum field, or an empty slice for other fields.
+    #[must_use]
+    pub const fn enum_values(&self) -> &'static [&'static str] {
+        self.info().enum_values
+    }
+
+    /// Returns the filter operators supported by the field.
+    ///
+    /// Where the catalogue does not list operators for a field, the operators are derived from its
+    /// [`ValueKind`] (see [`ValueKind::default_operators`]).
+    #[must_use]
+    pub const fn operators(&self) -> &'static [Operator] {
+        let listed = self.info().operators;
+        if !listed.is_empty() {
+            return listed;
         }
+        self.value_kind().default_operators()
+    }
+
+    /// Returns `true` if the field supports the given filter operator.
+    #[must_use]
+    pub fn supports(&self, operator: Operator) -> bool {
+        self.operators().contains(&operator)
     }
 
     /// Parse a string to a Field, optionally restricting to a set of allowed values.
@@ -223,52 +359,24 @@ impl Field {
     /// Returns [`crate::error::GleifError::ParseError`] if the input string is not a valid field name or is not allowed.
     pub fn parse_with_allowed(input: &str, allowed: Option<&[Field]>) -> Result<Field> {
         let parsed = match input {
-            "lei" => Field::Lei,
-            "bic" => Field::Bic,
-            "isin" => Field::Isin,
-            "entity.legalName" => Field::EntityLegalName,
-            "entity.otherNames" => Field::EntityOtherNames,
-            "entity.legalForm" => Field::EntityLegalForm,
-            "entity.legalForm.id" => Field::EntityLegalFormId,
-            "entity.legalForm.code" => Field::EntityLegalFormCode,
-            "entity.category" => Field::EntityCategory,
-            "entity.legalAddress.country" => Field::EntityLegalAddressCountry,
-            "entity.legalAddress.line1" => Field::EntityLegalAddressLine1,
-            "entity.legalAddress.city" => Field::EntityLegalAddressCity,
-            "entity.legalAddress.postalCode" => Field::EntityLegalAddressPostalCode,
-            "entity.headquartersAddress.country" => Field::EntityHqAddressCountry,
-            "entity.headquartersAddress.line1" => Field::EntityHqAddressLine1,
-            "entity.headquartersAddress.city" => Field::EntityHqAddressCity,
-            "entity.headquartersAddress.postalCode" => Field::EntityHqAddressPostalCode,
-            "entity.registeredAs" => Field::EntityBusinessRegisterNumber,
-            "entity.jurisdiction" => Field::EntityJurisdiction,
-            "registration.status" => Field::RegistrationStatus,
-            "registration.initialRegistrationDate" => Field::RegistrationInitialRegistrationDate,
-            "registration.lastUpdateDate" => Field::RegistrationLastUpdateDate,
-            "registration.nextRenewalDate" => Field::RegistrationNextRenewalDate,
-            "registration.managingLou" => Field::RegistrationManagingLou,
+            // Spelling used by earlier releases of this crate.
             "conformity_flag" => Field::ConformityFlag,
-            "owns" => Field::Owns,
-            "ownedBy" => Field::OwnedBy,
-            "relationship.startDate" => Field::RelationshipStartDate,
-            "relationship.endDate" => Field::RelationshipEndDate,
-            "relationship.status" => Field::RelationshipStatus,
-            "relationship.type" => Field::RelationshipType,
-            "fulltext" => Field::Fulltext,
-            _ => {
-                return Err(GleifError::ParseError {
+            _ => Field::ALL
+                .iter()
+                .copied()
+                .find(|field| field.as_str() == input)
+                .ok_or_else(|| GleifError::ParseError {
                     kind: ParseErrorKind::Field,
                     message: "Unknown field name".to_string(),
-                });
-            }
+                })?,
         };
-        if let Some(allowed) = allowed {
-            if !allowed.contains(&parsed) {
-                return Err(GleifError::ParseError {
-                    kind: ParseErrorKind::Field,
-                    message: "Field not allowed for this operation".to_string(),
-                });
-            }
+        if let Some(allowed) = allowed
+            && !allowed.contains(&parsed)
+        {
+            return Err(GleifError::ParseError {
+                kind: ParseErrorKind::Field,
+                message: "Field not allowed for this operation".to_string(),
+            });
         }
         Ok(parsed)
     }
@@ -297,6 +405,120 @@ impl FromStr for Field {
     }
 }
 
+/// Validation rule of a field, taken from the static [`Field`] metadata or a runtime [`FieldCatalog`].
+#[derive(Debug, Clone)]
+pub(crate) struct FieldRule {
+    name: String,
+    sortable: bool,
+    operators: Vec<Operator>,
+    enum_values: Vec<String>,
+}
+
+impl FieldRule {
+    /// Creates a rule, deriving the operators from the value kind when none are listed.
+    pub(crate) fn new(
+        name: &str,
+        kind: ValueKind,
+        sortable: bool,
+        operators: Vec<Operator>,
+        enum_values: Vec<String>,
+    ) -> Self {
+        let operators = if operators.is_empty() {
+            kind.default_operators().to_vec()
+        } else {
+            operators
+        };
+        Self {
+            name: name.to_string(),
+            sortable,
+            operators,
+            enum_values,
+        }
+    }
+
+    /// Returns the rule of a known [`Field`], naming the field in the error.
+    pub(crate) fn from_name(name: &str) -> Result<Self> {
+        Field::from_str(name)
+            .map(Self::from)
+            .map_err(|_| GleifError::ParseError {
+                kind: ParseErrorKind::Field,
+                message: format!("Unknown field name: {name}"),
+            })
+    }
+
+    /// Checks a `filter[...]` value against the supported operators and enum values of the field.
+    ///
+    /// Comma-separated lists are accepted for any field supporting [`Operator::Match`], as the API treats
+    /// them as a match on any of the values. Enum values are compared case-insensitively.
+    pub(crate) fn check_filter(&self, value: &str) -> Result<()> {
+        let condition = Condition::parse(value);
+        let operator = condition.operator();
+        let supported = self.operators.contains(&operator)
+            || (operator == Operator::In && self.operators.contains(&Operator::Match));
+        if !supported {
+            return Err(GleifError::ParseError {
+                kind: ParseErrorKind::Value,
+                message: format!(
+                    "Operator {operator} is not supported by field {}",
+                    self.name
+                ),
+            });
+        }
+        if self.enum_values.is_empty() {
+            return Ok(());
+        }
+        let values = match &condition {
+            Condition::Eq(value) | Condition::Not(value) => std::slice::from_ref(value),
+            Condition::In(values) | Condition::NotIn(values) => values.as_slice(),
+            _ => &[],
+        };
+        match values.iter().find(|value| {
+            !self
+                .enum_values
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(value))
+        }) {
+            Some(value) => Err(GleifError::ParseError {
+                kind: ParseErrorKind::Value,
+                message: format!(
+                    "Value {value} is not valid for field {}, expected one of: {}",
+                    self.name,
+                    self.enum_values.join(", ")
+                ),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks that the field can be used in the `sort` parameter.
+    pub(crate) fn check_sort(&self) -> Result<()> {
+        if self.sortable {
+            Ok(())
+        } else {
+            Err(GleifError::ParseError {
+                kind: ParseErrorKind::Field,
+                message: format!("Field {} is not sortable", self.name),
+            })
+        }
+    }
+}
+
+impl From<Field> for FieldRule {
+    fn from(field: Field) -> Self {
+        Self::new(
+            field.as_str(),
+            field.value_kind(),
+            field.is_sortable(),
+            field.operators().to_vec(),
+            field
+                .enum_values()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -329,5 +551,70 @@ mod tests {
             Field::RegistrationStatus
         );
         assert!(Field::from_str("not_a_field").is_err());
+        assert_eq!(
+            Field::from_str("conformity_flag").unwrap(),
+            Field::ConformityFlag
+        );
+    }
+
+    #[test]
+    fn test_field_round_trip() {
+        for field in Field::ALL {
+            assert_eq!(Field::from_str(field.as_str()).unwrap(), *field);
+            assert!(!field.operators().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_field_from_xpath() {
+        let prefix = "/lei:LEIData/lei:LEIRecords/lei:LEIRecord";
+        for field in Field::ALL {
+            if let Some(xpath) = field.xpath() {
+                let found = Field::from_xpath(xpath).unwrap();
+                assert_eq!(found.xpath(), Some(xpath));
+            }
+        }
+        assert_eq!(
+            Field::from_xpath(&format!("{prefix}/lei:Entity/lei:EntityStatus")),
+            Some(Field::EntityStatus)
+        );
+        assert_eq!(
+            Field::from_xpath(&format!(
+                "{prefix}/lei:Entity/lei:LegalAddress/lei:AdditionalAddressLine[1]"
+            )),
+            Some(Field::EntityLegalAddressLine2)
+        );
+        assert_eq!(
+            Field::from_xpath(&format!("{prefix}/lei:Entity[1]/lei:EntityStatus")),
+            Some(Field::EntityStatus)
+        );
+        assert_eq!(Field::from_xpath("/lei:Unknown"), None);
+    }
+
+    #[test]
+    fn test_field_metadata() {
+        assert_eq!(Field::EntityCategory.value_kind(), ValueKind::Enum);
+        assert_eq!(
+            Field::RegistrationNextRenewalDate.value_kind(),
+            ValueKind::Date
+        );
+        assert_eq!(Field::BranchCount.value_kind(), ValueKind::Number);
+        assert!(Field::EntityLegalName.is_sortable());
+        assert!(!Field::Fulltext.is_sortable());
+        assert!(Field::EntityStatus.supports(Operator::In));
+        assert!(!Field::Lei.supports(Operator::GreaterThan));
+        // Operators are derived from the value kind when the catalogue does not list them.
+        assert!(Field::EntityLegalAddressCity.supports(Operator::NotMatch));
+        assert_eq!(
+            Field::EntityExpirationReason.enum_values(),
+            &["DISSOLVED", "CORPORATE_ACTION", "OTHER"]
+        );
+        assert_eq!(Operator::from_str("IN_RANGE").unwrap(), Operator::InRange);
+        assert_eq!(
+            Field::EntityLegalName.xpath(),
+            Some("/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalName")
+        );
+        assert_eq!(Field::Bic.xpath(), None);
+        assert_eq!(ValueKind::Date.to_string(), "DATE");
     }
 }
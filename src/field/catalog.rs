@@ -0,0 +1,3364 @@
+//! Field catalogue of the GLEIF API, derived from the `fields` endpoint.
+//!
+//! The variants, names and metadata in this file mirror `tests/data/fields/fields_all.json`, followed by
+//! the relationship record filters, which are not part of the catalogue.
+//!
+//! This file is generated by `cargo xtask codegen`. Do not edit it by hand.
+
+use super::{Operator, ValueKind};
+
+/// Enum for known GLEIF API field names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Field {
+    /// BICs (`bic`).
+    Bic,
+    /// Branch Count (`branchCount`).
+    BranchCount,
+    /// Branch City (`branches.addresses.city`).
+    BranchesAddressesCity,
+    /// Branch Country (`branches.addresses.country`).
+    BranchesAddressesCountry,
+    /// Branch Postal Codes (`branches.addresses.postalCode`).
+    BranchesAddressesPostalCode,
+    /// Branch Region (`branches.addresses.region`).
+    BranchesAddressesRegion,
+    /// Branch Entity Status (`branches.entityStatus`).
+    BranchesEntityStatus,
+    /// Branch Legal Name (`branches.legalName`).
+    BranchesLegalName,
+    /// Branch LEI (`branches.lei`).
+    BranchesLei,
+    /// Branch LEI Issuer (`branches.managingLou`).
+    BranchesManagingLou,
+    /// Branch Names (`branches.names`).
+    BranchesNames,
+    /// Branch Valid From (`branches.validFrom`).
+    BranchesValidFrom,
+    /// Branch Valid To (`branches.validTo`).
+    BranchesValidTo,
+    /// Policy Conformity Flag (`conformityFlag`).
+    ConformityFlag,
+    /// Direct child Count (`directChildCount`).
+    DirectChildCount,
+    /// Direct Child City (`directChildren.addresses.city`).
+    DirectChildrenAddressesCity,
+    /// Direct Child Country (`directChildren.addresses.country`).
+    DirectChildrenAddressesCountry,
+    /// Direct Child Postal Codes (`directChildren.addresses.postalCode`).
+    DirectChildrenAddressesPostalCode,
+    /// Direct Child Region (`directChildren.addresses.region`).
+    DirectChildrenAddressesRegion,
+    /// Direct Child Entity Status (`directChildren.entityStatus`).
+    DirectChildrenEntityStatus,
+    /// Direct Child Legal Name (`directChildren.legalName`).
+    DirectChildrenLegalName,
+    /// Direct Child LEI (`directChildren.lei`).
+    DirectChildrenLei,
+    /// Direct Child LEI Issuer (`directChildren.managingLou`).
+    DirectChildrenManagingLou,
+    /// Direct Child Names (`directChildren.names`).
+    DirectChildrenNames,
+    /// Direct Child Valid From (`directChildren.validFrom`).
+    DirectChildrenValidFrom,
+    /// Direct Child Valid To (`directChildren.validTo`).
+    DirectChildrenValidTo,
+    /// Direct Parent City (`directParent.addresses.city`).
+    DirectParentAddressesCity,
+    /// Direct Parent Country (`directParent.addresses.country`).
+    DirectParentAddressesCountry,
+    /// Direct Parent Postal Codes (`directParent.addresses.postalCode`).
+    DirectParentAddressesPostalCode,
+    /// Direct Parent Region (`directParent.addresses.region`).
+    DirectParentAddressesRegion,
+    /// Direct Parent Entity Status (`directParent.entityStatus`).
+    DirectParentEntityStatus,
+    /// Direct Parent Exception Reason (`directParent.exception.reason`).
+    DirectParentExceptionReason,
+    /// Direct Parent Exception Valid From (`directParent.exception.validFrom`).
+    DirectParentExceptionValidFrom,
+    /// Direct Parent Exception Valid To (`directParent.exception.validTo`).
+    DirectParentExceptionValidTo,
+    /// Direct Parent Legal Name (`directParent.legalName`).
+    DirectParentLegalName,
+    /// Direct Parent LEI (`directParent.lei`).
+    DirectParentLei,
+    /// Direct Parent LEI Issuer (`directParent.managingLou`).
+    DirectParentManagingLou,
+    /// Direct Parent Names (`directParent.names`).
+    DirectParentNames,
+    /// Direct Parent Valid From (`directParent.validFrom`).
+    DirectParentValidFrom,
+    /// Direct Parent Valid To (`directParent.validTo`).
+    DirectParentValidTo,
+    /// Addresses (`entity.addresses`).
+    EntityAddresses,
+    /// `entity.addresses.addressLines`.
+    EntityAddressesAddressLines,
+    /// `entity.addresses.addressNumber`.
+    EntityAddressesAddressNumber,
+    /// `entity.addresses.addressNumberWithinBuilding`.
+    EntityAddressesAddressNumberWithinBuilding,
+    /// City (`entity.addresses.city`).
+    EntityAddressesCity,
+    /// Country (`entity.addresses.country`).
+    EntityAddressesCountry,
+    /// CDF Field (`entity.addresses.fieldType`).
+    EntityAddressesFieldType,
+    /// `entity.addresses.language`.
+    EntityAddressesLanguage,
+    /// `entity.addresses.mailRouting`.
+    EntityAddressesMailRouting,
+    /// Postal Code (`entity.addresses.postalCode`).
+    EntityAddressesPostalCode,
+    /// Region (`entity.addresses.region`).
+    EntityAddressesRegion,
+    /// `entity.addresses.type`.
+    EntityAddressesType,
+    /// Associated Entity (`entity.associatedEntity`).
+    EntityAssociatedEntity,
+    /// Associated LEI (`entity.associatedEntity.lei`).
+    EntityAssociatedEntityLei,
+    /// Associated Entity Name (`entity.associatedEntity.name`).
+    EntityAssociatedEntityName,
+    /// General Category (`entity.category`).
+    EntityCategory,
+    /// Entity Creation Date (`entity.creationDate`).
+    EntityCreationDate,
+    /// Expiration Date (`entity.expiration.date`).
+    EntityExpirationDate,
+    /// Expiration Reason (`entity.expiration.reason`).
+    EntityExpirationReason,
+    /// Headquarters Address Number (`entity.headquartersAddress.addressNumber`).
+    EntityHqAddressAddressNumber,
+    /// Headquarters Address Number within Building (`entity.headquartersAddress.addressNumberWithinBuilding`).
+    EntityHqAddressAddressNumberWithinBuilding,
+    /// Additional Headquarters Address Line (`entity.headquartersAddress.addresslines`).
+    EntityHqAddressAddressLines,
+    /// Headquarters Address City (`entity.headquartersAddress.city`).
+    EntityHqAddressCity,
+    /// Headquarters Address Country (`entity.headquartersAddress.country`).
+    EntityHqAddressCountry,
+    /// Language of Headquarters Address (`entity.headquartersAddress.language`).
+    EntityHqAddressLanguage,
+    /// First Headquarters Address Line (`entity.headquartersAddress.line1`).
+    EntityHqAddressLine1,
+    /// Additional Headquarters Address Line (`entity.headquartersAddress.line2`).
+    EntityHqAddressLine2,
+    /// Additional Headquarters Address Line (`entity.headquartersAddress.line3`).
+    EntityHqAddressLine3,
+    /// Additional Headquarters Address Line (`entity.headquartersAddress.line4`).
+    EntityHqAddressLine4,
+    /// Headquarters Address Mail Routing (`entity.headquartersAddress.mailRouting`).
+    EntityHqAddressMailRouting,
+    /// Headquarters Address Postal Code (`entity.headquartersAddress.postalCode`).
+    EntityHqAddressPostalCode,
+    /// Headquarters Address Region (`entity.headquartersAddress.region`).
+    EntityHqAddressRegion,
+    /// Jurisdiction of Formation (`entity.jurisdiction`).
+    EntityJurisdiction,
+    /// Additional Legal Address Line (`entity.legalAddress.addressLines`).
+    EntityLegalAddressAddressLines,
+    /// Legal Address Number (`entity.legalAddress.addressNumber`).
+    EntityLegalAddressAddressNumber,
+    /// Legal Address Number within Building (`entity.legalAddress.addressNumberWithinBuilding`).
+    EntityLegalAddressAddressNumberWithinBuilding,
+    /// Legal Address City (`entity.legalAddress.city`).
+    EntityLegalAddressCity,
+    /// Legal Address Country (`entity.legalAddress.country`).
+    EntityLegalAddressCountry,
+    /// Language of Legal Address (`entity.legalAddress.language`).
+    EntityLegalAddressLanguage,
+    /// First Legal Address Line (`entity.legalAddress.line1`).
+    EntityLegalAddressLine1,
+    /// Additional Legal Address Line (`entity.legalAddress.line2`).
+    EntityLegalAddressLine2,
+    /// Additional Legal Address Line (`entity.legalAddress.line3`).
+    EntityLegalAddressLine3,
+    /// Additional Legal Address Line (`entity.legalAddress.line4`).
+    EntityLegalAddressLine4,
+    /// Legal Address Mail Routing (`entity.legalAddress.mailRouting`).
+    EntityLegalAddressMailRouting,
+    /// Legal Address Postal Code (`entity.legalAddress.postalCode`).
+    EntityLegalAddressPostalCode,
+    /// Legal Address Region (`entity.legalAddress.region`).
+    EntityLegalAddressRegion,
+    /// Entity Legal Form (`entity.legalForm`).
+    EntityLegalForm,
+    /// Entity Legal Form Code (`entity.legalForm.code`).
+    EntityLegalFormCode,
+    /// Entity Legal Form (other) (`entity.legalForm.other`).
+    EntityLegalFormOther,
+    /// (Primary) Legal Name (`entity.legalName`).
+    EntityLegalName,
+    /// Language of (Primary) Legal Name (`entity.legalName.language`).
+    EntityLegalNameLanguage,
+    /// All Names (`entity.names`).
+    EntityNames,
+    /// Other Entity Name (`entity.otherNames`).
+    EntityOtherNames,
+    /// Language of Other Entity Name (`entity.otherNames.language`).
+    EntityOtherNamesLanguage,
+    /// Type of Other Entity Name (`entity.otherNames.type`).
+    EntityOtherNamesType,
+    /// Registered As (`entity.registeredAs`).
+    EntityBusinessRegisterNumber,
+    /// Registered At (`entity.registeredAt`).
+    EntityRegisteredAt,
+    /// Registered At (`entity.registeredAt.id`).
+    EntityRegisteredAtId,
+    /// Registered At (other) (`entity.registeredAt.other`).
+    EntityRegisteredAtOther,
+    /// Entity Status (`entity.status`).
+    EntityStatus,
+    /// Sub Category (`entity.subCategory`).
+    EntitySubCategory,
+    /// Replaced By (`entity.successorEntities`).
+    EntitySuccessorEntities,
+    /// Successor Entity Lang (`entity.successorEntities.lang`).
+    EntitySuccessorEntitiesLang,
+    /// Successor Entity LEI (`entity.successorEntities.lei`).
+    EntitySuccessorEntitiesLei,
+    /// Successor Entity Name (`entity.successorEntities.name`).
+    EntitySuccessorEntitiesName,
+    /// Successor LEI (`entity.successorEntity.lei`).
+    EntitySuccessorEntityLei,
+    /// Successor Entity Name (`entity.successorEntity.name`).
+    EntitySuccessorEntityName,
+    /// Transliterated Other Entity Name (`entity.transliteratedOtherNames`).
+    EntityTransliteratedOtherNames,
+    /// Language of Transliterated Other Entity Name (`entity.transliteratedOtherNames.language`).
+    EntityTransliteratedOtherNamesLanguage,
+    /// Type of Transliterated Other Entity Name (`entity.transliteratedOtherNames.type`).
+    EntityTransliteratedOtherNamesType,
+    /// Feeder Fund Count (`feederFundCount`).
+    FeederFundCount,
+    /// Feeder Fund City (`feederFunds.addresses.city`).
+    FeederFundsAddressesCity,
+    /// Feeder Fund Country (`feederFunds.addresses.country`).
+    FeederFundsAddressesCountry,
+    /// Feeder Fund Postal Codes (`feederFunds.addresses.postalCode`).
+    FeederFundsAddressesPostalCode,
+    /// Feeder Fund Region (`feederFunds.addresses.region`).
+    FeederFundsAddressesRegion,
+    /// Feeder Fund Entity Status (`feederFunds.entityStatus`).
+    FeederFundsEntityStatus,
+    /// Feeder Fund Legal Name (`feederFunds.legalName`).
+    FeederFundsLegalName,
+    /// Feeder Fund LEI (`feederFunds.lei`).
+    FeederFundsLei,
+    /// Feeder Fund LEI Issuer (`feederFunds.managingLou`).
+    FeederFundsManagingLou,
+    /// Feeder Fund Names (`feederFunds.names`).
+    FeederFundsNames,
+    /// Feeder Fund Valid From (`feederFunds.validFrom`).
+    FeederFundsValidFrom,
+    /// Feeder Fund Valid To (`feederFunds.validTo`).
+    FeederFundsValidTo,
+    /// Find LEIs (`fulltext`).
+    Fulltext,
+    /// Fund Manager City (`fundManager.addresses.city`).
+    FundManagerAddressesCity,
+    /// Fund Manager Country (`fundManager.addresses.country`).
+    FundManagerAddressesCountry,
+    /// Fund Manager Postal Codes (`fundManager.addresses.postalCode`).
+    FundManagerAddressesPostalCode,
+    /// Fund Manager Region (`fundManager.addresses.region`).
+    FundManagerAddressesRegion,
+    /// Fund Manager Entity Status (`fundManager.entityStatus`).
+    FundManagerEntityStatus,
+    /// Fund Manager Legal Name (`fundManager.legalName`).
+    FundManagerLegalName,
+    /// Fund Manager LEI (`fundManager.lei`).
+    FundManagerLei,
+    /// Fund Manager LEI Issuer (`fundManager.managingLou`).
+    FundManagerManagingLou,
+    /// Fund Manager Names (`fundManager.names`).
+    FundManagerNames,
+    /// Fund Manager Valid From (`fundManager.validFrom`).
+    FundManagerValidFrom,
+    /// Fund Manager Valid To (`fundManager.validTo`).
+    FundManagerValidTo,
+    /// Head office City (`headOffice.addresses.city`).
+    HeadOfficeAddressesCity,
+    /// Head office Country (`headOffice.addresses.country`).
+    HeadOfficeAddressesCountry,
+    /// Head office Postal Codes (`headOffice.addresses.postalCode`).
+    HeadOfficeAddressesPostalCode,
+    /// Head office Region (`headOffice.addresses.region`).
+    HeadOfficeAddressesRegion,
+    /// Head office Entity Status (`headOffice.entityStatus`).
+    HeadOfficeEntityStatus,
+    /// Head office Legal Name (`headOffice.legalName`).
+    HeadOfficeLegalName,
+    /// Head office LEI (`headOffice.lei`).
+    HeadOfficeLei,
+    /// Head office LEI Issuer (`headOffice.managingLou`).
+    HeadOfficeManagingLou,
+    /// Head office Names (`headOffice.names`).
+    HeadOfficeNames,
+    /// Head office Valid From (`headOffice.validFrom`).
+    HeadOfficeValidFrom,
+    /// Head office Valid To (`headOffice.validTo`).
+    HeadOfficeValidTo,
+    /// ISIN Code (`isin`).
+    Isin,
+    /// LEI Code (`lei`).
+    Lei,
+    /// Managed Fund Count (`managedFundCount`).
+    ManagedFundCount,
+    /// Managed Fund City (`managedFunds.addresses.city`).
+    ManagedFundsAddressesCity,
+    /// Managed Fund Country (`managedFunds.addresses.country`).
+    ManagedFundsAddressesCountry,
+    /// Managed Fund Postal Codes (`managedFunds.addresses.postalCode`).
+    ManagedFundsAddressesPostalCode,
+    /// Managed Fund Region (`managedFunds.addresses.region`).
+    ManagedFundsAddressesRegion,
+    /// Managed Fund Entity Status (`managedFunds.entityStatus`).
+    ManagedFundsEntityStatus,
+    /// Managed Fund Legal Name (`managedFunds.legalName`).
+    ManagedFundsLegalName,
+    /// Managed Fund LEI (`managedFunds.lei`).
+    ManagedFundsLei,
+    /// Managed Fund LEI Issuer (`managedFunds.managingLou`).
+    ManagedFundsManagingLou,
+    /// Managed Fund Names (`managedFunds.names`).
+    ManagedFundsNames,
+    /// Managed Fund Valid From (`managedFunds.validFrom`).
+    ManagedFundsValidFrom,
+    /// Managed Fund Valid To (`managedFunds.validTo`).
+    ManagedFundsValidTo,
+    /// Master Fund City (`masterFund.addresses.city`).
+    MasterFundAddressesCity,
+    /// Master Fund Country (`masterFund.addresses.country`).
+    MasterFundAddressesCountry,
+    /// Master Fund Postal Codes (`masterFund.addresses.postalCode`).
+    MasterFundAddressesPostalCode,
+    /// Master Fund Region (`masterFund.addresses.region`).
+    MasterFundAddressesRegion,
+    /// Master Fund Entity Status (`masterFund.entityStatus`).
+    MasterFundEntityStatus,
+    /// Master Fund Legal Name (`masterFund.legalName`).
+    MasterFundLegalName,
+    /// Master Fund LEI (`masterFund.lei`).
+    MasterFundLei,
+    /// Master Fund LEI Issuer (`masterFund.managingLou`).
+    MasterFundManagingLou,
+    /// Master Fund Names (`masterFund.names`).
+    MasterFundNames,
+    /// Master Fund Valid From (`masterFund.validFrom`).
+    MasterFundValidFrom,
+    /// Master Fund Valid To (`masterFund.validTo`).
+    MasterFundValidTo,
+    /// `meta.validFrom`.
+    MetaValidFrom,
+    /// `meta.validTo`.
+    MetaValidTo,
+    /// MICs (`mic`).
+    Mic,
+    /// OCID (`ocid`).
+    Ocid,
+    /// Who is Owned By... (`ownedBy`).
+    OwnedBy,
+    /// Who Owns... (`owns`).
+    Owns,
+    /// QCCs (`qcc`).
+    Qcc,
+    /// Corroboration Level (`registration.corroborationLevel`).
+    RegistrationCorroborationLevel,
+    /// Registration Date (`registration.initialRegistrationDate`).
+    RegistrationInitialRegistrationDate,
+    /// Last Update (`registration.lastUpdateDate`).
+    RegistrationLastUpdateDate,
+    /// LEI Issuer (`registration.managingLou`).
+    RegistrationManagingLou,
+    /// Next Renewal (`registration.nextRenewalDate`).
+    RegistrationNextRenewalDate,
+    /// Data Validated As (`registration.otherValidationAuthorities.validatedAs`).
+    RegistrationOtherValidationAuthoritiesValidatedAs,
+    /// Data Validated At (ID) (`registration.otherValidationAuthorities.validatedAt.id`).
+    RegistrationOtherValidationAuthoritiesValidatedAtId,
+    /// Data Validated At (other) (`registration.otherValidationAuthorities.validatedAt.other`).
+    RegistrationOtherValidationAuthoritiesValidatedAtOther,
+    /// Status (`registration.status`).
+    RegistrationStatus,
+    /// Data Validated As (`registration.validatedAs`).
+    RegistrationValidatedAs,
+    /// Data Validated At (`registration.validatedAt`).
+    RegistrationValidatedAt,
+    /// Data Validated At (ID) (`registration.validatedAt.id`).
+    RegistrationValidatedAtId,
+    /// Data Validated At (other) (`registration.validatedAt.other`).
+    RegistrationValidatedAtOther,
+    /// S&P Global Company ID (`spglobal`).
+    Spglobal,
+    /// Sub-Fund Count (`subFundCount`).
+    SubFundCount,
+    /// Sub-Fund City (`subFunds.addresses.city`).
+    SubFundsAddressesCity,
+    /// Sub-Fund Country (`subFunds.addresses.country`).
+    SubFundsAddressesCountry,
+    /// Sub-Fund Postal Codes (`subFunds.addresses.postalCode`).
+    SubFundsAddressesPostalCode,
+    /// Sub-Fund Region (`subFunds.addresses.region`).
+    SubFundsAddressesRegion,
+    /// Sub-Fund Entity Status (`subFunds.entityStatus`).
+    SubFundsEntityStatus,
+    /// Sub-Fund Legal Name (`subFunds.legalName`).
+    SubFundsLegalName,
+    /// Sub-Fund LEI (`subFunds.lei`).
+    SubFundsLei,
+    /// Sub-Fund LEI Issuer (`subFunds.managingLou`).
+    SubFundsManagingLou,
+    /// Sub-Fund Names (`subFunds.names`).
+    SubFundsNames,
+    /// Sub-Fund Valid From (`subFunds.validFrom`).
+    SubFundsValidFrom,
+    /// Sub-Fund Valid To (`subFunds.validTo`).
+    SubFundsValidTo,
+    /// Ultimate Child Count (`ultimateChildCount`).
+    UltimateChildCount,
+    /// Ultimate Child City (`ultimateChildren.addresses.city`).
+    UltimateChildrenAddressesCity,
+    /// Ultimate Child Country (`ultimateChildren.addresses.country`).
+    UltimateChildrenAddressesCountry,
+    /// Ultimate Child Postal Codes (`ultimateChildren.addresses.postalCode`).
+    UltimateChildrenAddressesPostalCode,
+    /// Ultimate Child Region (`ultimateChildren.addresses.region`).
+    UltimateChildrenAddressesRegion,
+    /// Ultimate Child Entity Status (`ultimateChildren.entityStatus`).
+    UltimateChildrenEntityStatus,
+    /// Ultimate Child Legal Name (`ultimateChildren.legalName`).
+    UltimateChildrenLegalName,
+    /// Ultimate Child LEI (`ultimateChildren.lei`).
+    UltimateChildrenLei,
+    /// Ultimate Child LEI Issuer (`ultimateChildren.managingLou`).
+    UltimateChildrenManagingLou,
+    /// Ultimate Child Names (`ultimateChildren.names`).
+    UltimateChildrenNames,
+    /// Ultimate Child Valid From (`ultimateChildren.validFrom`).
+    UltimateChildrenValidFrom,
+    /// Ultimate Child Valid To (`ultimateChildren.validTo`).
+    UltimateChildrenValidTo,
+    /// Ultimate Parent City (`ultimateParent.addresses.city`).
+    UltimateParentAddressesCity,
+    /// Ultimate Parent Country (`ultimateParent.addresses.country`).
+    UltimateParentAddressesCountry,
+    /// Ultimate Parent Postal Codes (`ultimateParent.addresses.postalCode`).
+    UltimateParentAddressesPostalCode,
+    /// Ultimate Parent Region (`ultimateParent.addresses.region`).
+    UltimateParentAddressesRegion,
+    /// Ultimate Parent Entity Status (`ultimateParent.entityStatus`).
+    UltimateParentEntityStatus,
+    /// Ultimate Parent Exception Reason (`ultimateParent.exception.reason`).
+    UltimateParentExceptionReason,
+    /// Direct Parent Exception Valid From (`ultimateParent.exception.validFrom`).
+    UltimateParentExceptionValidFrom,
+    /// Direct Parent Exception Valid To (`ultimateParent.exception.validTo`).
+    UltimateParentExceptionValidTo,
+    /// Ultimate Parent Legal Name (`ultimateParent.legalName`).
+    UltimateParentLegalName,
+    /// Ultimate Parent LEI (`ultimateParent.lei`).
+    UltimateParentLei,
+    /// Ultimate Parent LEI Issuer (`ultimateParent.managingLou`).
+    UltimateParentManagingLou,
+    /// Ultimate Parent Names (`ultimateParent.names`).
+    UltimateParentNames,
+    /// Ultimate Parent Valid From (`ultimateParent.validFrom`).
+    UltimateParentValidFrom,
+    /// Ultimate Parent Valid To (`ultimateParent.validTo`).
+    UltimateParentValidTo,
+    /// Umbrella Fund City (`umbrellaFund.addresses.city`).
+    UmbrellaFundAddressesCity,
+    /// Umbrella Fund Country (`umbrellaFund.addresses.country`).
+    UmbrellaFundAddressesCountry,
+    /// Umbrella Fund Postal Codes (`umbrellaFund.addresses.postalCode`).
+    UmbrellaFundAddressesPostalCode,
+    /// Umbrella Fund Region (`umbrellaFund.addresses.region`).
+    UmbrellaFundAddressesRegion,
+    /// Umbrella Fund Entity Status (`umbrellaFund.entityStatus`).
+    UmbrellaFundEntityStatus,
+    /// Umbrella Fund Legal Name (`umbrellaFund.legalName`).
+    UmbrellaFundLegalName,
+    /// Umbrella Fund LEI (`umbrellaFund.lei`).
+    UmbrellaFundLei,
+    /// Umbrella Fund LEI Issuer (`umbrellaFund.managingLou`).
+    UmbrellaFundManagingLou,
+    /// Umbrella Fund Names (`umbrellaFund.names`).
+    UmbrellaFundNames,
+    /// Umbrella Fund Valid From (`umbrellaFund.validFrom`).
+    UmbrellaFundValidFrom,
+    /// Umbrella Fund Valid To (`umbrellaFund.validTo`).
+    UmbrellaFundValidTo,
+    // Relationship record filters and aliases (not part of the field catalogue)
+    /// Entity Legal Form ID (`entity.legalForm.id`).
+    EntityLegalFormId,
+    /// Relationship Start Date (`relationship.startDate`).
+    RelationshipStartDate,
+    /// Relationship End Date (`relationship.endDate`).
+    RelationshipEndDate,
+    /// Relationship Status (`relationship.status`).
+    RelationshipStatus,
+    /// Relationship Type (`relationship.type`).
+    RelationshipType,
+}
+
+/// Private catalogue entry describing a [`Field`].
+pub(super) struct FieldInfo {
+    pub(super) name: &'static str,
+    pub(super) label: &'static str,
+    pub(super) xpath: &'static str,
+    pub(super) kind: ValueKind,
+    pub(super) sortable: bool,
+    pub(super) operators: &'static [Operator],
+    pub(super) enum_values: &'static [&'static str],
+}
+
+/// Private catalogue of all fields, indexed by the [`Field`] discriminant.
+pub(super) const CATALOG: &[FieldInfo] = &[
+    FieldInfo {
+        name: "bic",
+        label: "BICs",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "branchCount",
+        label: "Branch Count",
+        xpath: "",
+        kind: ValueKind::Number,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "branches.addresses.city",
+        label: "Branch City",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "branches.addresses.country",
+        label: "Branch Country",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "branches.addresses.postalCode",
+        label: "Branch Postal Codes",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "branches.addresses.region",
+        label: "Branch Region",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "branches.entityStatus",
+        label: "Branch Entity Status",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &["ACTIVE", "INACTIVE", "NULL"],
+    },
+    FieldInfo {
+        name: "branches.legalName",
+        label: "Branch Legal Name",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "branches.lei",
+        label: "Branch LEI",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "branches.managingLou",
+        label: "Branch LEI Issuer",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "branches.names",
+        label: "Branch Names",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "branches.validFrom",
+        label: "Branch Valid From",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "branches.validTo",
+        label: "Branch Valid To",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "conformityFlag",
+        label: "Policy Conformity Flag",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &["CONFORMING", "NON_CONFORMING", "NOT_APPLICABLE"],
+    },
+    FieldInfo {
+        name: "directChildCount",
+        label: "Direct child Count",
+        xpath: "",
+        kind: ValueKind::Number,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directChildren.addresses.city",
+        label: "Direct Child City",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directChildren.addresses.country",
+        label: "Direct Child Country",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directChildren.addresses.postalCode",
+        label: "Direct Child Postal Codes",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directChildren.addresses.region",
+        label: "Direct Child Region",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directChildren.entityStatus",
+        label: "Direct Child Entity Status",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &["ACTIVE", "INACTIVE", "NULL"],
+    },
+    FieldInfo {
+        name: "directChildren.legalName",
+        label: "Direct Child Legal Name",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directChildren.lei",
+        label: "Direct Child LEI",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directChildren.managingLou",
+        label: "Direct Child LEI Issuer",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directChildren.names",
+        label: "Direct Child Names",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directChildren.validFrom",
+        label: "Direct Child Valid From",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directChildren.validTo",
+        label: "Direct Child Valid To",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directParent.addresses.city",
+        label: "Direct Parent City",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directParent.addresses.country",
+        label: "Direct Parent Country",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directParent.addresses.postalCode",
+        label: "Direct Parent Postal Codes",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directParent.addresses.region",
+        label: "Direct Parent Region",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directParent.entityStatus",
+        label: "Direct Parent Entity Status",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &["ACTIVE", "INACTIVE", "NULL"],
+    },
+    FieldInfo {
+        name: "directParent.exception.reason",
+        label: "Direct Parent Exception Reason",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[
+            "NON_PUBLIC",
+            "NO_LEI",
+            "NATURAL_PERSONS",
+            "NON_CONSOLIDATING",
+            "NO_KNOWN_PERSON",
+            "LEGAL_OBSTACLES",
+            "CONSENT_NOT_OBTAINED",
+            "BINDING_LEGAL_COMMITMENTS",
+            "DETRIMENT_NOT_EXCLUDED",
+            "DISCOLSURE_DETRIMENTAL",
+        ],
+    },
+    FieldInfo {
+        name: "directParent.exception.validFrom",
+        label: "Direct Parent Exception Valid From",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directParent.exception.validTo",
+        label: "Direct Parent Exception Valid To",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directParent.legalName",
+        label: "Direct Parent Legal Name",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directParent.lei",
+        label: "Direct Parent LEI",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directParent.managingLou",
+        label: "Direct Parent LEI Issuer",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directParent.names",
+        label: "Direct Parent Names",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directParent.validFrom",
+        label: "Direct Parent Valid From",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "directParent.validTo",
+        label: "Direct Parent Valid To",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.addresses",
+        label: "Addresses",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.addresses.addressLines",
+        label: "",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.addresses.addressNumber",
+        label: "",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.addresses.addressNumberWithinBuilding",
+        label: "",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.addresses.city",
+        label: "City",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.addresses.country",
+        label: "Country",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.addresses.fieldType",
+        label: "CDF Field",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.addresses.language",
+        label: "",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.addresses.mailRouting",
+        label: "",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.addresses.postalCode",
+        label: "Postal Code",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.addresses.region",
+        label: "Region",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.addresses.type",
+        label: "",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.associatedEntity",
+        label: "Associated Entity",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.associatedEntity.lei",
+        label: "Associated LEI",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:AssociatedEntity/lei:AssociatedLEI",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.associatedEntity.name",
+        label: "Associated Entity Name",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:AssociatedEntity/lei:AssociatedEntityName",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.category",
+        label: "General Category",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:EntityCategory",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[
+            "BRANCH",
+            "FUND",
+            "SOLE_PROPRIETOR",
+            "GENERAL",
+            "RESIDENT_GOVERNMENT_ENTITY",
+            "INTERNATIONAL_ORGANIZATION",
+        ],
+    },
+    FieldInfo {
+        name: "entity.creationDate",
+        label: "Entity Creation Date",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:EntityCreationDate",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.expiration.date",
+        label: "Expiration Date",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:EntityExpirationDate",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.expiration.reason",
+        label: "Expiration Reason",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:EntityExpirationReason",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &["DISSOLVED", "CORPORATE_ACTION", "OTHER"],
+    },
+    FieldInfo {
+        name: "entity.headquartersAddress.addressNumber",
+        label: "Headquarters Address Number",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:HeadquartersAddress/lei:AddressNumber",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.headquartersAddress.addressNumberWithinBuilding",
+        label: "Headquarters Address Number within Building",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:HeadquartersAddress/lei:AddressNumberWithinBuilding",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.headquartersAddress.addresslines",
+        label: "Additional Headquarters Address Line",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:HeadquartersAddress/lei:AdditionalAddressLine",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.headquartersAddress.city",
+        label: "Headquarters Address City",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:HeadquartersAddress/lei:City",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.headquartersAddress.country",
+        label: "Headquarters Address Country",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:HeadquartersAddress/lei:Country",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.headquartersAddress.language",
+        label: "Language of Headquarters Address",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:HeadquartersAddress/@xml:lang",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.headquartersAddress.line1",
+        label: "First Headquarters Address Line",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:HeadquartersAddress/lei:FirstAddressLine",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.headquartersAddress.line2",
+        label: "Additional Headquarters Address Line",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:HeadquartersAddress/lei:AdditionalAddressLine[1]",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.headquartersAddress.line3",
+        label: "Additional Headquarters Address Line",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:HeadquartersAddress/lei:AdditionalAddressLine[2]",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.headquartersAddress.line4",
+        label: "Additional Headquarters Address Line",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:HeadquartersAddress/lei:AdditionalAddressLine[3]",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.headquartersAddress.mailRouting",
+        label: "Headquarters Address Mail Routing",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:HeadquartersAddress/lei:MailRouting",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.headquartersAddress.postalCode",
+        label: "Headquarters Address Postal Code",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:HeadquartersAddress/lei:PostalCode",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.headquartersAddress.region",
+        label: "Headquarters Address Region",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:HeadquartersAddress/lei:Region",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.jurisdiction",
+        label: "Jurisdiction of Formation",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalJurisdiction",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalAddress.addressLines",
+        label: "Additional Legal Address Line",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalAddress/lei:AdditionalAddressLine",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalAddress.addressNumber",
+        label: "Legal Address Number",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalAddress/lei:AddressNumber",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalAddress.addressNumberWithinBuilding",
+        label: "Legal Address Number within Building",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalAddress/lei:AddressNumberWithinBuilding",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalAddress.city",
+        label: "Legal Address City",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalAddress/lei:City",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalAddress.country",
+        label: "Legal Address Country",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalAddress/lei:Country",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalAddress.language",
+        label: "Language of Legal Address",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalAddress/@xml:lang",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalAddress.line1",
+        label: "First Legal Address Line",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalAddress/lei:FirstAddressLine",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalAddress.line2",
+        label: "Additional Legal Address Line",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalAddress/lei:AdditionalAddressLine[1]",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalAddress.line3",
+        label: "Additional Legal Address Line",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalAddress/lei:AdditionalAddressLine[2]",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalAddress.line4",
+        label: "Additional Legal Address Line",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalAddress/lei:AdditionalAddressLine[3]",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalAddress.mailRouting",
+        label: "Legal Address Mail Routing",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalAddress/lei:MailRouting",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalAddress.postalCode",
+        label: "Legal Address Postal Code",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalAddress/lei:PostalCode",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalAddress.region",
+        label: "Legal Address Region",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalAddress/lei:Region",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalForm",
+        label: "Entity Legal Form",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalForm.code",
+        label: "Entity Legal Form Code",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalForm/lei:EntityLegalFormCode",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalForm.other",
+        label: "Entity Legal Form (other)",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalForm/lei:OtherLegalForm",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalName",
+        label: "(Primary) Legal Name",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalName",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalName.language",
+        label: "Language of (Primary) Legal Name",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalName/@xml:lang",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.names",
+        label: "All Names",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.otherNames",
+        label: "Other Entity Name",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:OtherEntityNames/lei:OtherEntityName",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.otherNames.language",
+        label: "Language of Other Entity Name",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:OtherEntityNames/lei:OtherEntityName/@xml:lang",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.otherNames.type",
+        label: "Type of Other Entity Name",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:OtherEntityNames/lei:OtherEntityName/@type",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[],
+        enum_values: &[
+            "ALTERNATIVE_LANGUAGE_LEGAL_NAME",
+            "PREVIOUS_LEGAL_NAME",
+            "TRADING_OR_OPERATING_NAME",
+        ],
+    },
+    FieldInfo {
+        name: "entity.registeredAs",
+        label: "Registered As",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:RegistrationAuthority/lei:RegistrationAuthorityEntityID",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.registeredAt",
+        label: "Registered At",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.registeredAt.id",
+        label: "Registered At",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:RegistrationAuthority/lei:RegistrationAuthorityID",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.registeredAt.other",
+        label: "Registered At (other)",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:RegistrationAuthority/lei:OtherRegistrationAuthorityID",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.status",
+        label: "Entity Status",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:EntityStatus",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &["ACTIVE", "INACTIVE", "NULL"],
+    },
+    FieldInfo {
+        name: "entity.subCategory",
+        label: "Sub Category",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:EntitySubCategory",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[
+            "CENTRAL_GOVERNMENT",
+            "STATE_GOVERNMENT",
+            "LOCAL_GOVERNMENT",
+            "SOCIAL_SECURITY",
+        ],
+    },
+    FieldInfo {
+        name: "entity.successorEntities",
+        label: "Replaced By",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.successorEntities.lang",
+        label: "Successor Entity Lang",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:SuccessorEntity/lei:SuccessorEntityName/@xml:lang",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.successorEntities.lei",
+        label: "Successor Entity LEI",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:SuccessorEntity/lei:SuccessorLEI",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.successorEntities.name",
+        label: "Successor Entity Name",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:SuccessorEntity/lei:SuccessorEntityName",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.successorEntity.lei",
+        label: "Successor LEI",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.successorEntity.name",
+        label: "Successor Entity Name",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.transliteratedOtherNames",
+        label: "Transliterated Other Entity Name",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:TransliteratedOtherEntityNames/lei:TransliteratedOtherEntityName",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.transliteratedOtherNames.language",
+        label: "Language of Transliterated Other Entity Name",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:TransliteratedOtherEntityNames/lei:TransliteratedOtherEntityName/@xml:lang",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.transliteratedOtherNames.type",
+        label: "Type of Transliterated Other Entity Name",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:TransliteratedOtherEntityNames/lei:TransliteratedOtherEntityName/@type",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[
+            "PREFERRED_ASCII_TRANSLITERATED_LEGAL_NAME",
+            "AUTO_ASCII_TRANSLITERATED_LEGAL_NAME",
+        ],
+    },
+    FieldInfo {
+        name: "feederFundCount",
+        label: "Feeder Fund Count",
+        xpath: "",
+        kind: ValueKind::Number,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "feederFunds.addresses.city",
+        label: "Feeder Fund City",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "feederFunds.addresses.country",
+        label: "Feeder Fund Country",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "feederFunds.addresses.postalCode",
+        label: "Feeder Fund Postal Codes",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "feederFunds.addresses.region",
+        label: "Feeder Fund Region",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "feederFunds.entityStatus",
+        label: "Feeder Fund Entity Status",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &["ACTIVE", "INACTIVE", "NULL"],
+    },
+    FieldInfo {
+        name: "feederFunds.legalName",
+        label: "Feeder Fund Legal Name",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "feederFunds.lei",
+        label: "Feeder Fund LEI",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "feederFunds.managingLou",
+        label: "Feeder Fund LEI Issuer",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "feederFunds.names",
+        label: "Feeder Fund Names",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "feederFunds.validFrom",
+        label: "Feeder Fund Valid From",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "feederFunds.validTo",
+        label: "Feeder Fund Valid To",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "fulltext",
+        label: "Find LEIs",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "fundManager.addresses.city",
+        label: "Fund Manager City",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "fundManager.addresses.country",
+        label: "Fund Manager Country",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "fundManager.addresses.postalCode",
+        label: "Fund Manager Postal Codes",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "fundManager.addresses.region",
+        label: "Fund Manager Region",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "fundManager.entityStatus",
+        label: "Fund Manager Entity Status",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &["ACTIVE", "INACTIVE", "NULL"],
+    },
+    FieldInfo {
+        name: "fundManager.legalName",
+        label: "Fund Manager Legal Name",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "fundManager.lei",
+        label: "Fund Manager LEI",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "fundManager.managingLou",
+        label: "Fund Manager LEI Issuer",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "fundManager.names",
+        label: "Fund Manager Names",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "fundManager.validFrom",
+        label: "Fund Manager Valid From",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "fundManager.validTo",
+        label: "Fund Manager Valid To",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "headOffice.addresses.city",
+        label: "Head office City",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "headOffice.addresses.country",
+        label: "Head office Country",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "headOffice.addresses.postalCode",
+        label: "Head office Postal Codes",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "headOffice.addresses.region",
+        label: "Head office Region",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "headOffice.entityStatus",
+        label: "Head office Entity Status",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &["ACTIVE", "INACTIVE", "NULL"],
+    },
+    FieldInfo {
+        name: "headOffice.legalName",
+        label: "Head office Legal Name",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "headOffice.lei",
+        label: "Head office LEI",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "headOffice.managingLou",
+        label: "Head office LEI Issuer",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "headOffice.names",
+        label: "Head office Names",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "headOffice.validFrom",
+        label: "Head office Valid From",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "headOffice.validTo",
+        label: "Head office Valid To",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "isin",
+        label: "ISIN Code",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "lei",
+        label: "LEI Code",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:LEI",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "managedFundCount",
+        label: "Managed Fund Count",
+        xpath: "",
+        kind: ValueKind::Number,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "managedFunds.addresses.city",
+        label: "Managed Fund City",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "managedFunds.addresses.country",
+        label: "Managed Fund Country",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "managedFunds.addresses.postalCode",
+        label: "Managed Fund Postal Codes",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "managedFunds.addresses.region",
+        label: "Managed Fund Region",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "managedFunds.entityStatus",
+        label: "Managed Fund Entity Status",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &["ACTIVE", "INACTIVE", "NULL"],
+    },
+    FieldInfo {
+        name: "managedFunds.legalName",
+        label: "Managed Fund Legal Name",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "managedFunds.lei",
+        label: "Managed Fund LEI",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "managedFunds.managingLou",
+        label: "Managed Fund LEI Issuer",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "managedFunds.names",
+        label: "Managed Fund Names",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "managedFunds.validFrom",
+        label: "Managed Fund Valid From",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "managedFunds.validTo",
+        label: "Managed Fund Valid To",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "masterFund.addresses.city",
+        label: "Master Fund City",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "masterFund.addresses.country",
+        label: "Master Fund Country",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "masterFund.addresses.postalCode",
+        label: "Master Fund Postal Codes",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "masterFund.addresses.region",
+        label: "Master Fund Region",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "masterFund.entityStatus",
+        label: "Master Fund Entity Status",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &["ACTIVE", "INACTIVE", "NULL"],
+    },
+    FieldInfo {
+        name: "masterFund.legalName",
+        label: "Master Fund Legal Name",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "masterFund.lei",
+        label: "Master Fund LEI",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "masterFund.managingLou",
+        label: "Master Fund LEI Issuer",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "masterFund.names",
+        label: "Master Fund Names",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "masterFund.validFrom",
+        label: "Master Fund Valid From",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "masterFund.validTo",
+        label: "Master Fund Valid To",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "meta.validFrom",
+        label: "",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "meta.validTo",
+        label: "",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "mic",
+        label: "MICs",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ocid",
+        label: "OCID",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ownedBy",
+        label: "Who is Owned By...",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "owns",
+        label: "Who Owns...",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "qcc",
+        label: "QCCs",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "registration.corroborationLevel",
+        label: "Corroboration Level",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Registration/lei:ValidationSources",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[
+            "ENTITY_SUPPLIED_ONLY",
+            "PARTIALLY_CORROBORATED",
+            "FULLY_CORROBORATED",
+        ],
+    },
+    FieldInfo {
+        name: "registration.initialRegistrationDate",
+        label: "Registration Date",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Registration/lei:InitialRegistrationDate",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "registration.lastUpdateDate",
+        label: "Last Update",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Registration/lei:LastUpdateDate",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "registration.managingLou",
+        label: "LEI Issuer",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Registration/lei:ManagingLOU",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "registration.nextRenewalDate",
+        label: "Next Renewal",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Registration/lei:NextRenewalDate",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "registration.otherValidationAuthorities.validatedAs",
+        label: "Data Validated As",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Registration/lei:OtherValidationAuthorities/lei:OtherValidationAuthority/lei:ValidationAuthorityEntityID",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "registration.otherValidationAuthorities.validatedAt.id",
+        label: "Data Validated At (ID)",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Registration/lei:OtherValidationAuthorities/lei:OtherValidationAuthority/lei:ValidationAuthorityID",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "registration.otherValidationAuthorities.validatedAt.other",
+        label: "Data Validated At (other)",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Registration/lei:OtherValidationAuthorities/lei:OtherValidationAuthority/lei:OtherValidationAuthorityID",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "registration.status",
+        label: "Status",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Registration/lei:RegistrationStatus",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[
+            "ISSUED",
+            "LAPSED",
+            "ANNULLED",
+            "PENDING_TRANSFER",
+            "PENDING_ARCHIVAL",
+            "DUPLICATE",
+            "RETIRED",
+            "MERGED",
+        ],
+    },
+    FieldInfo {
+        name: "registration.validatedAs",
+        label: "Data Validated As",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "registration.validatedAt",
+        label: "Data Validated At",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "registration.validatedAt.id",
+        label: "Data Validated At (ID)",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Registration/lei:ValidationAuthority/lei:ValidationAuthorityID",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "registration.validatedAt.other",
+        label: "Data Validated At (other)",
+        xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Registration/lei:ValidationAuthority/lei:OtherValidationAuthorityID",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "spglobal",
+        label: "S&P Global Company ID",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "subFundCount",
+        label: "Sub-Fund Count",
+        xpath: "",
+        kind: ValueKind::Number,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "subFunds.addresses.city",
+        label: "Sub-Fund City",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "subFunds.addresses.country",
+        label: "Sub-Fund Country",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "subFunds.addresses.postalCode",
+        label: "Sub-Fund Postal Codes",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "subFunds.addresses.region",
+        label: "Sub-Fund Region",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "subFunds.entityStatus",
+        label: "Sub-Fund Entity Status",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &["ACTIVE", "INACTIVE", "NULL"],
+    },
+    FieldInfo {
+        name: "subFunds.legalName",
+        label: "Sub-Fund Legal Name",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "subFunds.lei",
+        label: "Sub-Fund LEI",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "subFunds.managingLou",
+        label: "Sub-Fund LEI Issuer",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "subFunds.names",
+        label: "Sub-Fund Names",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "subFunds.validFrom",
+        label: "Sub-Fund Valid From",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "subFunds.validTo",
+        label: "Sub-Fund Valid To",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateChildCount",
+        label: "Ultimate Child Count",
+        xpath: "",
+        kind: ValueKind::Number,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateChildren.addresses.city",
+        label: "Ultimate Child City",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateChildren.addresses.country",
+        label: "Ultimate Child Country",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateChildren.addresses.postalCode",
+        label: "Ultimate Child Postal Codes",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateChildren.addresses.region",
+        label: "Ultimate Child Region",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateChildren.entityStatus",
+        label: "Ultimate Child Entity Status",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &["ACTIVE", "INACTIVE", "NULL"],
+    },
+    FieldInfo {
+        name: "ultimateChildren.legalName",
+        label: "Ultimate Child Legal Name",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateChildren.lei",
+        label: "Ultimate Child LEI",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateChildren.managingLou",
+        label: "Ultimate Child LEI Issuer",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateChildren.names",
+        label: "Ultimate Child Names",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateChildren.validFrom",
+        label: "Ultimate Child Valid From",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateChildren.validTo",
+        label: "Ultimate Child Valid To",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateParent.addresses.city",
+        label: "Ultimate Parent City",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateParent.addresses.country",
+        label: "Ultimate Parent Country",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateParent.addresses.postalCode",
+        label: "Ultimate Parent Postal Codes",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateParent.addresses.region",
+        label: "Ultimate Parent Region",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateParent.entityStatus",
+        label: "Ultimate Parent Entity Status",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &["ACTIVE", "INACTIVE", "NULL"],
+    },
+    FieldInfo {
+        name: "ultimateParent.exception.reason",
+        label: "Ultimate Parent Exception Reason",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[
+            "NON_PUBLIC",
+            "NO_LEI",
+            "NATURAL_PERSONS",
+            "NON_CONSOLIDATING",
+            "NO_KNOWN_PERSON",
+            "LEGAL_OBSTACLES",
+            "CONSENT_NOT_OBTAINED",
+            "BINDING_LEGAL_COMMITMENTS",
+            "DETRIMENT_NOT_EXCLUDED",
+            "DISCOLSURE_DETRIMENTAL",
+        ],
+    },
+    FieldInfo {
+        name: "ultimateParent.exception.validFrom",
+        label: "Direct Parent Exception Valid From",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateParent.exception.validTo",
+        label: "Direct Parent Exception Valid To",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateParent.legalName",
+        label: "Ultimate Parent Legal Name",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateParent.lei",
+        label: "Ultimate Parent LEI",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateParent.managingLou",
+        label: "Ultimate Parent LEI Issuer",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateParent.names",
+        label: "Ultimate Parent Names",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateParent.validFrom",
+        label: "Ultimate Parent Valid From",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "ultimateParent.validTo",
+        label: "Ultimate Parent Valid To",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "umbrellaFund.addresses.city",
+        label: "Umbrella Fund City",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "umbrellaFund.addresses.country",
+        label: "Umbrella Fund Country",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "umbrellaFund.addresses.postalCode",
+        label: "Umbrella Fund Postal Codes",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "umbrellaFund.addresses.region",
+        label: "Umbrella Fund Region",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "umbrellaFund.entityStatus",
+        label: "Umbrella Fund Entity Status",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &["ACTIVE", "INACTIVE", "NULL"],
+    },
+    FieldInfo {
+        name: "umbrellaFund.legalName",
+        label: "Umbrella Fund Legal Name",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "umbrellaFund.lei",
+        label: "Umbrella Fund LEI",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "umbrellaFund.managingLou",
+        label: "Umbrella Fund LEI Issuer",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "umbrellaFund.names",
+        label: "Umbrella Fund Names",
+        xpath: "",
+        kind: ValueKind::String,
+        sortable: false,
+        operators: &[Operator::Match, Operator::NotMatch],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "umbrellaFund.validFrom",
+        label: "Umbrella Fund Valid From",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "umbrellaFund.validTo",
+        label: "Umbrella Fund Valid To",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "entity.legalForm.id",
+        label: "Entity Legal Form ID",
+        xpath: "",
+        kind: ValueKind::Resource,
+        sortable: true,
+        operators: &[],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "relationship.startDate",
+        label: "Relationship Start Date",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "relationship.endDate",
+        label: "Relationship End Date",
+        xpath: "",
+        kind: ValueKind::Date,
+        sortable: true,
+        operators: &[
+            Operator::Match,
+            Operator::NotMatch,
+            Operator::GreaterThan,
+            Operator::GreaterThanOrEqual,
+            Operator::LessThan,
+            Operator::LessThanOrEqual,
+            Operator::InRange,
+        ],
+        enum_values: &[],
+    },
+    FieldInfo {
+        name: "relationship.status",
+        label: "Relationship Status",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &["ACTIVE", "INACTIVE", "NULL"],
+    },
+    FieldInfo {
+        name: "relationship.type",
+        label: "Relationship Type",
+        xpath: "",
+        kind: ValueKind::Enum,
+        sortable: true,
+        operators: &[Operator::Match, Operator::NotMatch, Operator::In],
+        enum_values: &[
+            "IS_DIRECTLY_CONSOLIDATED_BY",
+            "IS_ULTIMATELY_CONSOLIDATED_BY",
+            "IS_INTERNATIONAL_BRANCH_OF",
+            "IS_FUND-MANAGED_BY",
+            "IS_SUBFUND_OF",
+            "IS_FEEDER_TO",
+        ],
+    },
+];
+
+impl Field {
+    /// All known fields, in declaration order.
+    pub const ALL: &'static [Field] = &[
+        Field::Bic,
+        Field::BranchCount,
+        Field::BranchesAddressesCity,
+        Field::BranchesAddressesCountry,
+        Field::BranchesAddressesPostalCode,
+        Field::BranchesAddressesRegion,
+        Field::BranchesEntityStatus,
+        Field::BranchesLegalName,
+        Field::BranchesLei,
+        Field::BranchesManagingLou,
+        Field::BranchesNames,
+        Field::BranchesValidFrom,
+        Field::BranchesValidTo,
+        Field::ConformityFlag,
+        Field::DirectChildCount,
+        Field::DirectChildrenAddressesCity,
+        Field::DirectChildrenAddressesCountry,
+        Field::DirectChildrenAddressesPostalCode,
+        Field::DirectChildrenAddressesRegion,
+        Field::DirectChildrenEntityStatus,
+        Field::DirectChildrenLegalName,
+        Field::DirectChildrenLei,
+        Field::DirectChildrenManagingLou,
+        Field::DirectChildrenNames,
+        Field::DirectChildrenValidFrom,
+        Field::DirectChildrenValidTo,
+        Field::DirectParentAddressesCity,
+        Field::DirectParentAddressesCountry,
+        Field::DirectParentAddressesPostalCode,
+        Field::DirectParentAddressesRegion,
+        Field::DirectParentEntityStatus,
+        Field::DirectParentExceptionReason,
+        Field::DirectParentExceptionValidFrom,
+        Field::DirectParentExceptionValidTo,
+        Field::DirectParentLegalName,
+        Field::DirectParentLei,
+        Field::DirectParentManagingLou,
+        Field::DirectParentNames,
+        Field::DirectParentValidFrom,
+        Field::DirectParentValidTo,
+        Field::EntityAddresses,
+        Field::EntityAddressesAddressLines,
+        Field::EntityAddressesAddressNumber,
+        Field::EntityAddressesAddressNumberWithinBuilding,
+        Field::EntityAddressesCity,
+        Field::EntityAddressesCountry,
+        Field::EntityAddressesFieldType,
+        Field::EntityAddressesLanguage,
+        Field::EntityAddressesMailRouting,
+        Field::EntityAddressesPostalCode,
+        Field::EntityAddressesRegion,
+        Field::EntityAddressesType,
+        Field::EntityAssociatedEntity,
+        Field::EntityAssociatedEntityLei,
+        Field::EntityAssociatedEntityName,
+        Field::EntityCategory,
+        Field::EntityCreationDate,
+        Field::EntityExpirationDate,
+        Field::EntityExpirationReason,
+        Field::EntityHqAddressAddressNumber,
+        Field::EntityHqAddressAddressNumberWithinBuilding,
+        Field::EntityHqAddressAddressLines,
+        Field::EntityHqAddressCity,
+        Field::EntityHqAddressCountry,
+        Field::EntityHqAddressLanguage,
+        Field::EntityHqAddressLine1,
+        Field::EntityHqAddressLine2,
+        Field::EntityHqAddressLine3,
+        Field::EntityHqAddressLine4,
+        Field::EntityHqAddressMailRouting,
+        Field::EntityHqAddressPostalCode,
+        Field::EntityHqAddressRegion,
+        Field::EntityJurisdiction,
+        Field::EntityLegalAddressAddressLines,
+        Field::EntityLegalAddressAddressNumber,
+        Field::EntityLegalAddressAddressNumberWithinBuilding,
+        Field::EntityLegalAddressCity,
+        Field::EntityLegalAddressCountry,
+        Field::EntityLegalAddressLanguage,
+        Field::EntityLegalAddressLine1,
+        Field::EntityLegalAddressLine2,
+        Field::EntityLegalAddressLine3,
+        Field::EntityLegalAddressLine4,
+        Field::EntityLegalAddressMailRouting,
+        Field::EntityLegalAddressPostalCode,
+        Field::EntityLegalAddressRegion,
+        Field::EntityLegalForm,
+        Field::EntityLegalFormCode,
+        Field::EntityLegalFormOther,
+        Field::EntityLegalName,
+        Field::EntityLegalNameLanguage,
+        Field::EntityNames,
+        Field::EntityOtherNames,
+        Field::EntityOtherNamesLanguage,
+        Field::EntityOtherNamesType,
+        Field::EntityBusinessRegisterNumber,
+        Field::EntityRegisteredAt,
+        Field::EntityRegisteredAtId,
+        Field::EntityRegisteredAtOther,
+        Field::EntityStatus,
+        Field::EntitySubCategory,
+        Field::EntitySuccessorEntities,
+        Field::EntitySuccessorEntitiesLang,
+        Field::EntitySuccessorEntitiesLei,
+        Field::EntitySuccessorEntitiesName,
+        Field::EntitySuccessorEntityLei,
+        Field::EntitySuccessorEntityName,
+        Field::EntityTransliteratedOtherNames,
+        Field::EntityTransliteratedOtherNamesLanguage,
+        Field::EntityTransliteratedOtherNamesType,
+        Field::FeederFundCount,
+        Field::FeederFundsAddressesCity,
+        Field::FeederFundsAddressesCountry,
+        Field::FeederFundsAddressesPostalCode,
+        Field::FeederFundsAddressesRegion,
+        Field::FeederFundsEntityStatus,
+        Field::FeederFundsLegalName,
+        Field::FeederFundsLei,
+        Field::FeederFundsManagingLou,
+        Field::FeederFundsNames,
+        Field::FeederFundsValidFrom,
+        Field::FeederFundsValidTo,
+        Field::Fulltext,
+        Field::FundManagerAddressesCity,
+        Field::FundManagerAddressesCountry,
+        Field::FundManagerAddressesPostalCode,
+        Field::FundManagerAddressesRegion,
+        Field::FundManagerEntityStatus,
+        Field::FundManagerLegalName,
+        Field::FundManagerLei,
+        Field::FundManagerManagingLou,
+        Field::FundManagerNames,
+        Field::FundManagerValidFrom,
+        Field::FundManagerValidTo,
+        Field::HeadOfficeAddressesCity,
+        Field::HeadOfficeAddressesCountry,
+        Field::HeadOfficeAddressesPostalCode,
+        Field::HeadOfficeAddressesRegion,
+        Field::HeadOfficeEntityStatus,
+        Field::HeadOfficeLegalName,
+        Field::HeadOfficeLei,
+        Field::HeadOfficeManagingLou,
+        Field::HeadOfficeNames,
+        Field::HeadOfficeValidFrom,
+        Field::HeadOfficeValidTo,
+        Field::Isin,
+        Field::Lei,
+        Field::ManagedFundCount,
+        Field::ManagedFundsAddressesCity,
+        Field::ManagedFundsAddressesCountry,
+        Field::ManagedFundsAddressesPostalCode,
+        Field::ManagedFundsAddressesRegion,
+        Field::ManagedFundsEntityStatus,
+        Field::ManagedFundsLegalName,
+        Field::ManagedFundsLei,
+        Field::ManagedFundsManagingLou,
+        Field::ManagedFundsNames,
+        Field::ManagedFundsValidFrom,
+        Field::ManagedFundsValidTo,
+        Field::MasterFundAddressesCity,
+        Field::MasterFundAddressesCountry,
+        Field::MasterFundAddressesPostalCode,
+        Field::MasterFundAddressesRegion,
+        Field::MasterFundEntityStatus,
+        Field::MasterFundLegalName,
+        Field::MasterFundLei,
+        Field::MasterFundManagingLou,
+        Field::MasterFundNames,
+        Field::MasterFundValidFrom,
+        Field::MasterFundValidTo,
+        Field::MetaValidFrom,
+        Field::MetaValidTo,
+        Field::Mic,
+        Field::Ocid,
+        Field::OwnedBy,
+        Field::Owns,
+        Field::Qcc,
+        Field::RegistrationCorroborationLevel,
+        Field::RegistrationInitialRegistrationDate,
+        Field::RegistrationLastUpdateDate,
+        Field::RegistrationManagingLou,
+        Field::RegistrationNextRenewalDate,
+        Field::RegistrationOtherValidationAuthoritiesValidatedAs,
+        Field::RegistrationOtherValidationAuthoritiesValidatedAtId,
+        Field::RegistrationOtherValidationAuthoritiesValidatedAtOther,
+        Field::RegistrationStatus,
+        Field::RegistrationValidatedAs,
+        Field::RegistrationValidatedAt,
+        Field::RegistrationValidatedAtId,
+        Field::RegistrationValidatedAtOther,
+        Field::Spglobal,
+        Field::SubFundCount,
+        Field::SubFundsAddressesCity,
+        Field::SubFundsAddressesCountry,
+        Field::SubFundsAddressesPostalCode,
+        Field::SubFundsAddressesRegion,
+        Field::SubFundsEntityStatus,
+        Field::SubFundsLegalName,
+        Field::SubFundsLei,
+        Field::SubFundsManagingLou,
+        Field::SubFundsNames,
+        Field::SubFundsValidFrom,
+        Field::SubFundsValidTo,
+        Field::UltimateChildCount,
+        Field::UltimateChildrenAddressesCity,
+        Field::UltimateChildrenAddressesCountry,
+        Field::UltimateChildrenAddressesPostalCode,
+        Field::UltimateChildrenAddressesRegion,
+        Field::UltimateChildrenEntityStatus,
+        Field::UltimateChildrenLegalName,
+        Field::UltimateChildrenLei,
+        Field::UltimateChildrenManagingLou,
+        Field::UltimateChildrenNames,
+        Field::UltimateChildrenValidFrom,
+        Field::UltimateChildrenValidTo,
+        Field::UltimateParentAddressesCity,
+        Field::UltimateParentAddressesCountry,
+        Field::UltimateParentAddressesPostalCode,
+        Field::UltimateParentAddressesRegion,
+        Field::UltimateParentEntityStatus,
+        Field::UltimateParentExceptionReason,
+        Field::UltimateParentExceptionValidFrom,
+        Field::UltimateParentExceptionValidTo,
+        Field::UltimateParentLegalName,
+        Field::UltimateParentLei,
+        Field::UltimateParentManagingLou,
+        Field::UltimateParentNames,
+        Field::UltimateParentValidFrom,
+        Field::UltimateParentValidTo,
+        Field::UmbrellaFundAddressesCity,
+        Field::UmbrellaFundAddressesCountry,
+        Field::UmbrellaFundAddressesPostalCode,
+        Field::UmbrellaFundAddressesRegion,
+        Field::UmbrellaFundEntityStatus,
+        Field::UmbrellaFundLegalName,
+        Field::UmbrellaFundLei,
+        Field::UmbrellaFundManagingLou,
+        Field::UmbrellaFundNames,
+        Field::UmbrellaFundValidFrom,
+        Field::UmbrellaFundValidTo,
+        Field::EntityLegalFormId,
+        Field::RelationshipStartDate,
+        Field::RelationshipEndDate,
+        Field::RelationshipStatus,
+        Field::RelationshipType,
+    ];
+}
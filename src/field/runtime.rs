@@ -0,0 +1,161 @@
+//! Runtime field catalogue, loaded from the `fields` endpoint.
+//!
+//! GLEIF may change which fields are sortable, which operators they accept and which enum values
+//! they take without a new release of this crate. A [`FieldCatalog`] captures the live metadata so
+//! that requests can be validated against it before they are sent.
+
+use super::{FieldRule, Operator, ValueKind};
+use crate::{
+    error::Result,
+    model::{FieldList, field::FieldAttributes},
+};
+use std::{collections::HashMap, str::FromStr};
+
+/// Field metadata reported by the GLEIF `fields` endpoint, keyed by field name.
+///
+/// Load it with [`crate::client::GleifClient::load_field_catalog`] and attach it to a client with
+/// [`crate::client::GleifClient::with_field_catalog`] to validate every `lei-records` query before it
+/// is sent, or validate individual requests with
+/// [`crate::request_builder::GleifRequestBuilder::validate_with`].
+///
+/// Fields missing from the catalogue, such as the relationship record filters, are validated against
+/// the static [`Field`](super::Field) metadata instead.
+///
+/// # Examples
+///
+/// ```rust, ignore
+/// let catalog = client.load_field_catalog().await?;
+/// let client = client.with_field_catalog(catalog);
+/// // Rejected locally with a `GleifError::ParseError` instead of a server-side 400.
+/// let result: Result<LeiRecordList> = client.lei_records().sort(Field::Bic).send().await;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FieldCatalog {
+    fields: HashMap<String, FieldAttributes>,
+}
+
+impl FieldCatalog {
+    /// Creates a catalogue from the field definitions returned by the `fields` endpoint.
+    ///
+    /// Definitions without a field name cannot be used in queries and are skipped.
+    #[must_use]
+    pub fn new<I>(fields: I) -> Self
+    where
+        I: IntoIterator<Item = crate::model::field::Field>,
+    {
+        let fields = fields
+            .into_iter()
+            .filter(|field| !field.attributes.field.is_empty())
+            .map(|field| (field.attributes.field.clone(), field.attributes))
+            .collect();
+        Self { fields }
+    }
+
+    /// Returns the metadata of the named field, if the catalogue lists it.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&FieldAttributes> {
+        self.fields.get(name)
+    }
+
+    /// Returns the number of fields in the catalogue.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns `true` if the catalogue lists no fields.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Returns the validation rule of the named field, falling back to the static [`Field`](super::Field) metadata.
+    ///
+    /// Operators unknown to this crate are ignored, and an unknown data type is treated as text.
+    pub(crate) fn rule(&self, name: &str) -> Result<FieldRule> {
+        let Some(attributes) = self.fields.get(name) else {
+            return FieldRule::from_name(name);
+        };
+        let operators = attributes
+            .operators
+            .iter()
+            .flatten()
+            .filter_map(|op| Operator::from_str(op).ok())
+            .collect();
+        Ok(FieldRule::new(
+            name,
+            ValueKind::from_str(&attributes.data_type).unwrap_or(ValueKind::String),
+            attributes.sortable,
+            operators,
+            attributes.enum_values.clone().unwrap_or_default(),
+        ))
+    }
+}
+
+impl From<FieldList> for FieldCatalog {
+    fn from(list: FieldList) -> Self {
+        Self::new(list.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::Field;
+    use crate::test_utils::test_model_files;
+    use std::path::Path;
+
+    #[test]
+    fn test_catalog_rules() {
+        let dir = Path::new("tests/data/fields");
+        test_model_files(
+            |filename| filename == "fields_all.json",
+            |data| serde_json::from_str::<FieldList>(data),
+            |_, list| {
+                let catalog = FieldCatalog::from(list.clone());
+                assert!(!catalog.is_empty());
+                assert_eq!(catalog.len(), Field::ALL.len() - 5);
+                assert!(catalog.get("entity.legalName").is_some());
+
+                let rule = catalog.rule("entity.category").unwrap();
+                assert!(rule.check_filter("FUND,BRANCH").is_ok());
+                assert!(rule.check_filter("fund").is_ok());
+                assert!(rule.check_filter("COMPANY").is_err());
+                assert!(rule.check_filter(">FUND").is_err());
+                assert!(rule.check_sort().is_ok());
+                assert!(catalog.rule("bic").unwrap().check_sort().is_err());
+
+                // Relationship filters are not in the catalogue and use the static metadata.
+                let rule = catalog.rule("relationship.status").unwrap();
+                assert!(rule.check_filter("ACTIVE").is_ok());
+                assert!(catalog.rule("entity.unknown").is_err());
+            },
+            dir,
+        );
+    }
+
+    #[test]
+    fn test_catalog_overrides_static_metadata() {
+        let field = crate::model::field::Field {
+            r#type: "fields".to_string(),
+            id: "LEIREC_LEGAL_NAME".to_string(),
+            attributes: FieldAttributes {
+                field: "entity.legalName".to_string(),
+                label: "Legal Name".to_string(),
+                data_type: "STRING".to_string(),
+                enum_values: None,
+                resource: None,
+                sortable: false,
+                operators: Some(vec!["MATCH".to_string(), "SOMETHING_NEW".to_string()]),
+                contexts: Vec::new(),
+                json_path: None,
+                xpath: None,
+            },
+        };
+        let catalog = FieldCatalog::new([field]);
+        let rule = catalog.rule("entity.legalName").unwrap();
+        assert!(rule.check_sort().is_err());
+        assert!(rule.check_filter("ACME").is_ok());
+        assert!(rule.check_filter("!ACME").is_err());
+    }
+}
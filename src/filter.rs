@@ -0,0 +1,596 @@
+//! # Local Record Filtering ([`RecordFilter`]) - Evaluate GLEIF Queries Against Local Records
+//!
+//! This module provides [`RecordFilter`], which evaluates the filters and sort order of a
+//! [`GleifRequestBuilder`] against LEI records held locally (e.g., snapshots or Golden Copy extracts).
+//! The same query can therefore be sent to the GLEIF API or applied offline, with consistent results.
+//!
+//! Filter values are interpreted with the same syntax the request builder produces:
+//!
+//! | Syntax       | Meaning                                              | Builder method                                           |
+//! |--------------|------------------------------------------------------|----------------------------------------------------------|
+//! | `value`      | Exact (case-insensitive) match                       | [`filter_eq`](GleifRequestBuilder::filter_eq)            |
+//! | `!value`     | No match (records without a value also match)        | [`filter_not`](GleifRequestBuilder::filter_not)          |
+//! | `a,b,c`      | Match any of the values                              | [`filter_in`](GleifRequestBuilder::filter_in)            |
+//! | `!a,b,c`     | Match none of the values                             | [`filter_not_in`](GleifRequestBuilder::filter_not_in)    |
+//! | `min..max`   | Inclusive range                                      | [`filter_range`](GleifRequestBuilder::filter_range)      |
+//! | `>v`, `>=v`  | Greater than (or equal)                              | [`filter_gt`](GleifRequestBuilder::filter_gt), [`filter_gte`](GleifRequestBuilder::filter_gte) |
+//! | `<v`, `<=v`  | Less than (or equal)                                 | [`filter_lt`](GleifRequestBuilder::filter_lt), [`filter_lte`](GleifRequestBuilder::filter_lte) |
+//!
+//! ## Comparison Semantics
+//!
+//! - **Text** is compared case-insensitively; values of [`ValueKind::Number`] fields are compared numerically.
+//! - **Dates** accept either a full RFC 3339 timestamp or a calendar date (`YYYY-MM-DD`). A calendar date
+//!   covers the whole (UTC) day, so `2020-01-01` matches any time on that day, `>2020-01-01` starts on the
+//!   next day and `<=2020-01-01` includes the whole day.
+//! - **Lists** (e.g., BICs or other names) match if any element matches; negated conditions match only if
+//!   no element matches.
+//! - **Sorting** follows the `sort` parameter (comma-separated, `-` prefix for descending). Records without
+//!   a value for a sort field are placed last, and ties keep their input order.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use gleif_rs::{client::GleifClient, field::Field, filter::RecordFilter, value::RegistrationStatus};
+//!
+//! let request = GleifClient::new()
+//!     .lei_records()
+//!     .filter_eq(Field::RegistrationStatus, RegistrationStatus::Issued)
+//!     .filter_gte(Field::RegistrationInitialRegistrationDate, "2014-01-01")
+//!     .sort(Field::EntityLegalName);
+//!
+//! // Evaluate the very same query against locally stored records
+//! let filter = RecordFilter::from_request(&request).unwrap();
+//! # let records: Vec<gleif_rs::model::lei_record::LeiRecord> = Vec::new();
+//! let matching = filter.apply(&records);
+//! # assert!(matching.is_empty());
+//! ```
+
+use crate::{
+    error::{GleifError, ParseErrorKind, Result},
+    field::{Field, Operator, ValueKind},
+    model::lei_record::LeiRecord,
+    request_builder::GleifRequestBuilder,
+    value::FieldValue,
+};
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+/// A single filter condition, mirroring the GLEIF filter value syntax.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Condition {
+    /// Exact match (`value`).
+    Eq(String),
+    /// Negated match (`!value`).
+    Not(String),
+    /// Match any of the values (`a,b,c`).
+    In(Vec<String>),
+    /// Match none of the values (`!a,b,c`).
+    NotIn(Vec<String>),
+    /// Inclusive range (`min..max`).
+    Range(String, String),
+    /// Greater than (`>value`).
+    Gt(String),
+    /// Greater than or equal (`>=value`).
+    Gte(String),
+    /// Less than (`<value`).
+    Lt(String),
+    /// Less than or equal (`<=value`).
+    Lte(String),
+}
+
+impl Condition {
+    /// Parses a filter value as produced by [`GleifRequestBuilder`] (e.g., `!a,b`, `>=2020-01-01`, `a..b`).
+    #[must_use]
+    pub fn parse(input: &str) -> Self {
+        if let Some(rest) = input.strip_prefix('!') {
+            return if rest.contains(',') {
+                Condition::NotIn(split_list(rest))
+            } else {
+                Condition::Not(rest.to_string())
+            };
+        }
+        if let Some(rest) = input.strip_prefix(">=") {
+            return Condition::Gte(rest.to_string());
+        }
+        if let Some(rest) = input.strip_prefix("<=") {
+            return Condition::Lte(rest.to_string());
+        }
+        if let Some(rest) = input.strip_prefix('>') {
+            return Condition::Gt(rest.to_string());
+        }
+        if let Some(rest) = input.strip_prefix('<') {
+            return Condition::Lt(rest.to_string());
+        }
+        if let Some((min, max)) = input.split_once("..") {
+            return Condition::Range(min.to_string(), max.to_string());
+        }
+        if input.contains(',') {
+            return Condition::In(split_list(input));
+        }
+        Condition::Eq(input.to_string())
+    }
+
+    /// Returns the catalogue [`Operator`] expressed by this condition.
+    #[must_use]
+    pub const fn operator(&self) -> Operator {
+        match self {
+            Condition::Eq(_) => Operator::Match,
+            Condition::Not(_) | Condition::NotIn(_) => Operator::NotMatch,
+            Condition::In(_) => Operator::In,
+            Condition::Range(_, _) => Operator::InRange,
+            Condition::Gt(_) => Operator::GreaterThan,
+            Condition::Gte(_) => Operator::GreaterThanOrEqual,
+            Condition::Lt(_) => Operator::LessThan,
+            Condition::Lte(_) => Operator::LessThanOrEqual,
+        }
+    }
+
+    /// Evaluates the condition against a field value read from a record.
+    ///
+    /// Text is compared numerically only for fields of [`ValueKind::Number`], and case-insensitively
+    /// otherwise, so codes such as `00501` and `501` stay distinct. A missing value only satisfies
+    /// the negated conditions ([`Condition::Not`] and [`Condition::NotIn`]).
+    #[must_use]
+    pub fn matches(&self, kind: ValueKind, value: Option<&FieldValue>) -> bool {
+        let Some(value) = value else {
+            return matches!(self, Condition::Not(_) | Condition::NotIn(_));
+        };
+        let items = Item::all(value);
+        let any = |op: Op, bound: &str| items.iter().any(|item| item.check(kind, op, bound));
+        match self {
+            Condition::Eq(bound) => any(Op::Eq, bound),
+            Condition::Not(bound) => !any(Op::Eq, bound),
+            Condition::In(bounds) => bounds.iter().any(|bound| any(Op::Eq, bound)),
+            Condition::NotIn(bounds) => !bounds.iter().any(|bound| any(Op::Eq, bound)),
+            Condition::Range(min, max) => items
+                .iter()
+                .any(|item| item.check(kind, Op::Gte, min) && item.check(kind, Op::Lte, max)),
+            Condition::Gt(bound) => any(Op::Gt, bound),
+            Condition::Gte(bound) => any(Op::Gte, bound),
+            Condition::Lt(bound) => any(Op::Lt, bound),
+            Condition::Lte(bound) => any(Op::Lte, bound),
+        }
+    }
+}
+
+// Renders the condition in the query syntax accepted by the GLEIF API.
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Condition::Eq(value) => write!(f, "{value}"),
+            Condition::Not(value) => write!(f, "!{value}"),
+            Condition::In(values) => write!(f, "{}", values.join(",")),
+            Condition::NotIn(values) => write!(f, "!{}", values.join(",")),
+            Condition::Range(min, max) => write!(f, "{min}..{max}"),
+            Condition::Gt(value) => write!(f, ">{value}"),
+            Condition::Gte(value) => write!(f, ">={value}"),
+            Condition::Lt(value) => write!(f, "<{value}"),
+            Condition::Lte(value) => write!(f, "<={value}"),
+        }
+    }
+}
+
+// Parsing never fails, as every string is a valid (exact match) filter value.
+impl FromStr for Condition {
+    type Err = GleifError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Condition::parse(s))
+    }
+}
+
+/// A sort key, as given by the `sort` query parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortKey {
+    /// The field to sort by.
+    pub field: Field,
+    /// Whether to sort in descending order (`-field`).
+    pub descending: bool,
+}
+
+/// Filters and sort order that can be evaluated against local LEI records.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecordFilter {
+    conditions: Vec<(Field, Condition)>,
+    sort: Vec<SortKey>,
+}
+
+impl RecordFilter {
+    /// Creates an empty filter that matches every record and preserves input order.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a filter from the `filter[...]` and `sort` query parameters of a request builder.
+    ///
+    /// Other parameters, such as pagination, are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::GleifError::ParseError`] if a filter or sort field is unknown, or if it
+    /// cannot be evaluated against local LEI records (e.g., [`Field::Fulltext`] or the relationship fields).
+    pub fn from_request(request: &GleifRequestBuilder) -> Result<Self> {
+        let mut filter = Self::new();
+        for (key, value) in request.get_query() {
+            if let Some(name) = key
+                .strip_prefix("filter[")
+                .and_then(|rest| rest.strip_suffix(']'))
+            {
+                let field = local_field(name)?;
+                filter.conditions.push((field, Condition::parse(value)));
+            }
+        }
+        // Query parameters are unordered; keep the evaluation order deterministic.
+        filter
+            .conditions
+            .sort_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+        if let Some(sort) = request.get_query().get("sort") {
+            for name in sort.split(',').filter(|name| !name.is_empty()) {
+                let (name, descending) = match name.strip_prefix('-') {
+                    Some(name) => (name, true),
+                    None => (name, false),
+                };
+                filter.sort.push(SortKey {
+                    field: local_field(name)?,
+                    descending,
+                });
+            }
+        }
+        Ok(filter)
+    }
+
+    /// Adds a condition on a field.
+    #[must_use]
+    pub fn condition(mut self, field: Field, condition: Condition) -> Self {
+        self.conditions.push((field, condition));
+        self
+    }
+
+    /// Adds a sort key. Keys are applied in the order they are added.
+    #[must_use]
+    pub fn sort_by(mut self, field: Field, descending: bool) -> Self {
+        self.sort.push(SortKey { field, descending });
+        self
+    }
+
+    /// Returns the conditions of this filter.
+    #[must_use]
+    pub fn conditions(&self) -> &[(Field, Condition)] {
+        &self.conditions
+    }
+
+    /// Returns the sort keys of this filter.
+    #[must_use]
+    pub fn sort_keys(&self) -> &[SortKey] {
+        &self.sort
+    }
+
+    /// Returns `true` if the record satisfies all conditions.
+    #[must_use]
+    pub fn matches(&self, record: &LeiRecord) -> bool {
+        self.conditions.iter().all(|(field, condition)| {
+            condition.matches(field.value_kind(), record.get(*field).as_ref())
+        })
+    }
+
+    /// Compares two records according to the sort keys.
+    #[must_use]
+    pub fn compare(&self, a: &LeiRecord, b: &LeiRecord) -> Ordering {
+        self.sort
+            .iter()
+            .map(|key| {
+                let kind = key.field.value_kind();
+                match (a.get(key.field), b.get(key.field)) {
+                    (Some(a), Some(b)) if key.descending => compare_values(kind, &b, &a),
+                    (Some(a), Some(b)) => compare_values(kind, &a, &b),
+                    // Missing values are placed last regardless of the sort direction.
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+
+    /// Returns the records matching all conditions, sorted by the sort keys.
+    #[must_use]
+    pub fn apply<'a, I>(&self, records: I) -> Vec<&'a LeiRecord>
+    where
+        I: IntoIterator<Item = &'a LeiRecord>,
+    {
+        let mut matching: Vec<&LeiRecord> = records
+            .into_iter()
+            .filter(|record| self.matches(record))
+            .collect();
+        if !self.sort.is_empty() {
+            matching.sort_by(|a, b| self.compare(a, b));
+        }
+        matching
+    }
+}
+
+/// Private helper to parse a field name and check that it can be read from a local LEI record.
+///
+/// Only the record's own attributes can be evaluated; related-entity filters (e.g. `directParent.*`),
+/// relationship filters, ISINs and full-text search require the API.
+fn local_field(name: &str) -> Result<Field> {
+    let field = Field::from_str(name)?;
+    let path = field.as_str();
+    let identifier = matches!(
+        field,
+        Field::Lei
+            | Field::Bic
+            | Field::Mic
+            | Field::Ocid
+            | Field::Qcc
+            | Field::Spglobal
+            | Field::ConformityFlag
+    );
+    let attribute = (path.starts_with("entity.") || path.starts_with("registration."))
+        && !matches!(
+            field,
+            Field::EntityAddresses | Field::EntitySuccessorEntitiesLang
+        );
+    if identifier || attribute {
+        Ok(field)
+    } else {
+        Err(GleifError::ParseError {
+            kind: ParseErrorKind::Field,
+            message: format!("Field cannot be evaluated against local LEI records: {field}"),
+        })
+    }
+}
+
+/// Private helper to split a comma-separated list of filter values.
+fn split_list(input: &str) -> Vec<String> {
+    input.split(',').map(str::to_string).collect()
+}
+
+/// Private comparison operator used when evaluating a single value.
+#[derive(Clone, Copy)]
+enum Op {
+    Eq,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+/// Private view of a single (list element) value.
+enum Item<'a> {
+    Text(&'a str),
+    Date(DateTime<Utc>),
+}
+
+impl<'a> Item<'a> {
+    /// Splits a field value into its individual items.
+    fn all(value: &'a FieldValue) -> Vec<Self> {
+        match value {
+            FieldValue::Text(text) => vec![Item::Text(text)],
+            FieldValue::Date(date) => vec![Item::Date(*date)],
+            FieldValue::List(list) => list.iter().map(|text| Item::Text(text)).collect(),
+        }
+    }
+
+    /// Checks the item against a bound with the given operator.
+    fn check(&self, kind: ValueKind, op: Op, bound: &str) -> bool {
+        match self {
+            Item::Text(text) => {
+                let ordering = compare_text(kind, text, bound);
+                match op {
+                    Op::Eq => ordering.is_eq(),
+                    Op::Gt => ordering.is_gt(),
+                    Op::Gte => ordering.is_ge(),
+                    Op::Lt => ordering.is_lt(),
+                    Op::Lte => ordering.is_le(),
+                }
+            }
+            Item::Date(date) => {
+                let Some((first, last)) = date_bounds(bound) else {
+                    return false;
+                };
+                match op {
+                    Op::Eq => first <= *date && *date <= last,
+                    Op::Gt => *date > last,
+                    Op::Gte => *date >= first,
+                    Op::Lt => *date < first,
+                    Op::Lte => *date <= last,
+                }
+            }
+        }
+    }
+}
+
+/// Private helper returning the first and last instant covered by a date bound.
+///
+/// A full timestamp covers a single instant, a calendar date the whole UTC day.
+fn date_bounds(bound: &str) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    if let Ok(instant) = DateTime::parse_from_rfc3339(bound) {
+        let instant = instant.with_timezone(&Utc);
+        return Some((instant, instant));
+    }
+    let day = NaiveDate::parse_from_str(bound, "%Y-%m-%d").ok()?;
+    let first = day.and_hms_opt(0, 0, 0)?.and_utc();
+    let last = first + TimeDelta::days(1) - TimeDelta::nanoseconds(1);
+    Some((first, last))
+}
+
+/// Private helper comparing text numerically for number fields whose values are both finite
+/// numbers, otherwise case-insensitively.
+fn compare_text(kind: ValueKind, a: &str, b: &str) -> Ordering {
+    if kind == ValueKind::Number {
+        let number = |text: &str| text.parse::<f64>().ok().filter(|n| n.is_finite());
+        if let (Some(a), Some(b)) = (number(a), number(b)) {
+            return a.total_cmp(&b);
+        }
+    }
+    a.to_lowercase().cmp(&b.to_lowercase())
+}
+
+/// Private helper comparing two field values for sorting.
+fn compare_values(kind: ValueKind, a: &FieldValue, b: &FieldValue) -> Ordering {
+    match (a, b) {
+        (FieldValue::Date(a), FieldValue::Date(b)) => a.cmp(b),
+        (FieldValue::List(a), FieldValue::List(b)) => {
+            compare_text(kind, first(a), first(b)).then_with(|| a.len().cmp(&b.len()))
+        }
+        _ => compare_text(kind, &a.to_string(), &b.to_string()),
+    }
+}
+
+/// Private helper returning the first element of a list, or an empty string.
+fn first(list: &[String]) -> &str {
+    list.first().map_or("", String::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::GleifClient,
+        model::common::GleifApiResponse,
+        test_utils::{sample_records, test_model_files},
+    };
+    use std::path::Path;
+
+    #[test]
+    fn test_parse_condition() {
+        assert_eq!(Condition::parse("a"), Condition::Eq("a".to_string()));
+        assert_eq!(Condition::parse("!a"), Condition::Not("a".to_string()));
+        assert_eq!(
+            Condition::parse("a,b"),
+            Condition::In(vec!["a".to_string(), "b".to_string()])
+        );
+        assert_eq!(
+            Condition::parse("!a,b"),
+            Condition::NotIn(vec!["a".to_string(), "b".to_string()])
+        );
+        assert_eq!(
+            Condition::parse("2020-01-01..2021-01-01"),
+            Condition::Range("2020-01-01".to_string(), "2021-01-01".to_string())
+        );
+        assert_eq!(Condition::parse(">=5"), Condition::Gte("5".to_string()));
+        assert_eq!(Condition::parse("<5"), Condition::Lt("5".to_string()));
+        assert_eq!(Condition::parse("a..b").operator(), Operator::InRange);
+        assert_eq!(Condition::parse("!a,b").operator(), Operator::NotMatch);
+        for input in ["a", "!a", "a,b", "!a,b", "a..b", ">a", ">=a", "<a", "<=a"] {
+            assert_eq!(Condition::parse(input).to_string(), input);
+        }
+    }
+
+    #[test]
+    fn test_condition_matches_text_and_lists() {
+        let text = FieldValue::from("ISSUED");
+        assert!(Condition::parse("issued").matches(ValueKind::Enum, Some(&text)));
+        assert!(Condition::parse("LAPSED,ISSUED").matches(ValueKind::Enum, Some(&text)));
+        assert!(!Condition::parse("!ISSUED").matches(ValueKind::Enum, Some(&text)));
+        assert!(Condition::parse("!LAPSED,RETIRED").matches(ValueKind::Enum, Some(&text)));
+        assert!(Condition::parse("!ISSUED").matches(ValueKind::Enum, None));
+        assert!(!Condition::parse("ISSUED").matches(ValueKind::Enum, None));
+
+        let list = FieldValue::List(vec!["AAAA".to_string(), "BBBB".to_string()]);
+        assert!(Condition::parse("BBBB").matches(ValueKind::String, Some(&list)));
+        assert!(!Condition::parse("!BBBB").matches(ValueKind::String, Some(&list)));
+        assert!(
+            Condition::parse("9..10").matches(ValueKind::Number, Some(&FieldValue::from("10")))
+        );
+        assert!(Condition::parse(">9").matches(ValueKind::Number, Some(&FieldValue::from("10.0"))));
+        assert!(!Condition::parse(">9").matches(ValueKind::String, Some(&FieldValue::from("10"))));
+        assert!(
+            !Condition::parse("501").matches(ValueKind::Resource, Some(&FieldValue::from("00501")))
+        );
+        assert!(
+            Condition::parse("501").matches(ValueKind::Number, Some(&FieldValue::from("00501")))
+        );
+        assert!(
+            !Condition::parse("1000").matches(ValueKind::String, Some(&FieldValue::from("1e3")))
+        );
+    }
+
+    #[test]
+    fn test_condition_matches_dates() {
+        let date = FieldValue::Date(
+            DateTime::parse_from_rfc3339("2012-12-06T20:55:22Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        );
+        assert!(Condition::parse("2012-12-06").matches(ValueKind::Date, Some(&date)));
+        assert!(Condition::parse("<=2012-12-06").matches(ValueKind::Date, Some(&date)));
+        assert!(!Condition::parse(">2012-12-06").matches(ValueKind::Date, Some(&date)));
+        assert!(Condition::parse(">=2012-12-06").matches(ValueKind::Date, Some(&date)));
+        assert!(!Condition::parse("<2012-12-06").matches(ValueKind::Date, Some(&date)));
+        assert!(Condition::parse("2012-01-01..2012-12-06").matches(ValueKind::Date, Some(&date)));
+        assert!(Condition::parse(">2012-12-06T20:00:00Z").matches(ValueKind::Date, Some(&date)));
+        assert!(!Condition::parse(">not-a-date").matches(ValueKind::Date, Some(&date)));
+    }
+
+    #[test]
+    fn test_from_request_and_apply() {
+        let all = sample_records();
+        let request = GleifClient::new()
+            .lei_records()
+            .filter_not(Field::RegistrationStatus, "LAPSED")
+            .sort(format!("-{}", Field::EntityLegalName));
+        let filter = RecordFilter::from_request(&request).unwrap();
+        assert_eq!(
+            filter.sort_keys(),
+            &[SortKey {
+                field: Field::EntityLegalName,
+                descending: true
+            }]
+        );
+        let matching = filter.apply(&all);
+        assert!(
+            matching
+                .iter()
+                .all(|record| { record.attributes.registration.status.as_str() != "LAPSED" })
+        );
+        assert!(matching.windows(2).all(|pair| {
+            compare_text(
+                ValueKind::String,
+                &pair[0].attributes.entity.legal_name.name,
+                &pair[1].attributes.entity.legal_name.name,
+            )
+            .is_ge()
+        }));
+    }
+
+    #[test]
+    fn test_from_request_rejects_unsupported_fields() {
+        let request = GleifClient::new()
+            .lei_records()
+            .filter_eq(Field::Fulltext, "bank");
+        assert!(RecordFilter::from_request(&request).is_err());
+        let request = GleifClient::new()
+            .lei_records()
+            .filter_eq(Field::DirectParentLei, "5493001KJTIIGC8Y1R12");
+        assert!(RecordFilter::from_request(&request).is_err());
+        let request = GleifClient::new()
+            .lei_records()
+            .filter_eq("entity.unknown", "x");
+        assert!(RecordFilter::from_request(&request).is_err());
+    }
+
+    #[test]
+    fn test_matches_sample_records() {
+        let dir = Path::new("tests/data/lei_records");
+        test_model_files(
+            |filename| filename == "single_lei_record_5493001KJTIIGC8Y1R12.json",
+            |data| serde_json::from_str::<GleifApiResponse<LeiRecord>>(data),
+            |_, record| {
+                let matching = RecordFilter::new()
+                    .condition(Field::EntityLegalAddressCountry, Condition::parse("US"))
+                    .condition(
+                        Field::RegistrationInitialRegistrationDate,
+                        Condition::parse("<2013-01-01"),
+                    );
+                assert!(matching.matches(&record.data));
+                let other = matching.condition(Field::EntityCategory, Condition::parse("FUND"));
+                assert!(!other.matches(&record.data));
+            },
+            dir,
+        );
+    }
+}
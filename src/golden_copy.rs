@@ -0,0 +1,74 @@
+//! # Golden Copy Files ([`LeiCdfReader`], [`RrCdfReader`]) - Bulk LEI Data Without the API
+//!
+//! GLEIF publishes the complete LEI population three times a day as Golden Copy files, plus delta
+//! files with the changes since earlier publications. They are the only practical way to work with
+//! all of the roughly 2.5 million records. This module reads these files as streams, one record at a
+//! time, into the same model types the API responses use, so bulk and API data can be processed by
+//! the same code.
+//!
+//! ## Key Features
+//!
+//! - **LEI-CDF 3.1:** Stream the level 1 records of an LEI-CDF Golden Copy or delta file with
+//!   [`LeiCdfReader`], including elements wrapped in `NextVersion`.
+//! - **LEI CSV:** Stream the same records from the flattened CSV form of the LEI Golden Copy with
+//!   [`LeiCsvReader`], mapping numbered column groups back onto repeated elements.
+//! - **RR-CDF 2.1:** Stream the level 2 relationship records of an RR-CDF file with [`RrCdfReader`],
+//!   including their qualifiers and quantifiers, to build ownership graphs from bulk data.
+//! - **XML Writers:** Write records back into namespaced LEI-CDF and RR-CDF documents with
+//!   [`LeiCdfWriter`] and [`RrCdfWriter`], e.g. to publish filtered extracts that the readers and
+//!   other LEI-CDF tooling accept.
+//! - **Reporting Exceptions:** Stream the level 2 reporting exceptions from XML with
+//!   [`ReportingExceptionReader`] or from CSV with [`ReportingExceptionCsvReader`].
+//! - **Identifier Mappings:** Stream the LEI-to-ISIN, BIC, MIC and `OpenCorporates` mapping files
+//!   with [`MappingReader`] and look pairs up in both directions with [`IdentifierIndex`].
+//! - **Delta Files:** Keep a local [`RecordStore`] current by applying delta files with
+//!   [`RecordStore::apply_delta`], which reports new LEIs, changed fields and status transitions as
+//!   [`LeiChange`]s.
+//! - **Downloads:** List publications and download full or delta files of any population and
+//!   format with [`GleifClient::download_golden_copy`](crate::client::GleifClient::download_golden_copy),
+//!   which checks the published size and the zip checksums.
+//! - **File Headers:** Inspect the publication date, record count and delta period of a file with
+//!   [`FileHeader`].
+//!
+//! ## Example
+//!
+//! ```rust, ignore
+//! use gleif_rs::{golden_copy::LeiCdfReader, model::enums::EntityStatus};
+//!
+//! let reader = LeiCdfReader::open("20250101-0000-gleif-goldencopy-lei2-golden-copy.xml")?;
+//! let mut active = 0;
+//! for record in reader {
+//!     if record?.entity.status == EntityStatus::Active {
+//!         active += 1;
+//!     }
+//! }
+//! println!("{active} active entities");
+//! ```
+
+mod delimited;
+mod delta;
+mod download;
+mod header;
+mod lei_cdf;
+mod lei_csv;
+mod mapping;
+mod repex;
+mod rr_cdf;
+mod writer;
+mod xml;
+
+pub use delta::{DeltaChanges, FieldChange, LeiChange, RecordStore};
+pub use download::{
+    DeltaFiles, DeltaPeriod, DownloadedFile, FileFormat, FileFormats, FileType, Publication,
+    PublishedFile, PublishedFiles,
+};
+pub use header::FileHeader;
+pub use lei_cdf::LeiCdfReader;
+pub use lei_csv::LeiCsvReader;
+pub use mapping::{
+    BicMapping, BicMappingReader, IdentifierIndex, IsinMappingReader, Mapping, MappingReader,
+    MicMapping, MicMappingReader, OpenCorporatesMapping, OpenCorporatesMappingReader,
+};
+pub use repex::{ReportingExceptionCsvReader, ReportingExceptionReader};
+pub use rr_cdf::RrCdfReader;
+pub use writer::{LeiCdfWriter, RrCdfWriter};
@@ -0,0 +1,25 @@
+//! CSV reading shared by the Golden Copy parsers.
+//!
+//! The CSV forms of the Golden Copy files are read with the [`csv`] crate; this module builds the
+//! errors for columns and values missing from them, alongside the XML helpers in
+//! [`xml`](super::xml).
+
+use crate::error::{GleifError, ParseErrorKind};
+use csv::StringRecord;
+
+/// Builds the error for a mandatory column missing from the header row.
+pub(crate) fn missing_column(column: &str) -> GleifError {
+    GleifError::ParseError {
+        kind: ParseErrorKind::Field,
+        message: format!("missing column `{column}`"),
+    }
+}
+
+/// Builds the error for an empty mandatory column.
+pub(crate) fn missing(column: &str, row: &StringRecord) -> GleifError {
+    let line = row.position().map_or(0, csv::Position::line);
+    GleifError::ParseError {
+        kind: ParseErrorKind::Field,
+        message: format!("missing value in column `{column}` on line {line}"),
+    }
+}
@@ -0,0 +1,323 @@
+//! Application of LEI-CDF delta files to a local store of LEI records.
+
+use crate::{
+    error::Result,
+    field::Field,
+    model::{
+        enums::{EntityStatus, RegistrationStatus},
+        lei_record::LeiRecordAttributes,
+    },
+    value::FieldValue,
+};
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    hash::BuildHasher,
+};
+
+/// `XPath` prefix of the elements of an LEI record.
+const LEI_RECORD_XPATH: &str = "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/";
+
+/// A local store of LEI records keyed by LEI, kept current by applying delta files.
+///
+/// The store is implemented for [`HashMap`] and [`BTreeMap`]; implement it for a database table or
+/// any other storage to apply deltas there.
+pub trait RecordStore {
+    /// Returns the stored record of an LEI, if any.
+    fn get(&self, lei: &str) -> Option<&LeiRecordAttributes>;
+
+    /// Stores a record, replacing the record of the same LEI.
+    fn insert(&mut self, record: LeiRecordAttributes);
+
+    /// Applies the records of a delta file to the store, yielding the resulting changes.
+    ///
+    /// Delta files carry the complete new state of every record added or changed in their period,
+    /// so each record replaces the stored record of its LEI. Records identical to or older than the
+    /// stored record (by `LastUpdateDate`) are skipped, which makes it safe to apply overlapping
+    /// deltas, such as the last 8 hours and the last day, or to apply the same delta twice. Records
+    /// leaving the published population are not removed from delta files but reported with their
+    /// final status (e.g. `RETIRED` or `ANNULLED`), which surfaces as a status transition.
+    ///
+    /// The store is updated as the returned iterator is consumed.
+    ///
+    /// # Parameters
+    ///
+    /// - `records` - The records of the delta file, e.g. an [`LeiCdfReader`](crate::golden_copy::LeiCdfReader).
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use gleif_rs::golden_copy::{LeiCdfReader, LeiChange, RecordStore};
+    /// use std::collections::HashMap;
+    ///
+    /// let mut store = HashMap::new();
+    /// for record in LeiCdfReader::open("20250101-0000-gleif-goldencopy-lei2-golden-copy.xml")? {
+    ///     let record = record?;
+    ///     store.insert(record.lei.clone(), record);
+    /// }
+    /// let delta = LeiCdfReader::open("20250102-0000-gleif-goldencopy-lei2-last-day.xml")?;
+    /// for change in store.apply_delta(delta) {
+    ///     if let LeiChange::Added { lei } = change? {
+    ///         println!("New LEI {lei}");
+    ///     }
+    /// }
+    /// ```
+    fn apply_delta<I>(&mut self, records: I) -> DeltaChanges<'_, Self, I::IntoIter>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Result<LeiRecordAttributes>>,
+    {
+        DeltaChanges {
+            store: self,
+            records: records.into_iter(),
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+impl<S: BuildHasher> RecordStore for HashMap<String, LeiRecordAttributes, S> {
+    fn get(&self, lei: &str) -> Option<&LeiRecordAttributes> {
+        HashMap::get(self, lei)
+    }
+
+    fn insert(&mut self, record: LeiRecordAttributes) {
+        HashMap::insert(self, record.lei.clone(), record);
+    }
+}
+
+impl RecordStore for BTreeMap<String, LeiRecordAttributes> {
+    fn get(&self, lei: &str) -> Option<&LeiRecordAttributes> {
+        BTreeMap::get(self, lei)
+    }
+
+    fn insert(&mut self, record: LeiRecordAttributes) {
+        BTreeMap::insert(self, record.lei.clone(), record);
+    }
+}
+
+/// The change of a single [`Field`] between the stored and the new state of a record.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldChange {
+    /// The changed field.
+    pub field: Field,
+    /// The stored value, or `None` if the field was not set.
+    pub old: Option<FieldValue>,
+    /// The new value, or `None` if the field is no longer set.
+    pub new: Option<FieldValue>,
+}
+
+/// A change to the store caused by a delta record.
+///
+/// A changed record yields its status transitions first, followed by one [`LeiChange::Updated`]
+/// with the remaining changes.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LeiChange {
+    /// A record for an LEI that was not in the store.
+    Added {
+        /// The new LEI.
+        lei: String,
+    },
+    /// A changed record.
+    Updated {
+        /// The LEI of the record.
+        lei: String,
+        /// The changed fields other than the entity and registration status, in catalogue order.
+        /// Only fields backed by a single LEI-CDF element are compared, so the list is empty when
+        /// only repeated elements such as legal entity events changed.
+        fields: Vec<FieldChange>,
+    },
+    /// A change of the entity status, e.g. from `ACTIVE` to `INACTIVE`.
+    EntityStatusChanged {
+        /// The LEI of the record.
+        lei: String,
+        /// The stored status.
+        from: EntityStatus,
+        /// The new status.
+        to: EntityStatus,
+    },
+    /// A change of the registration status, e.g. from `ISSUED` to `LAPSED`.
+    RegistrationStatusChanged {
+        /// The LEI of the record.
+        lei: String,
+        /// The stored status.
+        from: RegistrationStatus,
+        /// The new status.
+        to: RegistrationStatus,
+    },
+}
+
+impl LeiChange {
+    /// Returns the LEI the change applies to.
+    #[must_use]
+    pub fn lei(&self) -> &str {
+        match self {
+            LeiChange::Added { lei }
+            | LeiChange::Updated { lei, .. }
+            | LeiChange::EntityStatusChanged { lei, .. }
+            | LeiChange::RegistrationStatusChanged { lei, .. } => lei,
+        }
+    }
+}
+
+/// The iterator returned by [`RecordStore::apply_delta`], applying one delta record per step and
+/// yielding the resulting [`LeiChange`]s.
+///
+/// Errors of the delta records are passed through, leaving the store unchanged for those records.
+#[derive(Debug)]
+pub struct DeltaChanges<'a, S, I> {
+    store: &'a mut S,
+    records: I,
+    pending: VecDeque<LeiChange>,
+}
+
+impl<S, I> Iterator for DeltaChanges<'_, S, I>
+where
+    S: RecordStore,
+    I: Iterator<Item = Result<LeiRecordAttributes>>,
+{
+    type Item = Result<LeiChange>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(change) = self.pending.pop_front() {
+                return Some(Ok(change));
+            }
+            let record = match self.records.next()? {
+                Ok(record) => record,
+                Err(error) => return Some(Err(error)),
+            };
+            match self.store.get(&record.lei) {
+                None => self.pending.push_back(LeiChange::Added {
+                    lei: record.lei.clone(),
+                }),
+                Some(stored) => {
+                    if stored == &record
+                        || stored.registration.last_update_date
+                            > record.registration.last_update_date
+                    {
+                        continue;
+                    }
+                    self.pending.extend(changes(stored, &record));
+                }
+            }
+            self.store.insert(record);
+        }
+    }
+}
+
+/// Compares the stored and the new state of a record.
+fn changes(old: &LeiRecordAttributes, new: &LeiRecordAttributes) -> Vec<LeiChange> {
+    let lei = &new.lei;
+    let mut changes = Vec::new();
+    if old.entity.status != new.entity.status {
+        changes.push(LeiChange::EntityStatusChanged {
+            lei: lei.clone(),
+            from: old.entity.status.clone(),
+            to: new.entity.status.clone(),
+        });
+    }
+    if old.registration.status != new.registration.status {
+        changes.push(LeiChange::RegistrationStatusChanged {
+            lei: lei.clone(),
+            from: old.registration.status.clone(),
+            to: new.registration.status.clone(),
+        });
+    }
+    let fields = compared_fields()
+        .filter_map(|field| {
+            let (old, new) = (old.get(field), new.get(field));
+            (old != new).then_some(FieldChange { field, old, new })
+        })
+        .collect();
+    changes.push(LeiChange::Updated {
+        lei: lei.clone(),
+        fields,
+    });
+    changes
+}
+
+/// Returns the fields compared for [`LeiChange::Updated`]: those backed by an unindexed LEI-CDF
+/// element, other than the statuses, and the conformity flag.
+fn compared_fields() -> impl Iterator<Item = Field> {
+    Field::ALL
+        .iter()
+        .copied()
+        .filter(|field| {
+            field
+                .xpath()
+                .is_some_and(|xpath| xpath.starts_with(LEI_RECORD_XPATH) && !xpath.contains('['))
+                && !matches!(field, Field::EntityStatus | Field::RegistrationStatus)
+        })
+        .chain([Field::ConformityFlag])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::{GleifError, ParseErrorKind},
+        golden_copy::LeiCdfReader,
+    };
+    use chrono::Duration;
+
+    #[test]
+    fn test_apply_delta() {
+        let records: Vec<_> = LeiCdfReader::open("tests/data/golden_copy/lei_cdf_sample.xml")
+            .expect("Failed to open sample file")
+            .filter_map(Result::ok)
+            .collect();
+        let mut store = HashMap::new();
+        for record in &records {
+            RecordStore::insert(&mut store, record.clone());
+        }
+
+        let mut moved = records[0].clone();
+        moved.entity.legal_address.city = "Berlin".to_string();
+        moved.registration.status = RegistrationStatus::Lapsed;
+        moved.registration.last_update_date += Duration::days(1);
+        let mut stale = records[1].clone();
+        stale.entity.legal_name.name = "Outdated Fund".to_string();
+        stale.registration.last_update_date -= Duration::days(1);
+        let mut added = records[0].clone();
+        added.lei = "529900T8BM49AURSDO55".to_string();
+        let delta = vec![
+            Ok(moved.clone()),
+            Ok(stale),
+            Ok(records[1].clone()),
+            Err(GleifError::ParseError {
+                kind: ParseErrorKind::Field,
+                message: "missing element `LEI`".to_string(),
+            }),
+            Ok(added),
+        ];
+
+        let changes: Vec<_> = store.apply_delta(delta).collect();
+        assert_eq!(changes.len(), 4);
+        assert_eq!(
+            changes[0].as_ref().unwrap(),
+            &LeiChange::RegistrationStatusChanged {
+                lei: moved.lei.clone(),
+                from: RegistrationStatus::Issued,
+                to: RegistrationStatus::Lapsed,
+            }
+        );
+        let LeiChange::Updated { lei, fields } = changes[1].as_ref().unwrap() else {
+            panic!("Expected an update");
+        };
+        assert_eq!(lei, &moved.lei);
+        let fields: Vec<_> = fields.iter().map(|change| change.field).collect();
+        assert_eq!(
+            fields,
+            [
+                Field::EntityLegalAddressCity,
+                Field::RegistrationLastUpdateDate
+            ]
+        );
+        assert!(changes[2].is_err());
+        assert_eq!(changes[3].as_ref().unwrap().lei(), "529900T8BM49AURSDO55");
+
+        assert_eq!(store.len(), 3);
+        assert_eq!(store[&moved.lei], moved);
+        assert_eq!(store[&records[1].lei], records[1]);
+        assert_eq!(store.apply_delta(vec![Ok(moved)]).count(), 0);
+    }
+}
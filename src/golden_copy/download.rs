@@ -0,0 +1,553 @@
+//! Listing and downloading of Golden Copy publications through the Golden Copy publication API.
+
+use crate::{
+    client::GleifClient,
+    error::{GleifError, ParseErrorKind, ResponseContent, Result},
+};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Deserializer, de::DeserializeOwned};
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+use zip::ZipArchive;
+
+/// The populations published as Golden Copy files.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FileType {
+    /// Level 1 LEI records (LEI-CDF).
+    Lei2,
+    /// Level 2 relationship records (RR-CDF).
+    Rr,
+    /// Level 2 reporting exceptions.
+    Repex,
+}
+
+/// The formats Golden Copy files are published in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FileFormat {
+    /// XML, as read by [`LeiCdfReader`](crate::golden_copy::LeiCdfReader) and the other XML readers.
+    Xml,
+    /// CSV, as read by [`LeiCsvReader`](crate::golden_copy::LeiCsvReader) and
+    /// [`ReportingExceptionCsvReader`](crate::golden_copy::ReportingExceptionCsvReader).
+    Csv,
+    /// JSON.
+    Json,
+}
+
+/// The periods covered by delta files.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DeltaPeriod {
+    /// The changes since the previous publication, 8 hours earlier.
+    IntraDay,
+    /// The changes of the last 24 hours.
+    LastDay,
+    /// The changes of the last 7 days.
+    LastWeek,
+    /// The changes of the last 31 days.
+    LastMonth,
+}
+
+/// A Golden Copy publication: the full and delta files of all populations published at one time.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Publication {
+    /// The time of publication.
+    #[serde(deserialize_with = "publish_date")]
+    pub publish_date: DateTime<Utc>,
+    /// The LEI record files.
+    pub lei2: PublishedFiles,
+    /// The relationship record files.
+    pub rr: PublishedFiles,
+    /// The reporting exception files.
+    pub repex: PublishedFiles,
+}
+
+impl Publication {
+    /// Returns the published files of a population.
+    #[must_use]
+    pub fn files(&self, file_type: FileType) -> &PublishedFiles {
+        match file_type {
+            FileType::Lei2 => &self.lei2,
+            FileType::Rr => &self.rr,
+            FileType::Repex => &self.repex,
+        }
+    }
+
+    /// Returns the full Golden Copy file of a population in a format, if published.
+    #[must_use]
+    pub fn full_file(&self, file_type: FileType, format: FileFormat) -> Option<&PublishedFile> {
+        self.files(file_type).full_file.get(format)
+    }
+
+    /// Returns the delta file of a population for a period in a format, if published.
+    #[must_use]
+    pub fn delta_file(
+        &self,
+        file_type: FileType,
+        period: DeltaPeriod,
+        format: FileFormat,
+    ) -> Option<&PublishedFile> {
+        self.files(file_type).delta_files.get(period)?.get(format)
+    }
+}
+
+/// The full and delta files of one population in a [`Publication`].
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct PublishedFiles {
+    /// The full Golden Copy file.
+    pub full_file: FileFormats,
+    /// The delta files.
+    #[serde(default)]
+    pub delta_files: DeltaFiles,
+}
+
+/// The delta files of one population, by period.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeltaFiles {
+    /// The changes since the previous publication.
+    pub intra_day: Option<FileFormats>,
+    /// The changes of the last 24 hours.
+    pub last_day: Option<FileFormats>,
+    /// The changes of the last 7 days.
+    pub last_week: Option<FileFormats>,
+    /// The changes of the last 31 days.
+    pub last_month: Option<FileFormats>,
+}
+
+impl DeltaFiles {
+    /// Returns the delta file of a period.
+    #[must_use]
+    pub fn get(&self, period: DeltaPeriod) -> Option<&FileFormats> {
+        match period {
+            DeltaPeriod::IntraDay => self.intra_day.as_ref(),
+            DeltaPeriod::LastDay => self.last_day.as_ref(),
+            DeltaPeriod::LastWeek => self.last_week.as_ref(),
+            DeltaPeriod::LastMonth => self.last_month.as_ref(),
+        }
+    }
+}
+
+/// One published file, by format.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct FileFormats {
+    /// The XML file.
+    pub xml: Option<PublishedFile>,
+    /// The CSV file.
+    pub csv: Option<PublishedFile>,
+    /// The JSON file.
+    pub json: Option<PublishedFile>,
+}
+
+impl FileFormats {
+    /// Returns the file in a format.
+    #[must_use]
+    pub fn get(&self, format: FileFormat) -> Option<&PublishedFile> {
+        match format {
+            FileFormat::Xml => self.xml.as_ref(),
+            FileFormat::Csv => self.csv.as_ref(),
+            FileFormat::Json => self.json.as_ref(),
+        }
+    }
+}
+
+/// A zipped file of a [`Publication`].
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct PublishedFile {
+    /// The download URL of the zip archive.
+    pub url: String,
+    /// The size of the zip archive in bytes.
+    pub size: u64,
+    /// The number of records in the file.
+    pub record_count: Option<u64>,
+}
+
+/// A Golden Copy file downloaded and unzipped by [`GleifClient::download_golden_copy`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DownloadedFile {
+    /// The path of the unzipped file.
+    pub path: PathBuf,
+    /// The time of publication of the file.
+    pub publish_date: DateTime<Utc>,
+    /// The size of the downloaded zip archive in bytes.
+    pub size: u64,
+    /// The number of records in the file, as published.
+    pub record_count: Option<u64>,
+}
+
+/// The response envelope of the publication API.
+#[derive(Deserialize)]
+struct Response<T> {
+    data: T,
+}
+
+impl GleifClient {
+    /// Lists the available Golden Copy publications, most recent first.
+    ///
+    /// Publications are requested from the Golden Copy publication API at
+    /// [`GleifClient::golden_copy_url`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`crate::error::GleifError`] if the request fails or the response
+    /// cannot be deserialized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// for publication in client.golden_copy_publications().await? {
+    ///     println!("{}", publication.publish_date);
+    /// }
+    /// ```
+    pub async fn golden_copy_publications(&self) -> Result<Vec<Publication>> {
+        self.golden_copy_request("golden-copies/publishes").await
+    }
+
+    /// Fetches the most recent Golden Copy publication.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`crate::error::GleifError`] if the request fails or the response
+    /// cannot be deserialized.
+    pub async fn latest_golden_copy(&self) -> Result<Publication> {
+        self.golden_copy_request("golden-copies/publishes/latest")
+            .await
+    }
+
+    /// Downloads a file of a publication into a directory and unzips it.
+    ///
+    /// The zip archive is streamed to disk, checked against the published size, and unzipped next
+    /// to it, verifying the CRC-32 checksum of every entry. The archive is removed afterwards,
+    /// whether or not it could be unzipped. Files are written under temporary `.part` names and only
+    /// renamed when complete, so an interrupted download never leaves a truncated file under the
+    /// final name. Unzipping happens on the calling task; large files are best downloaded from a
+    /// blocking-tolerant task.
+    ///
+    /// # Parameters
+    ///
+    /// - `publication` - The publication the file belongs to.
+    /// - `file` - The file to download, e.g. from [`Publication::full_file`] or
+    ///   [`Publication::delta_file`].
+    /// - `dir` - The existing directory to download into.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`crate::error::GleifError`] in the following cases:
+    /// - The download fails or the server responds with an error status.
+    /// - The size of the archive differs from the published size, reported as a
+    ///   [`GleifError::SizeMismatchError`].
+    /// - The archive is not a valid zip file, a checksum does not match, or it contains no file.
+    /// - The files cannot be written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use gleif_rs::golden_copy::{DeltaPeriod, FileFormat, FileType, LeiCdfReader};
+    ///
+    /// let publication = client.latest_golden_copy().await?;
+    /// let file = publication
+    ///     .delta_file(FileType::Lei2, DeltaPeriod::LastDay, FileFormat::Xml)
+    ///     .expect("Delta file should be published");
+    /// let downloaded = client.download_golden_copy(&publication, file, "data").await?;
+    /// for record in LeiCdfReader::open(&downloaded.path)? {
+    ///     println!("{}", record?.lei);
+    /// }
+    /// ```
+    pub async fn download_golden_copy(
+        &self,
+        publication: &Publication,
+        file: &PublishedFile,
+        dir: impl AsRef<Path>,
+    ) -> Result<DownloadedFile> {
+        let dir = dir.as_ref();
+        let archive = dir.join(archive_name(&file.url));
+        let size = self.download(&file.url, &archive).await?;
+        if size != file.size {
+            fs::remove_file(&archive)?;
+            return Err(GleifError::SizeMismatchError {
+                url: file.url.clone(),
+                expected: file.size,
+                actual: size,
+            });
+        }
+        let path = unzip(&archive, dir);
+        fs::remove_file(&archive)?;
+        Ok(DownloadedFile {
+            path: path?,
+            publish_date: publication.publish_date,
+            size,
+            record_count: file.record_count,
+        })
+    }
+
+    /// Private helper fetching and deserializing a path of the publication API.
+    async fn golden_copy_request<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let url = self.golden_copy_url().join(path)?;
+        let response = self.client().get(url).send().await?;
+        let status = response.status();
+        let content = response.text().await?;
+        if status.is_client_error() || status.is_server_error() {
+            return Err(GleifError::ResponseError(ResponseContent {
+                status,
+                content,
+            }));
+        }
+        Ok(serde_json::from_str::<Response<T>>(&content)?.data)
+    }
+
+    /// Private helper streaming a URL to a file, returning the number of bytes written.
+    async fn download(&self, url: &str, path: &Path) -> Result<u64> {
+        let mut response = self.client().get(url).send().await?;
+        let status = response.status();
+        if status.is_client_error() || status.is_server_error() {
+            let content = response.text().await?;
+            return Err(GleifError::ResponseError(ResponseContent {
+                status,
+                content,
+            }));
+        }
+        let part = part_path(path);
+        let mut out = BufWriter::new(File::create(&part)?);
+        let mut size = 0;
+        let written: Result<()> = async {
+            while let Some(chunk) = response.chunk().await? {
+                out.write_all(&chunk)?;
+                size += chunk.len() as u64;
+            }
+            Ok(())
+        }
+        .await;
+        if let Err(error) = written {
+            drop(out);
+            fs::remove_file(&part)?;
+            return Err(error);
+        }
+        out.into_inner().map_err(io::IntoInnerError::into_error)?;
+        fs::rename(&part, path)?;
+        Ok(size)
+    }
+}
+
+/// Returns the file name of the archive at a URL: its last path segment, without query or fragment.
+fn archive_name(url: &str) -> &str {
+    url.split(['?', '#'])
+        .next()
+        .and_then(|path| path.rsplit('/').next())
+        .filter(|name| !name.is_empty())
+        .unwrap_or("golden-copy.zip")
+}
+
+/// Unzips the files of an archive into a directory, returning the path of the first file.
+///
+/// Entries are unzipped flat into the directory by file name, so archives cannot write elsewhere.
+fn unzip(archive: &Path, dir: &Path) -> Result<PathBuf> {
+    let mut archive = ZipArchive::new(File::open(archive)?).map_err(io::Error::from)?;
+    let mut first = None;
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index).map_err(io::Error::from)?;
+        let Some(name) = entry
+            .enclosed_name()
+            .filter(|_| entry.is_file())
+            .and_then(|name| name.file_name().map(ToOwned::to_owned))
+        else {
+            continue;
+        };
+        let path = dir.join(name);
+        let part = part_path(&path);
+        let mut out = BufWriter::new(File::create(&part)?);
+        // Reading the entry to its end verifies its CRC-32 checksum.
+        if let Err(error) = io::copy(&mut entry, &mut out) {
+            drop(out);
+            fs::remove_file(&part)?;
+            return Err(error.into());
+        }
+        out.into_inner().map_err(io::IntoInnerError::into_error)?;
+        fs::rename(&part, &path)?;
+        first.get_or_insert(path);
+    }
+    first.ok_or_else(|| GleifError::ParseError {
+        kind: ParseErrorKind::Value,
+        message: "zip archive contains no file".to_string(),
+    })
+}
+
+/// Returns the temporary path a file is written to before it is complete.
+fn part_path(path: &Path) -> PathBuf {
+    let mut part = path.as_os_str().to_owned();
+    part.push(".part");
+    PathBuf::from(part)
+}
+
+/// Deserializes a publish date given as `2025-04-04 08:00:00` (UTC) or in RFC 3339.
+fn publish_date<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<DateTime<Utc>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    NaiveDateTime::parse_from_str(&value, "%Y-%m-%d %H:%M:%S")
+        .map(|date| date.and_utc())
+        .or_else(|_| DateTime::parse_from_rfc3339(&value).map(|date| date.with_timezone(&Utc)))
+        .map_err(|_| serde::de::Error::custom(format!("invalid publish date `{value}`")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_server;
+    use std::{
+        io::Cursor,
+        sync::{Arc, OnceLock},
+    };
+    use zip::{ZipWriter, write::SimpleFileOptions};
+
+    /// Zips the LEI-CDF sample file.
+    fn sample_zip() -> Vec<u8> {
+        let content = fs::read("tests/data/golden_copy/lei_cdf_sample.xml").unwrap();
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        zip.start_file("lei_cdf_sample.xml", SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(&content).unwrap();
+        zip.finish().unwrap().into_inner()
+    }
+
+    /// Serves the sample publication and archive on a local port, standing in for the
+    /// publication API, and returns its base URL.
+    async fn serve(archive: Vec<u8>, published_size: usize) -> String {
+        // The publication links to the archive on the server, so it is set once the server is bound.
+        let publication = Arc::new(OnceLock::new());
+        let base = mock_server({
+            let publication = Arc::clone(&publication);
+            move |target| match target {
+                "/api/v2/golden-copies/publishes/latest" => publication.get().cloned(),
+                _ if target.starts_with("/storage/") => Some(archive.clone()),
+                _ => None,
+            }
+        })
+        .await;
+        let body = fs::read_to_string("tests/data/golden_copy/publishes_latest.json")
+            .unwrap()
+            .replace("https://goldencopy.gleif.org", &base)
+            .replace("\"size\": 0", &format!("\"size\": {published_size}"));
+        publication.set(body.into_bytes()).unwrap();
+        base
+    }
+
+    /// Creates a client for the stand-in publication API.
+    fn stand_in_client(base: &str) -> GleifClient {
+        GleifClient::builder()
+            .golden_copy_url(format!("{base}/api/v2/"))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_publication() {
+        let data = fs::read_to_string("tests/data/golden_copy/publishes_latest.json").unwrap();
+        let publication = serde_json::from_str::<Response<Publication>>(&data)
+            .unwrap()
+            .data;
+        assert_eq!(
+            publication.publish_date.to_rfc3339(),
+            "2025-04-04T08:00:00+00:00"
+        );
+        let full = publication
+            .full_file(FileType::Lei2, FileFormat::Xml)
+            .unwrap();
+        assert_eq!(full.record_count, Some(3));
+        let delta = publication
+            .delta_file(FileType::Rr, DeltaPeriod::LastWeek, FileFormat::Csv)
+            .unwrap();
+        assert!(delta.url.ends_with("gleif-goldencopy-rr-last-week.csv.zip"));
+        assert!(
+            publication
+                .delta_file(FileType::Repex, DeltaPeriod::IntraDay, FileFormat::Json)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_archive_name() {
+        let url = "https://goldencopy.gleif.org/storage/golden-copy-files/2025/04/04/lei2.xml.zip";
+        assert_eq!(archive_name(url), "lei2.xml.zip");
+        assert_eq!(
+            archive_name(&format!("{url}?signature=a/b&expires=1#part")),
+            "lei2.xml.zip"
+        );
+        assert_eq!(archive_name(&format!("{url}#a/b")), "lei2.xml.zip");
+        assert_eq!(archive_name("https://example.com/"), "golden-copy.zip");
+    }
+
+    #[tokio::test]
+    async fn test_download_golden_copy() {
+        let archive = sample_zip();
+        let base = serve(archive.clone(), archive.len()).await;
+        let client = stand_in_client(&base);
+        let publication = client.latest_golden_copy().await.unwrap();
+        let file = publication
+            .full_file(FileType::Lei2, FileFormat::Xml)
+            .unwrap();
+
+        let dir = std::env::temp_dir().join(format!("gleif-download-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let downloaded = client
+            .download_golden_copy(&publication, file, &dir)
+            .await
+            .unwrap();
+        assert_eq!(downloaded.path, dir.join("lei_cdf_sample.xml"));
+        assert_eq!(downloaded.publish_date, publication.publish_date);
+        assert_eq!(downloaded.size, archive.len() as u64);
+        assert_eq!(
+            fs::read(&downloaded.path).unwrap(),
+            fs::read("tests/data/golden_copy/lei_cdf_sample.xml").unwrap()
+        );
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_download_golden_copy_integrity() {
+        let mut archive = sample_zip();
+        let base = serve(archive.clone(), archive.len() + 1).await;
+        let client = stand_in_client(&base);
+        let publication = client.latest_golden_copy().await.unwrap();
+        let file = publication
+            .full_file(FileType::Lei2, FileFormat::Xml)
+            .unwrap();
+        let dir = std::env::temp_dir().join(format!("gleif-integrity-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let error = client
+            .download_golden_copy(&publication, file, &dir)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            GleifError::SizeMismatchError { expected, actual, .. } if expected == actual + 1
+        ));
+
+        // Corrupt the compressed content so the checksum no longer matches.
+        let offset = archive.len() / 3;
+        archive[offset] ^= 0xFF;
+        let base = serve(archive.clone(), archive.len()).await;
+        let client = stand_in_client(&base);
+        let publication = client.latest_golden_copy().await.unwrap();
+        let file = publication
+            .full_file(FileType::Lei2, FileFormat::Xml)
+            .unwrap();
+        assert!(
+            client
+                .download_golden_copy(&publication, file, &dir)
+                .await
+                .is_err()
+        );
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+
+        let missing = GleifClient::builder()
+            .golden_copy_url(format!("{base}/missing/"))
+            .build()
+            .unwrap()
+            .latest_golden_copy()
+            .await;
+        assert!(matches!(missing, Err(GleifError::ResponseError(_))));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
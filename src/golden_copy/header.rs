@@ -0,0 +1,54 @@
+//! The header shared by the Golden Copy XML formats.
+
+use crate::{
+    error::Result,
+    golden_copy::xml::{Element, invalid},
+};
+use chrono::{DateTime, Utc};
+
+/// The header of a Golden Copy or delta file (`LEIHeader` in LEI-CDF, `Header` in RR-CDF and the
+/// reporting exceptions format).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileHeader {
+    /// The date and time the content of the file was published.
+    pub content_date: DateTime<Utc>,
+    /// The LEI of the organization that produced the file, usually GLEIF.
+    pub originator: Option<String>,
+    /// The kind of content, e.g. `GLEIF_FULL_PUBLISHED` or `GLEIF_DELTA_PUBLISHED`.
+    pub file_content: Option<String>,
+    /// For delta files, the start of the period the changes were collected over.
+    pub delta_start: Option<DateTime<Utc>>,
+    /// The number of records in the file, as declared by the publisher.
+    pub record_count: Option<u64>,
+}
+
+impl FileHeader {
+    /// Reads the header from its element.
+    pub(crate) fn from_element(element: &Element) -> Result<Self> {
+        let record_count = element
+            .child_text("RecordCount")
+            .map(|count| {
+                count
+                    .parse()
+                    .map_err(|_| invalid(&format!("invalid record count `{count}`")))
+            })
+            .transpose()?;
+        Ok(Self {
+            content_date: element.date("ContentDate")?,
+            originator: element.child_text("Originator"),
+            file_content: element.child_text("FileContent"),
+            delta_start: element.optional_date("DeltaStart")?,
+            record_count,
+        })
+    }
+
+    /// Returns `true` if the file holds the changes over a period rather than a full population.
+    #[must_use]
+    pub fn is_delta(&self) -> bool {
+        self.delta_start.is_some()
+            || self
+                .file_content
+                .as_deref()
+                .is_some_and(|content| content.contains("DELTA"))
+    }
+}
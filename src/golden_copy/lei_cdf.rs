@@ -0,0 +1,469 @@
+//! Streaming parser for LEI-CDF 3.1 Golden Copy and delta files.
+
+use crate::{
+    error::Result,
+    golden_copy::{
+        header::FileHeader,
+        xml::{Element, RecordStream, in_record},
+    },
+    history::parse_code,
+    model::lei_record::{
+        Address, AffectedFields, AssociatedEntity, Entity, Event, EventGroup, Expiration,
+        LegalForm, LeiRecordAttributes, Name, OtherAddress, OtherName, OtherValidationAuthority,
+        Registration, RegistrationAuthority, SuccessorEntity, TransliteratedOtherName,
+        ValidationAuthority,
+    },
+};
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+};
+
+/// A streaming reader over an LEI-CDF 3.1 file, yielding one [`LeiRecordAttributes`] per
+/// `LEIRecord`.
+///
+/// Only one record is held in memory at a time, so the full Golden Copy can be processed with
+/// bounded memory. Records are mapped onto the same model as the API responses:
+///
+/// - Dates are normalized to UTC.
+/// - `FirstAddressLine` and the `AdditionalAddressLine`s are merged into `address_lines`.
+/// - Legal entity events are grouped into [`EventGroup`]s by their `group_id`.
+/// - Elements wrapped in `NextVersion` (such as the `ConformityFlag`) are read as if they were
+///   direct children, and `Extension` content is skipped.
+///
+/// The `bic`, `mic`, `ocid`, `qcc` and `spglobal` attributes are not part of LEI-CDF and are always
+/// `None`; they are published as separate mapping files.
+#[derive(Debug)]
+pub struct LeiCdfReader<R> {
+    stream: RecordStream<R>,
+}
+
+impl LeiCdfReader<BufReader<File>> {
+    /// Opens an unzipped LEI-CDF file.
+    ///
+    /// # Parameters
+    ///
+    /// - `path` - The path of the XML file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or its header cannot be read.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: BufRead> LeiCdfReader<R> {
+    /// Creates a reader over LEI-CDF content, reading the `LEIHeader` right away.
+    ///
+    /// # Parameters
+    ///
+    /// - `reader` - The buffered source of the XML document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document is not well-formed up to the header, or the header is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use gleif_rs::golden_copy::LeiCdfReader;
+    ///
+    /// let reader = LeiCdfReader::open("20250101-0000-gleif-goldencopy-lei2-golden-copy.xml")?;
+    /// println!("{:?} records", reader.header().and_then(|header| header.record_count));
+    /// for record in reader {
+    ///     let record = record?;
+    ///     println!("{}: {}", record.lei, record.entity.legal_name.name);
+    /// }
+    /// ```
+    pub fn new(reader: R) -> Result<Self> {
+        Ok(Self {
+            stream: RecordStream::new(reader, "LEIHeader", "LEIRecord")?,
+        })
+    }
+
+    /// Returns the file header, if the file has one.
+    #[must_use]
+    pub fn header(&self) -> Option<&FileHeader> {
+        self.stream.header()
+    }
+}
+
+impl<R: BufRead> Iterator for LeiCdfReader<R> {
+    type Item = Result<LeiRecordAttributes>;
+
+    /// Reads the next record.
+    ///
+    /// A record with missing or invalid content yields an error and reading continues with the next
+    /// record; a malformed document yields an error and ends the iteration.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.stream
+            .next_record()
+            .map(|element| element.and_then(|element| record(&element)))
+    }
+}
+
+/// Maps an `LEIRecord` element.
+pub(crate) fn record(element: &Element) -> Result<LeiRecordAttributes> {
+    let lei = element.required_text("LEI")?;
+    let attributes = (|| {
+        Ok(LeiRecordAttributes {
+            entity: entity(element.required("Entity")?)?,
+            registration: registration(element.required("Registration")?)?,
+            bic: None,
+            mic: None,
+            ocid: None,
+            qcc: None,
+            spglobal: None,
+            conformity_flag: element.optional_code("ConformityFlag")?,
+            lei: lei.clone(),
+        })
+    })();
+    attributes.map_err(|error| in_record(error, &lei))
+}
+
+/// Maps an `Entity` element.
+fn entity(element: &Element) -> Result<Entity> {
+    let legal_name = element.required("LegalName")?;
+    let registration_authority = element.required("RegistrationAuthority")?;
+    let legal_form = element.required("LegalForm")?;
+    let successors = element
+        .children("SuccessorEntity")
+        .map(successor)
+        .collect::<Vec<_>>();
+    let transliterated_addresses = element
+        .list("TransliteratedOtherAddresses", "TransliteratedOtherAddress")
+        .map(other_address)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Entity {
+        legal_name: Name {
+            name: legal_name.text().to_string(),
+            language: language(legal_name),
+        },
+        other_names: element
+            .list("OtherEntityNames", "OtherEntityName")
+            .map(|name| {
+                Ok(OtherName {
+                    name: name.text().to_string(),
+                    language: language(name),
+                    r#type: parse_code(name.required_attribute("type")?)?,
+                })
+            })
+            .collect::<Result<_>>()?,
+        transliterated_other_names: element
+            .list(
+                "TransliteratedOtherEntityNames",
+                "TransliteratedOtherEntityName",
+            )
+            .map(|name| {
+                Ok(TransliteratedOtherName {
+                    name: name.text().to_string(),
+                    language: language(name),
+                    r#type: parse_code(name.required_attribute("type")?)?,
+                })
+            })
+            .collect::<Result<_>>()?,
+        legal_address: address(element.required("LegalAddress")?)?,
+        headquarters_address: address(element.required("HeadquartersAddress")?)?,
+        other_addresses: element
+            .list("OtherAddresses", "OtherAddress")
+            .map(other_address)
+            .collect::<Result<_>>()?,
+        transliterated_other_addresses: (!transliterated_addresses.is_empty())
+            .then_some(transliterated_addresses),
+        registered_at: RegistrationAuthority {
+            id: registration_authority.required_text("RegistrationAuthorityID")?,
+            other: registration_authority.child_text("OtherRegistrationAuthorityID"),
+            entity_id: None,
+        },
+        registered_as: registration_authority.child_text("RegistrationAuthorityEntityID"),
+        jurisdiction: element.required_text("LegalJurisdiction")?,
+        category: element.code("EntityCategory")?,
+        sub_category: element.optional_code("EntitySubCategory")?,
+        legal_form: LegalForm {
+            id: legal_form.required_text("EntityLegalFormCode")?,
+            other: legal_form.child_text("OtherLegalForm"),
+        },
+        associated_entity: associated_entity(element.child("AssociatedEntity"))?,
+        status: element.code("EntityStatus")?,
+        creation_date: element.optional_date("EntityCreationDate")?,
+        expiration: Expiration {
+            date: element.optional_date("EntityExpirationDate")?,
+            reason: element.optional_code("EntityExpirationReason")?,
+        },
+        successor_entity: successors.first().cloned().unwrap_or(SuccessorEntity {
+            lei: None,
+            name: None,
+        }),
+        successor_entities: successors,
+        event_groups: event_groups(element)?,
+    })
+}
+
+/// Returns the `xml:lang` attribute of an element.
+fn language(element: &Element) -> Option<String> {
+    element.attribute("lang").map(str::to_string)
+}
+
+/// Returns the first address line followed by the additional address lines.
+fn address_lines(element: &Element) -> Result<Vec<String>> {
+    let mut lines = vec![element.required_text("FirstAddressLine")?];
+    lines.extend(
+        element
+            .children("AdditionalAddressLine")
+            .map(|line| line.text().to_string())
+            .filter(|line| !line.is_empty()),
+    );
+    Ok(lines)
+}
+
+/// Maps a `LegalAddress` or `HeadquartersAddress` element.
+fn address(element: &Element) -> Result<Address> {
+    Ok(Address {
+        language: language(element),
+        address_lines: address_lines(element)?,
+        address_number: element.child_text("AddressNumber"),
+        address_number_within_building: element.child_text("AddressNumberWithinBuilding"),
+        mail_routing: element.child_text("MailRouting"),
+        additional_address_line: None,
+        city: element.required_text("City")?,
+        region: element.child_text("Region"),
+        country: element.required_text("Country")?,
+        postal_code: element.child_text("PostalCode"),
+    })
+}
+
+/// Maps an `OtherAddress` or `TransliteratedOtherAddress` element.
+fn other_address(element: &Element) -> Result<OtherAddress> {
+    Ok(OtherAddress {
+        field_type: element.name().to_string(),
+        language: language(element),
+        address_lines: address_lines(element)?,
+        address_number: element.child_text("AddressNumber"),
+        address_number_within_building: element.child_text("AddressNumberWithinBuilding"),
+        mail_routing: element.child_text("MailRouting"),
+        additional_address_line: None,
+        city: element.required_text("City")?,
+        region: element.child_text("Region"),
+        country: element.required_text("Country")?,
+        postal_code: element.child_text("PostalCode"),
+        r#type: parse_code(element.required_attribute("type")?)?,
+    })
+}
+
+/// Maps an optional `AssociatedEntity` element.
+fn associated_entity(element: Option<&Element>) -> Result<AssociatedEntity> {
+    Ok(AssociatedEntity {
+        lei: element.and_then(|element| element.child_text("AssociatedLEI")),
+        name: element.and_then(|element| element.child_text("AssociatedEntityName")),
+        r#type: element
+            .and_then(|element| element.attribute("type"))
+            .map(parse_code)
+            .transpose()?,
+    })
+}
+
+/// Maps a `SuccessorEntity` element.
+fn successor(element: &Element) -> SuccessorEntity {
+    SuccessorEntity {
+        lei: element.child_text("SuccessorLEI"),
+        name: element.child_text("SuccessorEntityName"),
+    }
+}
+
+/// Maps the `LegalEntityEvents` of an entity, grouping events that share a `group_id`.
+fn event_groups(element: &Element) -> Result<Vec<EventGroup>> {
+    let mut groups: Vec<(Option<&str>, EventGroup)> = Vec::new();
+    for event_element in element.list("LegalEntityEvents", "LegalEntityEvent") {
+        let id = event_element.attribute("group_id");
+        let event = event(event_element)?;
+        match groups
+            .iter_mut()
+            .find(|(group_id, _)| id.is_some() && *group_id == id)
+        {
+            Some((_, group)) => group.events.push(event),
+            None => groups.push((
+                id,
+                EventGroup {
+                    group_type: parse_code(event_element.required_attribute("group_type")?)?,
+                    events: vec![event],
+                },
+            )),
+        }
+    }
+    Ok(groups.into_iter().map(|(_, group)| group).collect())
+}
+
+/// Maps a `LegalEntityEvent` element.
+fn event(element: &Element) -> Result<Event> {
+    let affected_fields = element
+        .child("AffectedFields")
+        .map(|fields| {
+            fields
+                .children("AffectedField")
+                .map(|field| {
+                    Ok(AffectedFields {
+                        value: field.text().to_string(),
+                        xpath: field.required_attribute("xpath")?.to_string(),
+                    })
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .transpose()?;
+    Ok(Event {
+        r#type: element.code("LegalEntityEventType")?,
+        effective_date: element.date("LegalEntityEventEffectiveDate")?,
+        recorded_date: element.date("LegalEntityEventRecordedDate")?,
+        validation_documents: element.code("ValidationDocuments")?,
+        validation_reference: element.child_text("ValidationReference"),
+        affected_fields,
+        status: parse_code(element.required_attribute("event_status")?)?,
+    })
+}
+
+/// Maps a `Registration` element.
+fn registration(element: &Element) -> Result<Registration> {
+    let validation_authority = element.child("ValidationAuthority");
+    Ok(Registration {
+        initial_registration_date: element.date("InitialRegistrationDate")?,
+        last_update_date: element.date("LastUpdateDate")?,
+        status: element.code("RegistrationStatus")?,
+        next_renewal_date: element.date("NextRenewalDate")?,
+        managing_lou: element.required_text("ManagingLOU")?,
+        corroboration_level: element.code("ValidationSources")?,
+        validated_at: validation_authority.map_or(
+            ValidationAuthority {
+                id: String::new(),
+                other: None,
+            },
+            authority,
+        ),
+        validated_as: validation_authority
+            .and_then(|authority| authority.child_text("ValidationAuthorityEntityID")),
+        other_validation_authorities: element
+            .list("OtherValidationAuthorities", "OtherValidationAuthority")
+            .map(|other| OtherValidationAuthority {
+                validated_at: authority(other),
+                validated_as: other
+                    .child_text("ValidationAuthorityEntityID")
+                    .unwrap_or_default(),
+            })
+            .collect(),
+    })
+}
+
+/// Maps the identifiers of a `ValidationAuthority` or `OtherValidationAuthority` element.
+fn authority(element: &Element) -> ValidationAuthority {
+    ValidationAuthority {
+        id: element
+            .child_text("ValidationAuthorityID")
+            .unwrap_or_default(),
+        other: element.child_text("OtherValidationAuthorityID"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::GleifError,
+        model::{
+            enums::{ConformityFlag, EventType, GroupType},
+            lei_record::LeiRecord,
+        },
+        test_utils::read,
+    };
+    use std::fs;
+
+    const SAMPLE: &str = "tests/data/golden_copy/lei_cdf_sample.xml";
+
+    #[test]
+    fn test_read_lei_cdf() {
+        let reader = LeiCdfReader::open(SAMPLE).expect("Failed to open sample file");
+        let header = reader.header().expect("Missing header").clone();
+        assert_eq!(header.record_count, Some(3));
+        assert_eq!(header.originator.as_deref(), Some("EVK05KS7XY1DEII3R011"));
+        assert!(!header.is_delta());
+
+        let records: Vec<_> = reader.collect();
+        assert_eq!(records.len(), 3);
+
+        // The API record of the same entity, minus the attributes published as mapping files.
+        let mut expected =
+            read::<LeiRecord>("tests/data/lei_records/single_lei_record_851WYGNLUQLFZBSYGB56.json")
+                .attributes;
+        expected.bic = None;
+        expected.mic = None;
+        expected.ocid = None;
+        expected.qcc = None;
+        expected.spglobal = None;
+        assert_eq!(records[0].as_ref().unwrap(), &expected);
+
+        let fund = records[1].as_ref().unwrap();
+        assert_eq!(fund.entity.legal_name.name, "Smith & Jones Global Fund");
+        assert_eq!(fund.entity.other_names.len(), 1);
+        assert_eq!(fund.entity.transliterated_other_names.len(), 1);
+        assert_eq!(
+            fund.entity.legal_address.address_lines,
+            ["Floor 3", "1 Main Street", "Building B"]
+        );
+        assert_eq!(
+            fund.entity.transliterated_other_addresses.as_ref().unwrap()[0].field_type,
+            "TransliteratedOtherAddress"
+        );
+        assert_eq!(
+            fund.entity.associated_entity.lei.as_deref(),
+            Some("851WYGNLUQLFZBSYGB56")
+        );
+        assert_eq!(fund.entity.successor_entities.len(), 2);
+        assert_eq!(
+            fund.entity.successor_entity.lei.as_deref(),
+            Some("5493001KJTIIGC8Y1R12")
+        );
+        assert_eq!(fund.conformity_flag, Some(ConformityFlag::NonConforming));
+        assert_eq!(fund.registration.other_validation_authorities.len(), 1);
+
+        let groups = &fund.entity.event_groups;
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].group_type, GroupType::ComplexChangeLegalForm);
+        assert_eq!(groups[0].events.len(), 2);
+        assert_eq!(groups[1].events[0].r#type, EventType::ChangeLegalName);
+        let affected = groups[1].events[0].affected_fields.as_ref().unwrap();
+        assert_eq!(affected[0].value, "Smith & Jones Global Fund");
+
+        let invalid = records[2].as_ref().unwrap_err();
+        assert!(matches!(invalid, GleifError::ParseError { .. }));
+        assert!(invalid.to_string().contains("529900T8BM49AURSDO55"));
+        assert!(invalid.to_string().contains("EntityStatus"));
+
+        let xml = fs::read_to_string(SAMPLE)
+            .expect("Failed to read sample file")
+            .replace("<lei:LegalJurisdiction>DE</lei:LegalJurisdiction>", "");
+        let invalid = LeiCdfReader::new(xml.as_bytes())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert!(invalid.to_string().contains("LegalJurisdiction"));
+    }
+
+    #[test]
+    fn test_malformed_lei_cdf() {
+        let records = r"<lei:LEIRecords><lei:LEIRecord><lei:LEI>X</lei:LEIRecords></lei:LEIData>";
+        let xml = format!(
+            r#"<lei:LEIData xmlns:lei="http://www.gleif.org/data/schema/leidata/2016">{records}"#
+        );
+        assert!(LeiCdfReader::new(xml.as_bytes()).is_err());
+
+        let xml = format!(
+            r#"<lei:LEIData xmlns:lei="http://www.gleif.org/data/schema/leidata/2016">
+            <lei:LEIHeader><lei:ContentDate>2025-04-04T08:00:00Z</lei:ContentDate></lei:LEIHeader>
+            {records}"#
+        );
+        let mut reader = LeiCdfReader::new(xml.as_bytes()).unwrap();
+        assert!(reader.header().unwrap().record_count.is_none());
+        assert!(matches!(reader.next(), Some(Err(GleifError::XmlError(_)))));
+        assert!(reader.next().is_none());
+    }
+}
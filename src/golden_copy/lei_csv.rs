@@ -0,0 +1,290 @@
+//! Streaming parser for the flattened CSV form of LEI-CDF Golden Copy and delta files.
+
+use crate::{
+    error::{GleifError, ParseErrorKind, Result},
+    golden_copy::{delimited::missing_column, lei_cdf, xml::Element},
+    model::lei_record::LeiRecordAttributes,
+};
+use csv::StringRecord;
+use std::{fs::File, io::Read, path::Path};
+
+/// The highest position a numbered column may select among repeated elements.
+///
+/// The Golden Copy numbers its column groups (other names, other addresses, successor entities,
+/// events, ...) with small positions, but applying a column creates every earlier repetition too, so
+/// headers selecting larger positions are rejected rather than allocated.
+const MAX_REPETITIONS: usize = 100;
+
+/// A step from an element down to one of its children.
+#[derive(Debug)]
+struct Step {
+    name: String,
+    /// The 1-based position among the children of the same name.
+    index: usize,
+}
+
+/// Where the values of a column are placed.
+#[derive(Debug)]
+enum Target {
+    /// The text of the element.
+    Text,
+    /// An attribute of the element.
+    Attribute(String),
+}
+
+/// The location a CSV column stands for in an `LEIRecord` element.
+#[derive(Debug)]
+struct Column {
+    steps: Vec<Step>,
+    target: Target,
+}
+
+impl Column {
+    /// Parses a column header such as `Entity.OtherEntityNames.OtherEntityName.2.xmllang`.
+    ///
+    /// Dot-separated segments name nested elements, numeric segments select one of several
+    /// repeated elements, and a final lowercase segment names an attribute (`xmllang` standing for
+    /// `xml:lang`). Returns `None` for headers that do not follow this pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GleifError::ParseError`] if a numeric segment is 0 or exceeds [`MAX_REPETITIONS`].
+    fn parse(header: &str) -> Result<Option<Self>> {
+        let mut steps: Vec<Step> = Vec::new();
+        let mut target = Target::Text;
+        for segment in header.trim().split('.') {
+            if matches!(target, Target::Attribute(_)) || segment.is_empty() {
+                return Ok(None);
+            }
+            if segment.bytes().all(|byte| byte.is_ascii_digit()) {
+                let Some(step) = steps.last_mut() else {
+                    return Ok(None);
+                };
+                step.index = segment
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|index| (1..=MAX_REPETITIONS).contains(index))
+                    .ok_or_else(|| GleifError::ParseError {
+                        kind: ParseErrorKind::Field,
+                        message: format!(
+                            "column `{header}` selects repetition {segment}, outside 1 to {MAX_REPETITIONS}"
+                        ),
+                    })?;
+            } else if segment.starts_with(|c: char| c.is_ascii_lowercase()) {
+                let name = if segment == "xmllang" {
+                    "lang"
+                } else {
+                    segment
+                };
+                target = Target::Attribute(name.to_string());
+            } else {
+                steps.push(Step {
+                    name: segment.to_string(),
+                    index: 1,
+                });
+            }
+        }
+        Ok((!steps.is_empty()).then_some(Self { steps, target }))
+    }
+
+    /// Returns `true` if the column holds the LEI of the record.
+    fn is_lei(&self) -> bool {
+        matches!(self.target, Target::Text)
+            && matches!(self.steps.as_slice(), [step] if step.name == "LEI")
+    }
+
+    /// Places a value into a record element.
+    fn apply(&self, record: &mut Element, value: &str) {
+        let element = self.steps.iter().fold(record, |element, step| {
+            element.nth_child_mut(&step.name, step.index)
+        });
+        match &self.target {
+            Target::Text => element.set_text(value),
+            Target::Attribute(name) => element.set_attribute(name, value),
+        }
+    }
+}
+
+/// A streaming reader over a Golden Copy LEI CSV file, yielding one [`LeiRecordAttributes`] per
+/// row.
+///
+/// The flattened column headers (`Entity.LegalName`, `Entity.LegalAddress.FirstAddressLine`,
+/// `Entity.OtherEntityNames.OtherEntityName.1.type`, ...) are mapped back onto the nested LEI-CDF
+/// structure, including the numbered column groups of other names, other addresses, successor
+/// entities and legal entity events. Each row is then mapped exactly like an `LEIRecord` of the XML
+/// file read by [`LeiCdfReader`](crate::golden_copy::LeiCdfReader), so both formats yield the same
+/// records. Columns that do not correspond to model attributes are ignored.
+#[derive(Debug)]
+pub struct LeiCsvReader<R> {
+    reader: csv::Reader<R>,
+    columns: Vec<Option<Column>>,
+    row: StringRecord,
+    failed: bool,
+}
+
+impl LeiCsvReader<File> {
+    /// Opens an unzipped Golden Copy LEI CSV file.
+    ///
+    /// # Parameters
+    ///
+    /// - `path` - The path of the CSV file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or its header row has no `LEI` column.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new(File::open(path)?)
+    }
+}
+
+impl<R: Read> LeiCsvReader<R> {
+    /// Creates a reader over Golden Copy LEI CSV content, reading the header row right away.
+    ///
+    /// # Parameters
+    ///
+    /// - `reader` - The source of the CSV document; it is buffered internally.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header row cannot be read, has no `LEI` column, or has a numbered
+    /// column selecting a position beyond the supported number of repetitions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use gleif_rs::golden_copy::LeiCsvReader;
+    ///
+    /// let reader = LeiCsvReader::open("20250101-0000-gleif-goldencopy-lei2-golden-copy.csv")?;
+    /// for record in reader {
+    ///     let record = record?;
+    ///     println!("{}: {}", record.lei, record.entity.legal_address.city);
+    /// }
+    /// ```
+    pub fn new(reader: R) -> Result<Self> {
+        let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(reader);
+        let columns = reader
+            .headers()?
+            .iter()
+            .map(Column::parse)
+            .collect::<Result<Vec<_>>>()?;
+        if !columns.iter().flatten().any(Column::is_lei) {
+            return Err(missing_column("LEI"));
+        }
+        Ok(Self {
+            reader,
+            columns,
+            row: StringRecord::new(),
+            failed: false,
+        })
+    }
+
+    /// Rebuilds the `LEIRecord` element of the current row.
+    fn element(&self) -> Element {
+        let mut record = Element::new("LEIRecord");
+        for (value, column) in self.row.iter().zip(&self.columns) {
+            let value = value.trim();
+            if let Some(column) = column.as_ref().filter(|_| !value.is_empty()) {
+                column.apply(&mut record, value);
+            }
+        }
+        record.prune();
+        record
+    }
+}
+
+impl<R: Read> Iterator for LeiCsvReader<R> {
+    type Item = Result<LeiRecordAttributes>;
+
+    /// Reads the next row.
+    ///
+    /// A row with missing or invalid values yields an error and reading continues with the next row;
+    /// an I/O error yields an error and ends the iteration.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        match self.reader.read_record(&mut self.row) {
+            Ok(true) => Some(lei_cdf::record(&self.element())),
+            Ok(false) => None,
+            Err(error) => {
+                self.failed = error.is_io_error();
+                Some(Err(error.into()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::golden_copy::LeiCdfReader;
+
+    #[test]
+    fn test_parse_column() {
+        let column = Column::parse("Entity.OtherEntityNames.OtherEntityName.2.xmllang")
+            .unwrap()
+            .unwrap();
+        let steps: Vec<_> = column
+            .steps
+            .iter()
+            .map(|step| (step.name.as_str(), step.index))
+            .collect();
+        assert_eq!(
+            steps,
+            [
+                ("Entity", 1),
+                ("OtherEntityNames", 1),
+                ("OtherEntityName", 2)
+            ]
+        );
+        assert!(matches!(column.target, Target::Attribute(ref name) if name == "lang"));
+        assert!(Column::parse("LEI").unwrap().unwrap().is_lei());
+        assert!(!Column::parse("Entity.LegalName").unwrap().unwrap().is_lei());
+        assert!(Column::parse("").unwrap().is_none());
+        assert!(Column::parse("1.Entity").unwrap().is_none());
+        assert!(Column::parse("Entity.type.LegalName").unwrap().is_none());
+
+        // Positions count from 1, and positions beyond the cap would create that many elements per row.
+        assert!(Column::parse("Entity.OtherEntityNames.OtherEntityName.1").is_ok());
+        assert!(Column::parse("Entity.OtherEntityNames.OtherEntityName.100").is_ok());
+        for header in [
+            "Entity.LegalName.0",
+            "Entity.OtherEntityNames.OtherEntityName.0",
+            "Entity.OtherEntityNames.OtherEntityName.101",
+            "Entity.OtherEntityNames.OtherEntityName.99999999999999999999999",
+        ] {
+            assert!(matches!(
+                Column::parse(header),
+                Err(GleifError::ParseError {
+                    kind: ParseErrorKind::Field,
+                    ..
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_read_lei_csv() {
+        let xml: Vec<_> = LeiCdfReader::open("tests/data/golden_copy/lei_cdf_sample.xml")
+            .expect("Failed to open sample file")
+            .collect();
+        let csv: Vec<_> = LeiCsvReader::open("tests/data/golden_copy/lei_csv_sample.csv")
+            .expect("Failed to open sample file")
+            .collect();
+        assert_eq!(csv.len(), 3);
+        assert_eq!(csv[0].as_ref().unwrap(), xml[0].as_ref().unwrap());
+        assert_eq!(csv[1].as_ref().unwrap(), xml[1].as_ref().unwrap());
+        let invalid = csv[2].as_ref().unwrap_err();
+        assert!(invalid.to_string().contains("529900T8BM49AURSDO55"));
+        assert!(invalid.to_string().contains("EntityStatus"));
+
+        assert!(LeiCsvReader::new("Entity.LegalName\nAcme\n".as_bytes()).is_err());
+        assert!(
+            LeiCsvReader::new(
+                "LEI,Entity.LegalEntityEvents.LegalEntityEvent.1000000000\n".as_bytes()
+            )
+            .is_err()
+        );
+        assert!(LeiCsvReader::new("LEI,Entity.LegalName.0\nX,Acme\n".as_bytes()).is_err());
+    }
+}
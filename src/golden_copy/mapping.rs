@@ -0,0 +1,449 @@
+//! Streaming parsers for the LEI-to-ISIN, BIC, MIC and `OpenCorporates` mapping files, and an
+//! in-memory index over their pairs.
+
+use crate::{
+    error::Result,
+    golden_copy::{
+        delimited::{missing, missing_column},
+        xml::in_record,
+    },
+    model::isin::IsinAttributes,
+    resolve::Identifier,
+};
+use csv::StringRecord;
+use std::{collections::HashMap, fs::File, io::Read, marker::PhantomData, path::Path};
+
+/// A pair of an LEI and an identifier, as listed in a GLEIF mapping file.
+pub trait Mapping: Sized {
+    /// The accepted headers of the identifier column, upper-cased and without separators.
+    const COLUMNS: &'static [&'static str];
+
+    /// Creates a pair from the values of a row.
+    fn from_pair(lei: String, identifier: String) -> Self;
+
+    /// Returns the LEI of the pair.
+    fn lei(&self) -> &str;
+
+    /// Returns the identifier of the pair.
+    fn identifier(&self) -> Identifier;
+}
+
+impl Mapping for IsinAttributes {
+    const COLUMNS: &'static [&'static str] = &["ISIN"];
+
+    fn from_pair(lei: String, isin: String) -> Self {
+        Self { lei, isin }
+    }
+
+    fn lei(&self) -> &str {
+        &self.lei
+    }
+
+    fn identifier(&self) -> Identifier {
+        Identifier::Isin(self.isin.clone())
+    }
+}
+
+/// A pair from the LEI-to-BIC mapping file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BicMapping {
+    /// The LEI.
+    pub lei: String,
+    /// The Business Identifier Code (ISO 9362) assigned to the entity.
+    pub bic: String,
+}
+
+impl Mapping for BicMapping {
+    const COLUMNS: &'static [&'static str] = &["BIC"];
+
+    fn from_pair(lei: String, bic: String) -> Self {
+        Self { lei, bic }
+    }
+
+    fn lei(&self) -> &str {
+        &self.lei
+    }
+
+    fn identifier(&self) -> Identifier {
+        Identifier::Bic(self.bic.clone())
+    }
+}
+
+/// A pair from the LEI-to-MIC mapping file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MicMapping {
+    /// The LEI.
+    pub lei: String,
+    /// The Market Identifier Code (ISO 10383) of a market operated by the entity.
+    pub mic: String,
+}
+
+impl Mapping for MicMapping {
+    const COLUMNS: &'static [&'static str] = &["MIC"];
+
+    fn from_pair(lei: String, mic: String) -> Self {
+        Self { lei, mic }
+    }
+
+    fn lei(&self) -> &str {
+        &self.lei
+    }
+
+    fn identifier(&self) -> Identifier {
+        Identifier::Mic(self.mic.clone())
+    }
+}
+
+/// A pair from the LEI-to-`OpenCorporates` mapping file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OpenCorporatesMapping {
+    /// The LEI.
+    pub lei: String,
+    /// The `OpenCorporates` ID of the entity, e.g. `gb/01234567`.
+    pub ocid: String,
+}
+
+impl Mapping for OpenCorporatesMapping {
+    const COLUMNS: &'static [&'static str] = &["OPENCORPORATESID", "OCID"];
+
+    fn from_pair(lei: String, ocid: String) -> Self {
+        Self { lei, ocid }
+    }
+
+    fn lei(&self) -> &str {
+        &self.lei
+    }
+
+    fn identifier(&self) -> Identifier {
+        Identifier::Ocid(self.ocid.clone())
+    }
+}
+
+/// A streaming reader over a GLEIF mapping file, yielding one [`Mapping`] per row.
+///
+/// The `LEI` and identifier columns are located by name, so the column order of the published files
+/// does not matter. Use the aliases [`IsinMappingReader`], [`BicMappingReader`],
+/// [`MicMappingReader`] and [`OpenCorporatesMappingReader`] for the individual files.
+#[derive(Debug)]
+pub struct MappingReader<T, R = File> {
+    reader: csv::Reader<R>,
+    lei: usize,
+    identifier: usize,
+    row: StringRecord,
+    failed: bool,
+    mapping: PhantomData<T>,
+}
+
+/// A reader over the LEI-to-ISIN mapping file.
+pub type IsinMappingReader<R = File> = MappingReader<IsinAttributes, R>;
+
+/// A reader over the LEI-to-BIC mapping file.
+pub type BicMappingReader<R = File> = MappingReader<BicMapping, R>;
+
+/// A reader over the LEI-to-MIC mapping file.
+pub type MicMappingReader<R = File> = MappingReader<MicMapping, R>;
+
+/// A reader over the LEI-to-`OpenCorporates` mapping file.
+pub type OpenCorporatesMappingReader<R = File> = MappingReader<OpenCorporatesMapping, R>;
+
+impl<T: Mapping> MappingReader<T, File> {
+    /// Opens an unzipped mapping file.
+    ///
+    /// # Parameters
+    ///
+    /// - `path` - The path of the CSV file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or its header row lacks a mandatory column.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new(File::open(path)?)
+    }
+}
+
+impl<T: Mapping, R: Read> MappingReader<T, R> {
+    /// Creates a reader over mapping CSV content, reading the header row right away.
+    ///
+    /// # Parameters
+    ///
+    /// - `reader` - The source of the CSV document; it is buffered internally.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header row cannot be read or lacks the `LEI` or identifier column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use gleif_rs::golden_copy::IsinMappingReader;
+    ///
+    /// for pair in IsinMappingReader::open("isin-lei-20250101T070301.csv")? {
+    ///     let pair = pair?;
+    ///     println!("{} -> {}", pair.isin, pair.lei);
+    /// }
+    /// ```
+    pub fn new(reader: R) -> Result<Self> {
+        let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(reader);
+        let headers: Vec<String> = reader
+            .headers()?
+            .iter()
+            .map(|header| {
+                header
+                    .chars()
+                    .filter(char::is_ascii_alphanumeric)
+                    .collect::<String>()
+                    .to_ascii_uppercase()
+            })
+            .collect();
+        let position = |names: &[&str]| {
+            headers
+                .iter()
+                .position(|header| names.contains(&header.as_str()))
+                .ok_or_else(|| missing_column(names[0]))
+        };
+        Ok(Self {
+            lei: position(&["LEI"])?,
+            identifier: position(T::COLUMNS)?,
+            reader,
+            row: StringRecord::new(),
+            failed: false,
+            mapping: PhantomData,
+        })
+    }
+
+    /// Maps the current row.
+    fn mapping(&self) -> Result<T> {
+        let value = |index: usize| {
+            self.row
+                .get(index)
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+        let lei = value(self.lei).ok_or_else(|| missing("LEI", &self.row))?;
+        let identifier = value(self.identifier)
+            .ok_or_else(|| in_record(missing(T::COLUMNS[0], &self.row), &lei))?;
+        Ok(T::from_pair(lei, identifier))
+    }
+}
+
+impl<T: Mapping, R: Read> Iterator for MappingReader<T, R> {
+    type Item = Result<T>;
+
+    /// Reads the next row.
+    ///
+    /// A row with missing values yields an error and reading continues with the next row; an I/O
+    /// error yields an error and ends the iteration.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        match self.reader.read_record(&mut self.row) {
+            Ok(true) => Some(self.mapping()),
+            Ok(false) => None,
+            Err(error) => {
+                self.failed = error.is_io_error();
+                Some(Err(error.into()))
+            }
+        }
+    }
+}
+
+/// An in-memory index over LEI-to-identifier pairs, answering lookups in both directions.
+///
+/// Identifiers and LEIs are normalized on insertion and lookup (trimmed, and upper-cased for LEIs
+/// and the ISO identifiers), and duplicate pairs are ignored. The pairs of several mapping files
+/// can be combined in one index, since identifiers keep their kind.
+///
+/// # Examples
+///
+/// ```rust, ignore
+/// use gleif_rs::{
+///     golden_copy::{BicMappingReader, IdentifierIndex, IsinMappingReader},
+///     resolve::Identifier,
+/// };
+///
+/// let mut index = IdentifierIndex::new();
+/// index.load(IsinMappingReader::open("isin-lei-20250101T070301.csv")?)?;
+/// index.load(BicMappingReader::open("lei-bic-20250101T000000.csv")?)?;
+/// let issuers = index.leis(&Identifier::Isin("DE000CBK1001".to_string()));
+/// let identifiers = index.identifiers("851WYGNLUQLFZBSYGB56");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IdentifierIndex {
+    leis: HashMap<Identifier, Vec<String>>,
+    identifiers: HashMap<String, Vec<Identifier>>,
+}
+
+impl IdentifierIndex {
+    /// Creates an empty index.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a pair, returning `false` if it was already indexed.
+    pub fn insert(&mut self, lei: &str, identifier: &Identifier) -> bool {
+        let lei = lei.trim().to_ascii_uppercase();
+        let identifier = identifier.normalized();
+        let identifiers = self.identifiers.entry(lei.clone()).or_default();
+        if identifiers.contains(&identifier) {
+            return false;
+        }
+        identifiers.push(identifier.clone());
+        self.leis.entry(identifier).or_default().push(lei);
+        true
+    }
+
+    /// Adds the pairs of a mapping file, stopping at the first error.
+    ///
+    /// # Parameters
+    ///
+    /// - `mappings` - The pairs, e.g. a [`MappingReader`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of `mappings`; the pairs before it remain indexed.
+    pub fn load<T, I>(&mut self, mappings: I) -> Result<usize>
+    where
+        T: Mapping,
+        I: IntoIterator<Item = Result<T>>,
+    {
+        let mut added = 0;
+        for mapping in mappings {
+            let mapping = mapping?;
+            added += usize::from(self.insert(mapping.lei(), &mapping.identifier()));
+        }
+        Ok(added)
+    }
+
+    /// Returns the LEIs an identifier is mapped to.
+    #[must_use]
+    pub fn leis(&self, identifier: &Identifier) -> &[String] {
+        self.leis
+            .get(&identifier.normalized())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the identifiers mapped to an LEI, of all kinds, in insertion order.
+    #[must_use]
+    pub fn identifiers(&self, lei: &str) -> &[Identifier] {
+        self.identifiers
+            .get(&lei.trim().to_ascii_uppercase())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the number of indexed pairs.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.identifiers.values().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if no pairs are indexed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.identifiers.is_empty()
+    }
+}
+
+impl<T: Mapping> Extend<T> for IdentifierIndex {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, mappings: I) {
+        for mapping in mappings {
+            self.insert(mapping.lei(), &mapping.identifier());
+        }
+    }
+}
+
+impl<T: Mapping> FromIterator<T> for IdentifierIndex {
+    fn from_iter<I: IntoIterator<Item = T>>(mappings: I) -> Self {
+        let mut index = Self::new();
+        index.extend(mappings);
+        index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMMERZBANK: &str = "851WYGNLUQLFZBSYGB56";
+
+    #[test]
+    fn test_read_mappings() {
+        let isins: Vec<_> =
+            IsinMappingReader::open("tests/data/golden_copy/isin_mapping_sample.csv")
+                .expect("Failed to open sample file")
+                .collect();
+        assert_eq!(isins.len(), 4);
+        assert_eq!(
+            isins[0].as_ref().unwrap(),
+            &IsinAttributes {
+                lei: COMMERZBANK.to_string(),
+                isin: "DE000A2SH128".to_string(),
+            }
+        );
+        let invalid = isins[3].as_ref().unwrap_err().to_string();
+        assert!(invalid.contains("529900GRZ2BQY5ZM9N49"));
+        assert!(invalid.contains("line 5"));
+
+        let mics: Vec<_> = MicMappingReader::open("tests/data/golden_copy/mic_mapping_sample.csv")
+            .expect("Failed to open sample file")
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(
+            mics,
+            [MicMapping {
+                lei: COMMERZBANK.to_string(),
+                mic: "CBKA".to_string(),
+            }]
+        );
+
+        let ocids: Vec<_> =
+            OpenCorporatesMappingReader::open("tests/data/golden_copy/oc_mapping_sample.csv")
+                .expect("Failed to open sample file")
+                .collect::<Result<_>>()
+                .unwrap();
+        assert_eq!(ocids[1].ocid, "us_de/4348344");
+
+        assert!(BicMappingReader::new("LEI,ISIN\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_identifier_index() {
+        let mut index = IdentifierIndex::new();
+        let error = index.load(
+            IsinMappingReader::open("tests/data/golden_copy/isin_mapping_sample.csv").unwrap(),
+        );
+        assert!(error.is_err());
+        assert_eq!(index.len(), 3);
+        let added = index
+            .load(BicMappingReader::open("tests/data/golden_copy/bic_mapping_sample.csv").unwrap())
+            .unwrap();
+        assert_eq!(added, 3);
+
+        assert_eq!(
+            index.leis(&Identifier::Bic(" cobadeffxxx ".to_string())),
+            [COMMERZBANK]
+        );
+        assert!(
+            index
+                .leis(&Identifier::Isin("COBADEFFXXX".to_string()))
+                .is_empty()
+        );
+        let identifiers = index.identifiers(&COMMERZBANK.to_ascii_lowercase());
+        assert_eq!(identifiers.len(), 5);
+        assert_eq!(identifiers[3], Identifier::Bic("COBADEFFXXX".to_string()));
+        assert!(!index.insert(COMMERZBANK, &Identifier::Isin("de000a2sh128".to_string())));
+
+        let index: IdentifierIndex = [MicMapping {
+            lei: COMMERZBANK.to_string(),
+            mic: "CBKA".to_string(),
+        }]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            index.leis(&Identifier::Mic("cbka".to_string())),
+            [COMMERZBANK]
+        );
+    }
+}
@@ -0,0 +1,325 @@
+//! Streaming parsers for the reporting exceptions Golden Copy and delta files, in XML and CSV.
+
+use crate::{
+    error::Result,
+    golden_copy::{
+        delimited::{missing, missing_column},
+        header::FileHeader,
+        xml::{Element, RecordStream, in_record},
+    },
+    model::reporting_exception::ReportingExceptionAttributes,
+};
+use csv::StringRecord;
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, Read},
+    path::Path,
+};
+
+/// A streaming reader over a reporting exceptions XML file, yielding one
+/// [`ReportingExceptionAttributes`] per `Exception`.
+///
+/// Only one exception is held in memory at a time. The records carry the same values as the
+/// responses of the API's reporting exception endpoints. Where a record lists several
+/// `ExceptionReason`s or `ExceptionReference`s, the first of each is used, and `valid_from` and
+/// `valid_to` are `None`, as in the API responses.
+#[derive(Debug)]
+pub struct ReportingExceptionReader<R> {
+    stream: RecordStream<R>,
+}
+
+impl ReportingExceptionReader<BufReader<File>> {
+    /// Opens an unzipped reporting exceptions XML file.
+    ///
+    /// # Parameters
+    ///
+    /// - `path` - The path of the XML file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or its header cannot be read.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: BufRead> ReportingExceptionReader<R> {
+    /// Creates a reader over reporting exceptions XML content, reading the `Header` right away.
+    ///
+    /// # Parameters
+    ///
+    /// - `reader` - The buffered source of the XML document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document is not well-formed up to the header, or the header is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use gleif_rs::golden_copy::ReportingExceptionReader;
+    ///
+    /// let reader = ReportingExceptionReader::open("20250101-0000-gleif-goldencopy-repex-golden-copy.xml")?;
+    /// let natural_persons = reader
+    ///     .filter_map(Result::ok)
+    ///     .filter(|exception| exception.reason == "NATURAL_PERSONS")
+    ///     .count();
+    /// ```
+    pub fn new(reader: R) -> Result<Self> {
+        Ok(Self {
+            stream: RecordStream::new(reader, "Header", "Exception")?,
+        })
+    }
+
+    /// Returns the file header, if the file has one.
+    #[must_use]
+    pub fn header(&self) -> Option<&FileHeader> {
+        self.stream.header()
+    }
+}
+
+impl<R: BufRead> Iterator for ReportingExceptionReader<R> {
+    type Item = Result<ReportingExceptionAttributes>;
+
+    /// Reads the next exception.
+    ///
+    /// An exception with missing content yields an error and reading continues with the next one; a
+    /// malformed document yields an error and ends the iteration.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.stream
+            .next_record()
+            .map(|element| element.and_then(|element| exception(&element)))
+    }
+}
+
+/// Maps an `Exception` element.
+fn exception(element: &Element) -> Result<ReportingExceptionAttributes> {
+    let lei = element.required_text("LEI")?;
+    let attributes = (|| {
+        Ok(ReportingExceptionAttributes {
+            valid_from: None,
+            valid_to: None,
+            category: element.required_text("ExceptionCategory")?,
+            reason: element.required_text("ExceptionReason")?,
+            reference: element.child_text("ExceptionReference"),
+            lei: lei.clone(),
+        })
+    })();
+    attributes.map_err(|error| in_record(error, &lei))
+}
+
+/// The positions of the columns of a reporting exceptions CSV file.
+#[derive(Debug)]
+struct Columns {
+    lei: usize,
+    category: usize,
+    reasons: Vec<usize>,
+    references: Vec<usize>,
+}
+
+impl Columns {
+    /// Locates the columns in the header row.
+    ///
+    /// Repeated columns may be numbered (`ExceptionReason.1`, `ExceptionReason.2`, ...).
+    fn from_headers(headers: &StringRecord) -> Result<Self> {
+        let positions = |name: &str| -> Vec<usize> {
+            headers
+                .iter()
+                .enumerate()
+                .filter(|(_, header)| {
+                    header
+                        .trim()
+                        .trim_end_matches(|c: char| c.is_ascii_digit() || c == '.')
+                        == name
+                })
+                .map(|(index, _)| index)
+                .collect()
+        };
+        let required = |name: &str| -> Result<Vec<usize>> {
+            let found = positions(name);
+            if found.is_empty() {
+                return Err(missing_column(name));
+            }
+            Ok(found)
+        };
+        Ok(Self {
+            lei: required("LEI")?[0],
+            category: required("ExceptionCategory")?[0],
+            reasons: required("ExceptionReason")?,
+            references: positions("ExceptionReference"),
+        })
+    }
+
+    /// Maps a data row.
+    fn exception(&self, row: &StringRecord) -> Result<ReportingExceptionAttributes> {
+        let value = |index: usize| {
+            row.get(index)
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+        let lei = value(self.lei).ok_or_else(|| missing("LEI", row))?;
+        let attributes = (|| {
+            Ok(ReportingExceptionAttributes {
+                valid_from: None,
+                valid_to: None,
+                category: value(self.category).ok_or_else(|| missing("ExceptionCategory", row))?,
+                reason: self
+                    .reasons
+                    .iter()
+                    .find_map(|&index| value(index))
+                    .ok_or_else(|| missing("ExceptionReason", row))?,
+                reference: self.references.iter().find_map(|&index| value(index)),
+                lei: lei.clone(),
+            })
+        })();
+        attributes.map_err(|error| in_record(error, &lei))
+    }
+}
+
+/// A streaming reader over a reporting exceptions CSV file, yielding one
+/// [`ReportingExceptionAttributes`] per row.
+///
+/// Columns are located by name, so additional or reordered columns are accepted. As with
+/// [`ReportingExceptionReader`], the first non-empty `ExceptionReason` and `ExceptionReference` of
+/// each row are used.
+#[derive(Debug)]
+pub struct ReportingExceptionCsvReader<R> {
+    reader: csv::Reader<R>,
+    columns: Columns,
+    row: StringRecord,
+    failed: bool,
+}
+
+impl ReportingExceptionCsvReader<File> {
+    /// Opens an unzipped reporting exceptions CSV file.
+    ///
+    /// # Parameters
+    ///
+    /// - `path` - The path of the CSV file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or its header row lacks a mandatory column.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new(File::open(path)?)
+    }
+}
+
+impl<R: Read> ReportingExceptionCsvReader<R> {
+    /// Creates a reader over reporting exceptions CSV content, reading the header row right away.
+    ///
+    /// # Parameters
+    ///
+    /// - `reader` - The source of the CSV document; it is buffered internally.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header row cannot be read or lacks the `LEI`, `ExceptionCategory` or
+    /// `ExceptionReason` column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use gleif_rs::golden_copy::ReportingExceptionCsvReader;
+    ///
+    /// let reader = ReportingExceptionCsvReader::open("20250101-0000-gleif-goldencopy-repex-golden-copy.csv")?;
+    /// for exception in reader {
+    ///     let exception = exception?;
+    ///     println!("{}: {} ({})", exception.lei, exception.category, exception.reason);
+    /// }
+    /// ```
+    pub fn new(reader: R) -> Result<Self> {
+        let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(reader);
+        let columns = Columns::from_headers(reader.headers()?)?;
+        Ok(Self {
+            reader,
+            columns,
+            row: StringRecord::new(),
+            failed: false,
+        })
+    }
+}
+
+impl<R: Read> Iterator for ReportingExceptionCsvReader<R> {
+    type Item = Result<ReportingExceptionAttributes>;
+
+    /// Reads the next row.
+    ///
+    /// A row with missing values yields an error and reading continues with the next row; an I/O
+    /// error yields an error and ends the iteration.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        match self.reader.read_record(&mut self.row) {
+            Ok(true) => Some(self.columns.exception(&self.row)),
+            Ok(false) => None,
+            Err(error) => {
+                self.failed = error.is_io_error();
+                Some(Err(error.into()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{model::reporting_exception::ReportingException, test_utils::read};
+
+    /// Reads the API responses for the exceptions of the sample files.
+    fn expected() -> Vec<ReportingExceptionAttributes> {
+        ["direct", "ultimate"]
+            .iter()
+            .map(|level| {
+                read::<ReportingException>(&format!(
+                    "tests/data/reporting_exceptions/{level}-parent-reporting-exception_335800CCCPHKUOXPC332.json"
+                ))
+                .attributes
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_read_repex_xml() {
+        let reader = ReportingExceptionReader::open("tests/data/golden_copy/repex_sample.xml")
+            .expect("Failed to open sample file");
+        assert_eq!(reader.header().unwrap().record_count, Some(4));
+
+        let exceptions: Vec<_> = reader.collect();
+        assert_eq!(exceptions.len(), 4);
+        let expected = expected();
+        assert_eq!(exceptions[0].as_ref().unwrap(), &expected[0]);
+        assert_eq!(exceptions[1].as_ref().unwrap(), &expected[1]);
+
+        let referenced = exceptions[2].as_ref().unwrap();
+        assert_eq!(referenced.reason, "NON_CONSOLIDATING");
+        assert_eq!(referenced.reference.as_deref(), Some("Annual report 2024"));
+
+        let invalid = exceptions[3].as_ref().unwrap_err();
+        assert!(invalid.to_string().contains("529900T8BM49AURSDO55"));
+    }
+
+    #[test]
+    fn test_read_repex_csv() {
+        let reader = ReportingExceptionCsvReader::open("tests/data/golden_copy/repex_sample.csv")
+            .expect("Failed to open sample file");
+        let exceptions: Vec<_> = reader.collect();
+        assert_eq!(exceptions.len(), 4);
+        let expected = expected();
+        assert_eq!(exceptions[0].as_ref().unwrap(), &expected[0]);
+        assert_eq!(exceptions[1].as_ref().unwrap(), &expected[1]);
+
+        let referenced = exceptions[2].as_ref().unwrap();
+        assert_eq!(referenced.reason, "NON_CONSOLIDATING");
+        assert_eq!(referenced.reference.as_deref(), Some("Annual report, 2024"));
+
+        let invalid = exceptions[3].as_ref().unwrap_err();
+        assert!(invalid.to_string().contains("line 5"));
+        assert!(invalid.to_string().contains("ExceptionReason"));
+
+        let missing = ReportingExceptionCsvReader::new("LEI,Reason\n".as_bytes());
+        assert!(missing.is_err());
+    }
+}
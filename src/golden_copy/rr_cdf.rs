@@ -0,0 +1,246 @@
+//! Streaming parser for RR-CDF 2.1 relationship Golden Copy and delta files.
+
+use crate::{
+    error::Result,
+    golden_copy::{
+        header::FileHeader,
+        xml::{Element, RecordStream, in_record, invalid},
+    },
+    model::relationship_record::{
+        RelationshipDetails, RelationshipExtension, RelationshipNode, RelationshipPeriod,
+        RelationshipQualifier, RelationshipQuantifier, RelationshipRecordAttributes,
+        RelationshipRegistration,
+    },
+};
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+};
+
+/// A streaming reader over an RR-CDF 2.1 file, yielding one [`RelationshipRecordAttributes`] per
+/// `RelationshipRecord`.
+///
+/// Only one record is held in memory at a time. Records are mapped onto the same model as the API
+/// responses, with dates normalized to UTC. The API-only validity attributes are filled in from the
+/// file: `valid_from` is the content date of the file header (or, for files without a header, the
+/// last update of the record), and `valid_to` and `extension.deleted_at` are always `None`.
+#[derive(Debug)]
+pub struct RrCdfReader<R> {
+    stream: RecordStream<R>,
+}
+
+impl RrCdfReader<BufReader<File>> {
+    /// Opens an unzipped RR-CDF file.
+    ///
+    /// # Parameters
+    ///
+    /// - `path` - The path of the XML file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or its header cannot be read.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: BufRead> RrCdfReader<R> {
+    /// Creates a reader over RR-CDF content, reading the `Header` right away.
+    ///
+    /// # Parameters
+    ///
+    /// - `reader` - The buffered source of the XML document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document is not well-formed up to the header, or the header is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use gleif_rs::{golden_copy::RrCdfReader, model::enums::RelationshipType};
+    ///
+    /// let reader = RrCdfReader::open("20250101-0000-gleif-goldencopy-rr-golden-copy.xml")?;
+    /// for record in reader {
+    ///     let relationship = record?.relationship;
+    ///     if relationship.r#type == RelationshipType::IsDirectlyConsolidatedBy {
+    ///         println!("{} -> {}", relationship.start_node.id, relationship.end_node.id);
+    ///     }
+    /// }
+    /// ```
+    pub fn new(reader: R) -> Result<Self> {
+        Ok(Self {
+            stream: RecordStream::new(reader, "Header", "RelationshipRecord")?,
+        })
+    }
+
+    /// Returns the file header, if the file has one.
+    #[must_use]
+    pub fn header(&self) -> Option<&FileHeader> {
+        self.stream.header()
+    }
+}
+
+impl<R: BufRead> Iterator for RrCdfReader<R> {
+    type Item = Result<RelationshipRecordAttributes>;
+
+    /// Reads the next record.
+    ///
+    /// A record with missing or invalid content yields an error and reading continues with the next
+    /// record; a malformed document yields an error and ends the iteration.
+    fn next(&mut self) -> Option<Self::Item> {
+        let element = self.stream.next_record()?;
+        let header = self.stream.header();
+        Some(element.and_then(|element| record(&element, header)))
+    }
+}
+
+/// Maps a `RelationshipRecord` element.
+fn record(element: &Element, header: Option<&FileHeader>) -> Result<RelationshipRecordAttributes> {
+    let relationship = element.required("Relationship")?;
+    let start_node = node(relationship.required("StartNode")?)?;
+    let end_node = node(relationship.required("EndNode")?)?;
+    let id = format!("{}|{}", start_node.id, end_node.id);
+    let attributes = (|| {
+        let registration = registration(element.required("Registration")?)?;
+        Ok(RelationshipRecordAttributes {
+            valid_from: header.map_or(
+                registration
+                    .last_update_date
+                    .unwrap_or(registration.initial_registration_date),
+                |header| header.content_date,
+            ),
+            valid_to: None,
+            relationship: RelationshipDetails {
+                r#type: relationship.code("RelationshipType")?,
+                status: relationship.code("RelationshipStatus")?,
+                periods: relationship
+                    .list("RelationshipPeriods", "RelationshipPeriod")
+                    .map(period)
+                    .collect::<Result<_>>()?,
+                qualifiers: relationship
+                    .list("RelationshipQualifiers", "RelationshipQualifier")
+                    .map(qualifier)
+                    .collect::<Result<_>>()?,
+                quantifiers: relationship
+                    .list("RelationshipQuantifiers", "RelationshipQuantifier")
+                    .map(quantifier)
+                    .collect::<Result<_>>()?,
+                start_node: start_node.clone(),
+                end_node: end_node.clone(),
+            },
+            registration,
+            extension: RelationshipExtension { deleted_at: None },
+        })
+    })();
+    attributes.map_err(|error| in_record(error, &id))
+}
+
+/// Maps a `StartNode` or `EndNode` element.
+fn node(element: &Element) -> Result<RelationshipNode> {
+    Ok(RelationshipNode {
+        id: element.required_text("NodeID")?,
+        r#type: element.required_text("NodeIDType")?,
+    })
+}
+
+/// Maps a `RelationshipPeriod` element.
+fn period(element: &Element) -> Result<RelationshipPeriod> {
+    Ok(RelationshipPeriod {
+        start_date: element.date("StartDate")?,
+        end_date: element.optional_date("EndDate")?,
+        r#type: element.code("PeriodType")?,
+    })
+}
+
+/// Maps a `RelationshipQualifier` element.
+fn qualifier(element: &Element) -> Result<RelationshipQualifier> {
+    Ok(RelationshipQualifier {
+        dimension: element.required_text("QualifierDimension")?,
+        category: element.required_text("QualifierCategory")?,
+    })
+}
+
+/// Maps a `RelationshipQuantifier` element.
+fn quantifier(element: &Element) -> Result<RelationshipQuantifier> {
+    let amount = element.required_text("QuantifierAmount")?;
+    Ok(RelationshipQuantifier {
+        measurement_method: element.required_text("MeasurementMethod")?,
+        quantity: amount
+            .parse()
+            .map_err(|_| invalid(&format!("invalid quantifier amount `{amount}`")))?,
+        units: element.child_text("QuantifierUnits"),
+    })
+}
+
+/// Maps a `Registration` element.
+fn registration(element: &Element) -> Result<RelationshipRegistration> {
+    Ok(RelationshipRegistration {
+        initial_registration_date: element.date("InitialRegistrationDate")?,
+        last_update_date: element.optional_date("LastUpdateDate")?,
+        status: element.code("RegistrationStatus")?,
+        next_renewal_date: element.date("NextRenewalDate")?,
+        managing_lou: element.required_text("ManagingLOU")?,
+        corroboration_level: element.code("ValidationSources")?,
+        corroboration_documents: element.code("ValidationDocuments")?,
+        corroboration_reference: element.child_text("ValidationReference"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{
+            enums::{RelationshipPeriodType, RelationshipType},
+            relationship_record::RelationshipRecord,
+        },
+        test_utils::read,
+    };
+
+    const SAMPLE: &str = "tests/data/golden_copy/rr_cdf_sample.xml";
+
+    #[test]
+    fn test_read_rr_cdf() {
+        let reader = RrCdfReader::open(SAMPLE).expect("Failed to open sample file");
+        let header = reader.header().expect("Missing header").clone();
+        assert_eq!(header.record_count, Some(3));
+        assert!(header.is_delta());
+
+        let records: Vec<_> = reader.collect();
+        assert_eq!(records.len(), 3);
+
+        // The API record of the same relationship, with the validity taken from the file.
+        let mut expected = read::<RelationshipRecord>(
+            "tests/data/relationships/direct-parent-relationship_5493001KJTIIGC8Y1R12.json",
+        )
+        .attributes;
+        expected.valid_from = header.content_date;
+        expected.valid_to = None;
+        assert_eq!(records[0].as_ref().unwrap(), &expected);
+
+        let fund = records[1].as_ref().unwrap();
+        assert_eq!(fund.relationship.r#type, RelationshipType::IsFundManagedBy);
+        assert_eq!(fund.relationship.periods.len(), 1);
+        assert_eq!(
+            fund.relationship.periods[0].r#type,
+            RelationshipPeriodType::RelationshipPeriod
+        );
+        assert_eq!(fund.relationship.qualifiers[0].category, "IFRS");
+        let quantifier = &fund.relationship.quantifiers[0];
+        assert!((quantifier.quantity - 62.5).abs() < f64::EPSILON);
+        assert_eq!(quantifier.units.as_deref(), Some("PERCENTAGE"));
+        assert_eq!(
+            fund.registration.corroboration_reference.as_deref(),
+            Some("https://example.com/filing")
+        );
+
+        let invalid = records[2].as_ref().unwrap_err();
+        assert!(
+            invalid
+                .to_string()
+                .contains("5493000IBP32UQZ0KL24|851WYGNLUQLFZBSYGB56")
+        );
+    }
+}
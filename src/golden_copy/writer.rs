@@ -0,0 +1,832 @@
+//! Streaming writers for LEI-CDF 3.1 and RR-CDF 2.1 XML documents.
+
+use crate::{
+    error::Result,
+    golden_copy::{header::FileHeader, xml::invalid},
+    model::{
+        enums::GroupType,
+        lei_record::{
+            Entity, Event, EventGroup, LeiRecordAttributes, OtherAddress, Registration,
+            ValidationAuthority,
+        },
+        relationship_record::{
+            RelationshipNode, RelationshipRecordAttributes, RelationshipRegistration,
+        },
+    },
+};
+use chrono::{DateTime, SecondsFormat, Utc};
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event as XmlEvent};
+use serde::Serialize;
+use std::{
+    fmt,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+/// The namespace and framing elements of a Golden Copy document.
+struct Layout {
+    prefix: &'static str,
+    namespace: &'static str,
+    root: &'static str,
+    header: &'static str,
+    records: &'static str,
+}
+
+/// The layout of LEI-CDF 3.1 documents.
+const LEI_CDF: Layout = Layout {
+    prefix: "lei",
+    namespace: "http://www.gleif.org/data/schema/leidata/2016",
+    root: "LEIData",
+    header: "LEIHeader",
+    records: "LEIRecords",
+};
+
+/// The layout of RR-CDF 2.1 documents.
+const RR_CDF: Layout = Layout {
+    prefix: "rr",
+    namespace: "http://www.gleif.org/data/schema/rr/2016",
+    root: "RelationshipData",
+    header: "Header",
+    records: "RelationshipRecords",
+};
+
+/// Writes the elements of a Golden Copy document, qualifying element names with the prefix of its
+/// namespace.
+struct XmlWriter<W: Write> {
+    writer: quick_xml::Writer<W>,
+    layout: &'static Layout,
+}
+
+impl<W: Write> XmlWriter<W> {
+    /// Writes the XML declaration, the root element and the header, and opens the records element.
+    fn new(writer: W, layout: &'static Layout, header: &FileHeader) -> Result<Self> {
+        let mut xml = Self {
+            writer: quick_xml::Writer::new_with_indent(writer, b' ', 2),
+            layout,
+        };
+        xml.writer
+            .write_event(XmlEvent::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+        let xmlns = format!("xmlns:{}", layout.prefix);
+        xml.start(layout.root, &[(&xmlns, layout.namespace)])?;
+        xml.start(layout.header, &[])?;
+        xml.date("ContentDate", &header.content_date)?;
+        xml.optional_text("Originator", header.originator.as_deref())?;
+        xml.optional_text("FileContent", header.file_content.as_deref())?;
+        if let Some(delta_start) = &header.delta_start {
+            xml.date("DeltaStart", delta_start)?;
+        }
+        if let Some(record_count) = header.record_count {
+            xml.text("RecordCount", &record_count.to_string(), &[])?;
+        }
+        xml.end(layout.header)?;
+        xml.start(layout.records, &[])?;
+        Ok(xml)
+    }
+
+    /// Closes the records and root elements and flushes the destination.
+    fn finish(mut self) -> Result<W> {
+        self.end(self.layout.records)?;
+        self.end(self.layout.root)?;
+        let mut writer = self.writer.into_inner();
+        writer.write_all(b"\n")?;
+        writer.flush()?;
+        Ok(writer)
+    }
+
+    /// Returns the qualified name of an element.
+    fn name(&self, name: &str) -> String {
+        format!("{}:{name}", self.layout.prefix)
+    }
+
+    /// Opens an element.
+    fn start(&mut self, name: &str, attributes: &[(&str, &str)]) -> Result<()> {
+        let start = BytesStart::new(self.name(name)).with_attributes(attributes.iter().copied());
+        self.writer.write_event(XmlEvent::Start(start))?;
+        Ok(())
+    }
+
+    /// Closes an element.
+    fn end(&mut self, name: &str) -> Result<()> {
+        self.writer
+            .write_event(XmlEvent::End(BytesEnd::new(self.name(name))))?;
+        Ok(())
+    }
+
+    /// Writes an element holding text.
+    fn text(&mut self, name: &str, text: &str, attributes: &[(&str, &str)]) -> Result<()> {
+        self.writer
+            .create_element(self.name(name))
+            .with_attributes(attributes.iter().copied())
+            .write_text_content(BytesText::new(text))?;
+        Ok(())
+    }
+
+    /// Writes an element holding text, if the text is set.
+    fn optional_text(&mut self, name: &str, text: Option<&str>) -> Result<()> {
+        match text {
+            Some(text) => self.text(name, text, &[]),
+            None => Ok(()),
+        }
+    }
+
+    /// Writes an element holding an `xs:dateTime` in UTC.
+    fn date(&mut self, name: &str, date: &DateTime<Utc>) -> Result<()> {
+        self.text(name, &format_date(date), &[])
+    }
+
+    /// Writes an element holding a code list value.
+    fn code<T: Serialize>(&mut self, name: &str, value: &T) -> Result<()> {
+        self.text(name, &format_code(value)?, &[])
+    }
+}
+
+/// Formats a date as an `xs:dateTime` in UTC.
+fn format_date(date: &DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+/// Formats one of the model enums as its code list value.
+fn format_code<T: Serialize>(value: &T) -> Result<String> {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(code)) => Ok(code),
+        _ => Err(invalid("value is not a code list value")),
+    }
+}
+
+/// Builds the attributes of an element, leaving out those that are not set.
+fn attributes<'a, const N: usize>(
+    pairs: [(&'a str, Option<&'a str>); N],
+) -> Vec<(&'a str, &'a str)> {
+    pairs
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| (name, value)))
+        .collect()
+}
+
+/// A streaming writer producing an LEI-CDF 3.1 document from [`LeiRecordAttributes`].
+///
+/// The document declares the LEI-CDF namespace and follows the element order of the schema, so it
+/// can be validated, exchanged with other LEI-CDF tooling, and read back with
+/// [`LeiCdfReader`](crate::golden_copy::LeiCdfReader) into equal records. Records are written as
+/// they are passed in, so extracts of any size can be written with bounded memory:
+///
+/// - Dates are written in UTC.
+/// - The first of the `address_lines` becomes the `FirstAddressLine`, the others become
+///   `AdditionalAddressLine`s.
+/// - Events of [`EventGroup`]s other than `STANDALONE` are
+///   linked by a `group_id` numbered within the record, and a `group_sequence_no`.
+/// - The `ConformityFlag` is written as a direct child of the record, as in LEI-CDF 3.1.
+///
+/// The `bic`, `mic`, `ocid`, `qcc` and `spglobal` attributes are not part of LEI-CDF and are not
+/// written.
+pub struct LeiCdfWriter<W: Write> {
+    xml: XmlWriter<W>,
+}
+
+impl LeiCdfWriter<BufWriter<File>> {
+    /// Creates an LEI-CDF file, writing its header right away.
+    ///
+    /// # Parameters
+    ///
+    /// - `path` - The path of the XML file, which is replaced if it exists.
+    /// - `header` - The header of the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created or written.
+    pub fn create(path: impl AsRef<Path>, header: &FileHeader) -> Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?), header)
+    }
+}
+
+impl<W: Write> LeiCdfWriter<W> {
+    /// Creates a writer producing an LEI-CDF document, writing the `LEIHeader` right away.
+    ///
+    /// The `RecordCount` of the header is written as given; set it to the number of records that
+    /// will be written, or leave it `None`.
+    ///
+    /// # Parameters
+    ///
+    /// - `writer` - The destination of the XML document; wrap unbuffered destinations such as files
+    ///   in a [`BufWriter`].
+    /// - `header` - The header of the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the destination cannot be written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use gleif_rs::{
+    ///     golden_copy::{FileHeader, LeiCdfReader, LeiCdfWriter},
+    ///     model::enums::EntityStatus,
+    /// };
+    ///
+    /// let reader = LeiCdfReader::open("20250101-0000-gleif-goldencopy-lei2-golden-copy.xml")?;
+    /// let header = reader.header().cloned().expect("missing header");
+    /// let mut writer = LeiCdfWriter::create("inactive.xml", &FileHeader { record_count: None, ..header })?;
+    /// for record in reader {
+    ///     let record = record?;
+    ///     if record.entity.status == EntityStatus::Inactive {
+    ///         writer.write(&record)?;
+    ///     }
+    /// }
+    /// writer.finish()?;
+    /// ```
+    pub fn new(writer: W, header: &FileHeader) -> Result<Self> {
+        Ok(Self {
+            xml: XmlWriter::new(writer, &LEI_CDF, header)?,
+        })
+    }
+
+    /// Writes a record as an `LEIRecord` element.
+    ///
+    /// # Parameters
+    ///
+    /// - `record` - The record to write.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the destination cannot be written, or a
+    /// [`GleifError::ParseError`](crate::error::GleifError::ParseError) if the record has no legal
+    /// jurisdiction, which LEI-CDF requires.
+    pub fn write(&mut self, record: &LeiRecordAttributes) -> Result<()> {
+        let xml = &mut self.xml;
+        xml.start("LEIRecord", &[])?;
+        xml.text("LEI", &record.lei, &[])?;
+        entity(xml, &record.entity)?;
+        registration(xml, &record.registration)?;
+        if let Some(flag) = &record.conformity_flag {
+            xml.code("ConformityFlag", flag)?;
+        }
+        xml.end("LEIRecord")
+    }
+
+    /// Closes the document and returns the destination, after flushing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the destination cannot be written or flushed.
+    pub fn finish(self) -> Result<W> {
+        self.xml.finish()
+    }
+}
+
+impl<W: Write> fmt::Debug for LeiCdfWriter<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LeiCdfWriter").finish_non_exhaustive()
+    }
+}
+
+/// Writes an `Entity` element.
+fn entity<W: Write>(xml: &mut XmlWriter<W>, entity: &Entity) -> Result<()> {
+    xml.start("Entity", &[])?;
+    names(xml, entity)?;
+    for (name, address) in [
+        ("LegalAddress", &entity.legal_address),
+        ("HeadquartersAddress", &entity.headquarters_address),
+    ] {
+        xml.start(
+            name,
+            &attributes([("xml:lang", address.language.as_deref())]),
+        )?;
+        address_lines(
+            xml,
+            &address.address_lines,
+            [
+                address.address_number.as_deref(),
+                address.address_number_within_building.as_deref(),
+                address.mail_routing.as_deref(),
+            ],
+        )?;
+        locality(
+            xml,
+            &address.city,
+            address.region.as_deref(),
+            &address.country,
+            address.postal_code.as_deref(),
+        )?;
+        xml.end(name)?;
+    }
+    other_addresses(xml, "OtherAddress", &entity.other_addresses)?;
+    if let Some(addresses) = &entity.transliterated_other_addresses {
+        other_addresses(xml, "TransliteratedOtherAddress", addresses)?;
+    }
+    xml.start("RegistrationAuthority", &[])?;
+    xml.text("RegistrationAuthorityID", &entity.registered_at.id, &[])?;
+    xml.optional_text(
+        "OtherRegistrationAuthorityID",
+        entity.registered_at.other.as_deref(),
+    )?;
+    xml.optional_text(
+        "RegistrationAuthorityEntityID",
+        entity
+            .registered_as
+            .as_deref()
+            .or(entity.registered_at.entity_id.as_deref()),
+    )?;
+    xml.end("RegistrationAuthority")?;
+    if entity.jurisdiction.is_empty() {
+        return Err(invalid("entity has no legal jurisdiction"));
+    }
+    xml.text("LegalJurisdiction", &entity.jurisdiction, &[])?;
+    xml.code("EntityCategory", &entity.category)?;
+    if let Some(sub_category) = &entity.sub_category {
+        xml.code("EntitySubCategory", sub_category)?;
+    }
+    xml.start("LegalForm", &[])?;
+    xml.text("EntityLegalFormCode", &entity.legal_form.id, &[])?;
+    xml.optional_text("OtherLegalForm", entity.legal_form.other.as_deref())?;
+    xml.end("LegalForm")?;
+    let associated = &entity.associated_entity;
+    if associated.lei.is_some() || associated.name.is_some() {
+        let r#type = associated.r#type.as_ref().map(format_code).transpose()?;
+        xml.start(
+            "AssociatedEntity",
+            &attributes([("type", r#type.as_deref())]),
+        )?;
+        xml.optional_text("AssociatedLEI", associated.lei.as_deref())?;
+        xml.optional_text("AssociatedEntityName", associated.name.as_deref())?;
+        xml.end("AssociatedEntity")?;
+    }
+    xml.code("EntityStatus", &entity.status)?;
+    if let Some(date) = &entity.creation_date {
+        xml.date("EntityCreationDate", date)?;
+    }
+    if let Some(date) = &entity.expiration.date {
+        xml.date("EntityExpirationDate", date)?;
+    }
+    if let Some(reason) = &entity.expiration.reason {
+        xml.code("EntityExpirationReason", reason)?;
+    }
+    for successor in entity.successors() {
+        xml.start("SuccessorEntity", &[])?;
+        xml.optional_text("SuccessorLEI", successor.lei.as_deref())?;
+        xml.optional_text("SuccessorEntityName", successor.name.as_deref())?;
+        xml.end("SuccessorEntity")?;
+    }
+    legal_entity_events(xml, &entity.event_groups)?;
+    xml.end("Entity")
+}
+
+/// Writes the legal name, other names and transliterated other names of an entity.
+fn names<W: Write>(xml: &mut XmlWriter<W>, entity: &Entity) -> Result<()> {
+    let name = &entity.legal_name;
+    xml.text(
+        "LegalName",
+        &name.name,
+        &attributes([("xml:lang", name.language.as_deref())]),
+    )?;
+    if !entity.other_names.is_empty() {
+        xml.start("OtherEntityNames", &[])?;
+        for name in &entity.other_names {
+            let r#type = format_code(&name.r#type)?;
+            let attributes = attributes([
+                ("xml:lang", name.language.as_deref()),
+                ("type", Some(&r#type)),
+            ]);
+            xml.text("OtherEntityName", &name.name, &attributes)?;
+        }
+        xml.end("OtherEntityNames")?;
+    }
+    if !entity.transliterated_other_names.is_empty() {
+        xml.start("TransliteratedOtherEntityNames", &[])?;
+        for name in &entity.transliterated_other_names {
+            let r#type = format_code(&name.r#type)?;
+            let attributes = attributes([
+                ("xml:lang", name.language.as_deref()),
+                ("type", Some(&r#type)),
+            ]);
+            xml.text("TransliteratedOtherEntityName", &name.name, &attributes)?;
+        }
+        xml.end("TransliteratedOtherEntityNames")?;
+    }
+    Ok(())
+}
+
+/// Writes the `LegalEntityEvents` of an entity.
+fn legal_entity_events<W: Write>(xml: &mut XmlWriter<W>, groups: &[EventGroup]) -> Result<()> {
+    if groups.is_empty() {
+        return Ok(());
+    }
+    xml.start("LegalEntityEvents", &[])?;
+    let mut group_id = 0;
+    for group in groups {
+        let group_type = format_code(&group.group_type)?;
+        let linked = group.group_type != GroupType::Standalone;
+        if linked {
+            group_id += 1;
+        }
+        let group_id = group_id.to_string();
+        for (sequence_no, event) in group.events.iter().enumerate() {
+            let sequence_no = (sequence_no + 1).to_string();
+            legal_entity_event(
+                xml,
+                event,
+                &group_type,
+                linked.then_some((group_id.as_str(), sequence_no.as_str())),
+            )?;
+        }
+    }
+    xml.end("LegalEntityEvents")
+}
+
+/// Writes the `FirstAddressLine`, the address number, building and mail routing elements, and the
+/// `AdditionalAddressLine`s of an address.
+fn address_lines<W: Write>(
+    xml: &mut XmlWriter<W>,
+    lines: &[String],
+    [number, number_within_building, mail_routing]: [Option<&str>; 3],
+) -> Result<()> {
+    let (first, additional) = lines
+        .split_first()
+        .map_or(("", &[][..]), |(first, rest)| (first.as_str(), rest));
+    xml.text("FirstAddressLine", first, &[])?;
+    xml.optional_text("AddressNumber", number)?;
+    xml.optional_text("AddressNumberWithinBuilding", number_within_building)?;
+    xml.optional_text("MailRouting", mail_routing)?;
+    for line in additional {
+        xml.text("AdditionalAddressLine", line, &[])?;
+    }
+    Ok(())
+}
+
+/// Writes the city, region, country and postal code elements of an address.
+fn locality<W: Write>(
+    xml: &mut XmlWriter<W>,
+    city: &str,
+    region: Option<&str>,
+    country: &str,
+    postal_code: Option<&str>,
+) -> Result<()> {
+    xml.text("City", city, &[])?;
+    xml.optional_text("Region", region)?;
+    xml.text("Country", country, &[])?;
+    xml.optional_text("PostalCode", postal_code)
+}
+
+/// Writes the `OtherAddresses` or `TransliteratedOtherAddresses` of an entity, given the name of
+/// their elements.
+fn other_addresses<W: Write>(
+    xml: &mut XmlWriter<W>,
+    name: &str,
+    addresses: &[OtherAddress],
+) -> Result<()> {
+    if addresses.is_empty() {
+        return Ok(());
+    }
+    let list = format!("{name}es");
+    xml.start(&list, &[])?;
+    for address in addresses {
+        let r#type = format_code(&address.r#type)?;
+        xml.start(
+            name,
+            &attributes([
+                ("xml:lang", address.language.as_deref()),
+                ("type", Some(&r#type)),
+            ]),
+        )?;
+        address_lines(
+            xml,
+            &address.address_lines,
+            [
+                address.address_number.as_deref(),
+                address.address_number_within_building.as_deref(),
+                address.mail_routing.as_deref(),
+            ],
+        )?;
+        locality(
+            xml,
+            &address.city,
+            address.region.as_deref(),
+            &address.country,
+            address.postal_code.as_deref(),
+        )?;
+        xml.end(name)?;
+    }
+    xml.end(&list)
+}
+
+/// Writes a `LegalEntityEvent` element, with the group id and sequence number of linked events.
+fn legal_entity_event<W: Write>(
+    xml: &mut XmlWriter<W>,
+    event: &Event,
+    group_type: &str,
+    group: Option<(&str, &str)>,
+) -> Result<()> {
+    let status = format_code(&event.status)?;
+    xml.start(
+        "LegalEntityEvent",
+        &attributes([
+            ("event_status", Some(&status)),
+            ("group_type", Some(group_type)),
+            ("group_id", group.map(|(id, _)| id)),
+            (
+                "group_sequence_no",
+                group.map(|(_, sequence_no)| sequence_no),
+            ),
+        ]),
+    )?;
+    xml.code("LegalEntityEventType", &event.r#type)?;
+    xml.date("LegalEntityEventEffectiveDate", &event.effective_date)?;
+    xml.date("LegalEntityEventRecordedDate", &event.recorded_date)?;
+    xml.code("ValidationDocuments", &event.validation_documents)?;
+    xml.optional_text("ValidationReference", event.validation_reference.as_deref())?;
+    if let Some(fields) = &event.affected_fields {
+        xml.start("AffectedFields", &[])?;
+        for field in fields {
+            xml.text("AffectedField", &field.value, &[("xpath", &field.xpath)])?;
+        }
+        xml.end("AffectedFields")?;
+    }
+    xml.end("LegalEntityEvent")
+}
+
+/// Writes the `Registration` element of an LEI record.
+fn registration<W: Write>(xml: &mut XmlWriter<W>, registration: &Registration) -> Result<()> {
+    xml.start("Registration", &[])?;
+    xml.date(
+        "InitialRegistrationDate",
+        &registration.initial_registration_date,
+    )?;
+    xml.date("LastUpdateDate", &registration.last_update_date)?;
+    xml.code("RegistrationStatus", &registration.status)?;
+    xml.date("NextRenewalDate", &registration.next_renewal_date)?;
+    xml.text("ManagingLOU", &registration.managing_lou, &[])?;
+    xml.code("ValidationSources", &registration.corroboration_level)?;
+    if !registration.validated_at.id.is_empty() {
+        xml.start("ValidationAuthority", &[])?;
+        authority(
+            xml,
+            &registration.validated_at,
+            registration.validated_as.as_deref(),
+        )?;
+        xml.end("ValidationAuthority")?;
+    }
+    if !registration.other_validation_authorities.is_empty() {
+        xml.start("OtherValidationAuthorities", &[])?;
+        for other in &registration.other_validation_authorities {
+            xml.start("OtherValidationAuthority", &[])?;
+            authority(
+                xml,
+                &other.validated_at,
+                Some(other.validated_as.as_str()).filter(|id| !id.is_empty()),
+            )?;
+            xml.end("OtherValidationAuthority")?;
+        }
+        xml.end("OtherValidationAuthorities")?;
+    }
+    xml.end("Registration")
+}
+
+/// Writes the identifiers of a `ValidationAuthority` or `OtherValidationAuthority` element.
+fn authority<W: Write>(
+    xml: &mut XmlWriter<W>,
+    authority: &ValidationAuthority,
+    entity_id: Option<&str>,
+) -> Result<()> {
+    xml.text("ValidationAuthorityID", &authority.id, &[])?;
+    xml.optional_text("OtherValidationAuthorityID", authority.other.as_deref())?;
+    xml.optional_text("ValidationAuthorityEntityID", entity_id)
+}
+
+/// A streaming writer producing an RR-CDF 2.1 document from [`RelationshipRecordAttributes`].
+///
+/// The document declares the RR-CDF namespace and follows the element order of the schema, so it
+/// can be read back with [`RrCdfReader`](crate::golden_copy::RrCdfReader) into equal records. The
+/// API-only validity attributes (`valid_from`, `valid_to` and `extension`) have no RR-CDF
+/// counterpart and are not written; the reader derives `valid_from` from the content date of the
+/// header.
+pub struct RrCdfWriter<W: Write> {
+    xml: XmlWriter<W>,
+}
+
+impl RrCdfWriter<BufWriter<File>> {
+    /// Creates an RR-CDF file, writing its header right away.
+    ///
+    /// # Parameters
+    ///
+    /// - `path` - The path of the XML file, which is replaced if it exists.
+    /// - `header` - The header of the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created or written.
+    pub fn create(path: impl AsRef<Path>, header: &FileHeader) -> Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?), header)
+    }
+}
+
+impl<W: Write> RrCdfWriter<W> {
+    /// Creates a writer producing an RR-CDF document, writing the `Header` right away.
+    ///
+    /// # Parameters
+    ///
+    /// - `writer` - The destination of the XML document; wrap unbuffered destinations such as files
+    ///   in a [`BufWriter`].
+    /// - `header` - The header of the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the destination cannot be written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use gleif_rs::golden_copy::{RrCdfReader, RrCdfWriter};
+    ///
+    /// let reader = RrCdfReader::open("20250101-0000-gleif-goldencopy-rr-golden-copy.xml")?;
+    /// let header = reader.header().cloned().expect("missing header");
+    /// let mut writer = RrCdfWriter::create("relationships.xml", &header)?;
+    /// for record in reader {
+    ///     writer.write(&record?)?;
+    /// }
+    /// writer.finish()?;
+    /// ```
+    pub fn new(writer: W, header: &FileHeader) -> Result<Self> {
+        Ok(Self {
+            xml: XmlWriter::new(writer, &RR_CDF, header)?,
+        })
+    }
+
+    /// Writes a record as a `RelationshipRecord` element.
+    ///
+    /// # Parameters
+    ///
+    /// - `record` - The record to write.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the destination cannot be written.
+    pub fn write(&mut self, record: &RelationshipRecordAttributes) -> Result<()> {
+        let xml = &mut self.xml;
+        let relationship = &record.relationship;
+        xml.start("RelationshipRecord", &[])?;
+        xml.start("Relationship", &[])?;
+        node(xml, "StartNode", &relationship.start_node)?;
+        node(xml, "EndNode", &relationship.end_node)?;
+        xml.code("RelationshipType", &relationship.r#type)?;
+        if !relationship.periods.is_empty() {
+            xml.start("RelationshipPeriods", &[])?;
+            for period in &relationship.periods {
+                xml.start("RelationshipPeriod", &[])?;
+                xml.date("StartDate", &period.start_date)?;
+                if let Some(end_date) = &period.end_date {
+                    xml.date("EndDate", end_date)?;
+                }
+                xml.code("PeriodType", &period.r#type)?;
+                xml.end("RelationshipPeriod")?;
+            }
+            xml.end("RelationshipPeriods")?;
+        }
+        xml.code("RelationshipStatus", &relationship.status)?;
+        if !relationship.qualifiers.is_empty() {
+            xml.start("RelationshipQualifiers", &[])?;
+            for qualifier in &relationship.qualifiers {
+                xml.start("RelationshipQualifier", &[])?;
+                xml.text("QualifierDimension", &qualifier.dimension, &[])?;
+                xml.text("QualifierCategory", &qualifier.category, &[])?;
+                xml.end("RelationshipQualifier")?;
+            }
+            xml.end("RelationshipQualifiers")?;
+        }
+        if !relationship.quantifiers.is_empty() {
+            xml.start("RelationshipQuantifiers", &[])?;
+            for quantifier in &relationship.quantifiers {
+                xml.start("RelationshipQuantifier", &[])?;
+                xml.text("MeasurementMethod", &quantifier.measurement_method, &[])?;
+                xml.text("QuantifierAmount", &quantifier.quantity.to_string(), &[])?;
+                xml.optional_text("QuantifierUnits", quantifier.units.as_deref())?;
+                xml.end("RelationshipQuantifier")?;
+            }
+            xml.end("RelationshipQuantifiers")?;
+        }
+        xml.end("Relationship")?;
+        relationship_registration(xml, &record.registration)?;
+        xml.end("RelationshipRecord")
+    }
+
+    /// Closes the document and returns the destination, after flushing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the destination cannot be written or flushed.
+    pub fn finish(self) -> Result<W> {
+        self.xml.finish()
+    }
+}
+
+impl<W: Write> fmt::Debug for RrCdfWriter<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RrCdfWriter").finish_non_exhaustive()
+    }
+}
+
+/// Writes a `StartNode` or `EndNode` element.
+fn node<W: Write>(xml: &mut XmlWriter<W>, name: &str, node: &RelationshipNode) -> Result<()> {
+    xml.start(name, &[])?;
+    xml.text("NodeID", &node.id, &[])?;
+    xml.text("NodeIDType", &node.r#type, &[])?;
+    xml.end(name)
+}
+
+/// Writes the `Registration` element of a relationship record.
+fn relationship_registration<W: Write>(
+    xml: &mut XmlWriter<W>,
+    registration: &RelationshipRegistration,
+) -> Result<()> {
+    xml.start("Registration", &[])?;
+    xml.date(
+        "InitialRegistrationDate",
+        &registration.initial_registration_date,
+    )?;
+    if let Some(date) = &registration.last_update_date {
+        xml.date("LastUpdateDate", date)?;
+    }
+    xml.code("RegistrationStatus", &registration.status)?;
+    xml.date("NextRenewalDate", &registration.next_renewal_date)?;
+    xml.text("ManagingLOU", &registration.managing_lou, &[])?;
+    xml.code("ValidationSources", &registration.corroboration_level)?;
+    xml.code("ValidationDocuments", &registration.corroboration_documents)?;
+    xml.optional_text(
+        "ValidationReference",
+        registration.corroboration_reference.as_deref(),
+    )?;
+    xml.end("Registration")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::GleifError,
+        golden_copy::{LeiCdfReader, RrCdfReader},
+    };
+
+    #[test]
+    fn test_write_lei_cdf() {
+        let reader = LeiCdfReader::open("tests/data/golden_copy/lei_cdf_sample.xml")
+            .expect("Failed to open sample file");
+        let header = FileHeader {
+            record_count: Some(2),
+            ..reader.header().expect("Missing header").clone()
+        };
+        let records: Vec<_> = reader.filter_map(Result::ok).collect();
+        assert_eq!(records.len(), 2);
+
+        let mut writer = LeiCdfWriter::new(Vec::new(), &header).unwrap();
+        for record in &records {
+            writer.write(record).unwrap();
+        }
+        let xml = String::from_utf8(writer.finish().unwrap()).unwrap();
+        assert!(xml.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+        assert!(xml.contains(
+            r#"<lei:LEIData xmlns:lei="http://www.gleif.org/data/schema/leidata/2016">"#
+        ));
+        assert!(xml.contains(
+            r#"<lei:LegalName xml:lang="en">Smith &amp; Jones Global Fund</lei:LegalName>"#
+        ));
+        assert!(xml.contains(
+            r#"group_type="COMPLEX_CHANGE_LEGAL_FORM" group_id="1" group_sequence_no="2""#
+        ));
+
+        let reader = LeiCdfReader::new(xml.as_bytes()).unwrap();
+        assert_eq!(reader.header(), Some(&header));
+        let written: Vec<_> = reader.collect::<Result<_>>().unwrap();
+        assert_eq!(written, records);
+
+        let mut record = records[0].clone();
+        record.entity.jurisdiction.clear();
+        let mut writer = LeiCdfWriter::new(Vec::new(), &header).unwrap();
+        assert!(matches!(
+            writer.write(&record),
+            Err(GleifError::ParseError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_write_rr_cdf() {
+        let reader = RrCdfReader::open("tests/data/golden_copy/rr_cdf_sample.xml")
+            .expect("Failed to open sample file");
+        let header = reader.header().expect("Missing header").clone();
+        let records: Vec<_> = reader.filter_map(Result::ok).collect();
+        assert_eq!(records.len(), 2);
+
+        let mut writer = RrCdfWriter::new(Vec::new(), &header).unwrap();
+        for record in &records {
+            writer.write(record).unwrap();
+        }
+        let xml = String::from_utf8(writer.finish().unwrap()).unwrap();
+        assert!(xml.contains(
+            r#"<rr:RelationshipData xmlns:rr="http://www.gleif.org/data/schema/rr/2016">"#
+        ));
+        assert!(xml.contains("<rr:QuantifierAmount>62.5</rr:QuantifierAmount>"));
+
+        let reader = RrCdfReader::new(xml.as_bytes()).unwrap();
+        assert_eq!(reader.header(), Some(&header));
+        let written: Vec<_> = reader.collect::<Result<_>>().unwrap();
+        assert_eq!(written, records);
+    }
+}
@@ -0,0 +1,459 @@
+//! Streaming XML reading shared by the Golden Copy parsers.
+//!
+//! Golden Copy files are far too large to load at once, but each record is small. The
+//! [`ElementReader`] therefore streams through the file and materializes only the elements asked
+//! for (one record, or the file header) as a small [`Element`] tree, which the format-specific
+//! parsers map onto the model types.
+
+use crate::{
+    error::{GleifError, ParseErrorKind, Result},
+    golden_copy::header::FileHeader,
+    history::{parse_code, parse_date},
+};
+use chrono::{DateTime, Utc};
+use quick_xml::{
+    Reader,
+    escape::resolve_predefined_entity,
+    events::{BytesStart, Event},
+};
+use serde::de::DeserializeOwned;
+use std::{io::BufRead, iter, mem};
+
+/// Wrapper element holding elements introduced by a later version of a schema.
+///
+/// Its children are treated as children of the enclosing element, so that records written with the
+/// `NextVersion` extension point parse the same as records using the newer schema directly.
+const NEXT_VERSION: &str = "NextVersion";
+
+/// An XML element read into memory, identified by its local (namespace-less) name.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    text: String,
+    children: Vec<Element>,
+}
+
+impl Element {
+    /// Creates an element with no content from a start tag.
+    fn from_start(start: &BytesStart<'_>) -> Result<Self> {
+        let name = String::from_utf8_lossy(start.local_name().as_ref()).into_owned();
+        let attributes = start
+            .attributes()
+            .map(|attribute| {
+                let attribute = attribute.map_err(quick_xml::Error::from)?;
+                let key = String::from_utf8_lossy(attribute.key.local_name().as_ref()).into_owned();
+                Ok((key, attribute.unescape_value()?.into_owned()))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            name,
+            attributes,
+            ..Self::default()
+        })
+    }
+
+    /// Creates an empty element.
+    pub(crate) fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the `index`th (1-based) child with the given name, creating it and any missing
+    /// earlier ones. An `index` of 0 selects the first child.
+    ///
+    /// As up to `index` children are created, callers bound `index` by the number of repetitions
+    /// they accept.
+    pub(crate) fn nth_child_mut(&mut self, name: &str, index: usize) -> &mut Element {
+        let index = index.max(1);
+        let positions: Vec<usize> = self
+            .children
+            .iter()
+            .enumerate()
+            .filter(|(_, child)| child.name == name)
+            .map(|(position, _)| position)
+            .collect();
+        if let Some(&position) = positions.get(index - 1) {
+            return &mut self.children[position];
+        }
+        let missing = index - positions.len();
+        self.children
+            .extend(iter::repeat_with(|| Element::new(name)).take(missing));
+        let last = self.children.len() - 1;
+        &mut self.children[last]
+    }
+
+    /// Sets the value of an attribute.
+    pub(crate) fn set_attribute(&mut self, name: &str, value: &str) {
+        match self.attributes.iter_mut().find(|(key, _)| key == name) {
+            Some((_, current)) => *current = value.to_string(),
+            None => self.attributes.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    /// Sets the text content of the element.
+    pub(crate) fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
+
+    /// Removes descendants without text, attributes or children.
+    pub(crate) fn prune(&mut self) {
+        for child in &mut self.children {
+            child.prune();
+        }
+        self.children.retain(|child| {
+            !child.text().is_empty() || !child.attributes.is_empty() || !child.children.is_empty()
+        });
+    }
+
+    /// Returns the local name of the element.
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the value of an attribute, looked up by its local name (`lang` for `xml:lang`).
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the value of a mandatory attribute.
+    pub(crate) fn required_attribute(&self, name: &str) -> Result<&str> {
+        self.attribute(name)
+            .ok_or_else(|| missing(&format!("attribute `{name}`"), &self.name))
+    }
+
+    /// Returns the trimmed text content of the element.
+    pub(crate) fn text(&self) -> &str {
+        self.text.trim()
+    }
+
+    /// Returns the child elements, with the children of `NextVersion` wrappers inlined.
+    pub(crate) fn elements(&self) -> Box<dyn Iterator<Item = &Element> + '_> {
+        Box::new(self.children.iter().flat_map(
+            |child| -> Box<dyn Iterator<Item = &Element> + '_> {
+                if child.name == NEXT_VERSION {
+                    child.elements()
+                } else {
+                    Box::new(iter::once(child))
+                }
+            },
+        ))
+    }
+
+    /// Returns the child elements with the given name.
+    pub(crate) fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.elements().filter(move |child| child.name == name)
+    }
+
+    /// Returns the first child element with the given name.
+    pub(crate) fn child(&self, name: &str) -> Option<&Element> {
+        self.elements().find(|child| child.name == name)
+    }
+
+    /// Returns the children named `item` of the first child named `list`, e.g. the `OtherEntityName`s
+    /// of `OtherEntityNames`.
+    pub(crate) fn list<'a>(
+        &'a self,
+        list: &str,
+        item: &'a str,
+    ) -> impl Iterator<Item = &'a Element> {
+        self.child(list)
+            .into_iter()
+            .flat_map(move |list| list.children(item))
+    }
+
+    /// Returns the first child element with the given name, or an error if there is none.
+    pub(crate) fn required(&self, name: &str) -> Result<&Element> {
+        self.child(name)
+            .ok_or_else(|| missing(&format!("element `{name}`"), &self.name))
+    }
+
+    /// Returns the text of a child element, if present and not empty.
+    pub(crate) fn child_text(&self, name: &str) -> Option<String> {
+        self.child(name)
+            .map(Element::text)
+            .filter(|text| !text.is_empty())
+            .map(str::to_string)
+    }
+
+    /// Returns the text of a mandatory child element.
+    pub(crate) fn required_text(&self, name: &str) -> Result<String> {
+        self.child_text(name)
+            .ok_or_else(|| missing(&format!("element `{name}`"), &self.name))
+    }
+
+    /// Parses the text of a mandatory child element as a date.
+    pub(crate) fn date(&self, name: &str) -> Result<DateTime<Utc>> {
+        parse_date(&self.required_text(name)?)
+    }
+
+    /// Parses the text of an optional child element as a date.
+    pub(crate) fn optional_date(&self, name: &str) -> Result<Option<DateTime<Utc>>> {
+        self.child_text(name).as_deref().map(parse_date).transpose()
+    }
+
+    /// Parses the text of a mandatory child element as one of the model enums.
+    pub(crate) fn code<T: DeserializeOwned>(&self, name: &str) -> Result<T> {
+        parse_code(&self.required_text(name)?)
+    }
+
+    /// Parses the text of an optional child element as one of the model enums.
+    pub(crate) fn optional_code<T: DeserializeOwned>(&self, name: &str) -> Result<Option<T>> {
+        self.child_text(name).as_deref().map(parse_code).transpose()
+    }
+}
+
+/// Streams through an XML document, reading selected elements into memory one at a time.
+#[derive(Debug)]
+pub(crate) struct ElementReader<R> {
+    reader: Reader<R>,
+    buf: Vec<u8>,
+}
+
+impl<R: BufRead> ElementReader<R> {
+    /// Creates a reader over an XML document.
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader: Reader::from_reader(reader),
+            buf: Vec::new(),
+        }
+    }
+
+    /// Skips ahead to the next element whose local name is one of `names` and reads it, with all
+    /// its descendants, into memory. Returns `None` at the end of the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GleifError::XmlError`] if the document is not well-formed.
+    pub(crate) fn next_element(&mut self, names: &[&str]) -> Result<Option<Element>> {
+        loop {
+            self.buf.clear();
+            match self.reader.read_event_into(&mut self.buf)? {
+                Event::Start(start) if is_named(&start, names) => {
+                    let element = Element::from_start(&start)?;
+                    return self.read_content(element).map(Some);
+                }
+                Event::Empty(start) if is_named(&start, names) => {
+                    return Element::from_start(&start).map(Some);
+                }
+                Event::Eof => return Ok(None),
+                _ => {}
+            }
+        }
+    }
+
+    /// Reads the content of an element up to its end tag.
+    fn read_content(&mut self, element: Element) -> Result<Element> {
+        let mut current = element;
+        let mut parents: Vec<Element> = Vec::new();
+        loop {
+            self.buf.clear();
+            match self.reader.read_event_into(&mut self.buf)? {
+                Event::Start(start) => {
+                    let child = Element::from_start(&start)?;
+                    parents.push(mem::replace(&mut current, child));
+                }
+                Event::Empty(start) => current.children.push(Element::from_start(&start)?),
+                Event::Text(text) => current
+                    .text
+                    .push_str(&text.decode().map_err(quick_xml::Error::from)?),
+                Event::CData(data) => current
+                    .text
+                    .push_str(&data.decode().map_err(quick_xml::Error::from)?),
+                Event::GeneralRef(reference) => {
+                    if let Some(ch) = reference.resolve_char_ref()? {
+                        current.text.push(ch);
+                    } else {
+                        let name = reference.decode().map_err(quick_xml::Error::from)?;
+                        let value = resolve_predefined_entity(&name).ok_or_else(|| {
+                            invalid(&format!("unknown entity `&{name};` in `{}`", current.name))
+                        })?;
+                        current.text.push_str(value);
+                    }
+                }
+                Event::End(_) => {
+                    let Some(parent) = parents.pop() else {
+                        return Ok(current);
+                    };
+                    let child = mem::replace(&mut current, parent);
+                    current.children.push(child);
+                }
+                Event::Eof => {
+                    return Err(invalid(&format!(
+                        "unexpected end of document in `{}`",
+                        current.name
+                    )));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Streams the records of a Golden Copy file, after reading its header.
+#[derive(Debug)]
+pub(crate) struct RecordStream<R> {
+    elements: ElementReader<R>,
+    record: &'static str,
+    header: Option<FileHeader>,
+    pending: Option<Element>,
+    failed: bool,
+}
+
+impl<R: BufRead> RecordStream<R> {
+    /// Creates a stream over the elements named `record`, reading the header named `header` first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document is not well-formed up to the header, or the header is invalid.
+    pub(crate) fn new(reader: R, header: &'static str, record: &'static str) -> Result<Self> {
+        let mut elements = ElementReader::new(reader);
+        let (header, pending) = match elements.next_element(&[header, record])? {
+            Some(element) if element.name() == header => {
+                (Some(FileHeader::from_element(&element)?), None)
+            }
+            pending => (None, pending),
+        };
+        Ok(Self {
+            elements,
+            record,
+            header,
+            pending,
+            failed: false,
+        })
+    }
+
+    /// Returns the file header, if the file has one.
+    pub(crate) fn header(&self) -> Option<&FileHeader> {
+        self.header.as_ref()
+    }
+
+    /// Reads the next record element.
+    ///
+    /// Once the document turns out not to be well-formed, the error is returned and the stream ends.
+    pub(crate) fn next_record(&mut self) -> Option<Result<Element>> {
+        if let Some(element) = self.pending.take() {
+            return Some(Ok(element));
+        }
+        if self.failed {
+            return None;
+        }
+        match self.elements.next_element(&[self.record]) {
+            Ok(element) => element.map(Ok),
+            Err(error) => {
+                self.failed = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+/// Returns `true` if the local name of a start tag is one of `names`.
+fn is_named(start: &BytesStart<'_>, names: &[&str]) -> bool {
+    let name = start.local_name();
+    names
+        .iter()
+        .any(|candidate| candidate.as_bytes() == name.as_ref())
+}
+
+/// Builds the error for a missing mandatory element or attribute.
+fn missing(what: &str, parent: &str) -> GleifError {
+    GleifError::ParseError {
+        kind: ParseErrorKind::Field,
+        message: format!("missing {what} in `{parent}`"),
+    }
+}
+
+/// Prefixes the message of a parse error with the identifier of the record it occurred in.
+pub(crate) fn in_record(error: GleifError, id: &str) -> GleifError {
+    match error {
+        GleifError::ParseError { kind, message } => GleifError::ParseError {
+            kind,
+            message: format!("{id}: {message}"),
+        },
+        other => other,
+    }
+}
+
+/// Builds the error for invalid content.
+pub(crate) fn invalid(message: &str) -> GleifError {
+    GleifError::ParseError {
+        kind: ParseErrorKind::Value,
+        message: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::enums::EntityStatus;
+
+    #[test]
+    fn test_next_element() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <a:Root xmlns:a="urn:a" xmlns:b="urn:b">
+                <a:Item id="1" xml:lang="en"><a:Name>Smith &amp; Sons &#x2013; <![CDATA[<Ltd>]]></a:Name></a:Item>
+                <a:Other><a:Item id="2"/></a:Other>
+                <a:Item id="3">
+                    <a:NextVersion><a:Status>ACTIVE</a:Status></a:NextVersion>
+                    <b:Extension><b:Name>Ignored</b:Name></b:Extension>
+                </a:Item>
+            </a:Root>"#;
+        let mut reader = ElementReader::new(xml.as_bytes());
+
+        let first = reader.next_element(&["Item"]).unwrap().unwrap();
+        assert_eq!(first.name(), "Item");
+        assert_eq!(first.attribute("id"), Some("1"));
+        assert_eq!(first.attribute("lang"), Some("en"));
+        assert_eq!(
+            first.required_text("Name").unwrap(),
+            "Smith & Sons \u{2013} <Ltd>"
+        );
+
+        let second = reader.next_element(&["Item"]).unwrap().unwrap();
+        assert_eq!(second.attribute("id"), Some("2"));
+        assert!(second.elements().next().is_none());
+
+        let third = reader.next_element(&["Item"]).unwrap().unwrap();
+        assert_eq!(
+            third.code::<EntityStatus>("Status").unwrap(),
+            EntityStatus::Active
+        );
+        assert_eq!(third.child_text("Name"), None);
+        assert!(third.required("Name").is_err());
+        assert!(third.code::<EntityStatus>("Extension").is_err());
+
+        assert!(reader.next_element(&["Item"]).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_malformed_document() {
+        let mut reader = ElementReader::new("<Root><Item><Name>x</Item></Root>".as_bytes());
+        assert!(matches!(
+            reader.next_element(&["Item"]),
+            Err(GleifError::XmlError(_))
+        ));
+        let mut reader = ElementReader::new("<Root><Item><Name>x</Name>".as_bytes());
+        assert!(reader.next_element(&["Item"]).is_err());
+    }
+
+    #[test]
+    fn test_nth_child_mut() {
+        let mut element = Element::new("Entity");
+        element.nth_child_mut("LegalName", 1).set_text("Acme");
+        element
+            .nth_child_mut("OtherEntityName", 2)
+            .set_text("Second");
+        assert_eq!(element.children("OtherEntityName").count(), 2);
+        assert_eq!(element.nth_child_mut("OtherEntityName", 0).text(), "");
+        assert_eq!(element.nth_child_mut("OtherEntityName", 2).text(), "Second");
+        assert_eq!(element.nth_child_mut("LegalName", 0).text(), "Acme");
+        assert_eq!(element.nth_child_mut("Status", 0).name, "Status");
+        assert_eq!(element.children.len(), 4);
+    }
+}
@@ -0,0 +1,724 @@
+//! # Corporate Hierarchy Graphs ([`HierarchyGraph`]) - Walk the Ownership Tree of a Group
+//!
+//! This module builds the full accounting-consolidation hierarchy around an LEI in memory, rather than
+//! one hop at a time. Starting from any entity, [`HierarchyBuilder`] walks the direct parents up to the
+//! top of the group (and the reported ultimate parent), then walks the direct children of every entity
+//! down again, collecting [`LeiRecord`] nodes and [`RelationshipRecord`] edges.
+//!
+//! Edges are direct accounting-consolidation relationships only. The reported ultimate parent is added as a
+//! node without an edge of its own: its ultimate parent relationship skips the intermediate levels, so it
+//! would duplicate the chain of direct relationships (or, where that chain is incomplete, suggest a direct
+//! link that was never reported). It is connected to the rest of the graph once the downward walk reaches
+//! the start entity through its direct children.
+//!
+//! ## Key Features
+//!
+//! - **Bounded Concurrency:** Children of all entities on the same level are fetched concurrently, with at
+//!   most [`HierarchyBuilder::max_concurrency`] requests in flight.
+//! - **Pagination:** Child lists are fetched page by page until exhausted.
+//! - **Cycle Detection:** Relationships that would close a cycle are not added to the graph and are
+//!   reported by [`HierarchyGraph::cycles`] instead.
+//! - **Depth Limits:** The walk stops [`HierarchyBuilder::max_depth`] levels away from the start (upwards)
+//!   and from the top (downwards); [`HierarchyGraph::is_truncated`] tells whether the limit was hit.
+//! - **Reporting Exceptions:** Where an entity does not report its parent, the reporting exceptions are
+//!   attached to its [`HierarchyNode`].
+//! - **Export:** Render a graph to Graphviz DOT ([`HierarchyGraph::to_dot`]) or to a nested JSON tree
+//!   ([`HierarchyGraph::to_json_tree`]), optionally hiding inactive relationships or lapsed registrations
+//!   ([`ExportOptions`]).
+//!
+//! ## Example
+//!
+//! ```rust, ignore
+//! use gleif_rs::{client::GleifClient, hierarchy::ExportOptions};
+//!
+//! let client = GleifClient::new();
+//! let graph = client
+//!     .hierarchy("5493001KJTIIGC8Y1R12")
+//!     .max_depth(5)
+//!     .max_concurrency(8)
+//!     .build()
+//!     .await?;
+//! for root in graph.roots() {
+//!     println!("Top of group: {root}");
+//! }
+//! println!("{} entities, {} relationships", graph.len(), graph.edges().len());
+//!
+//! let options = ExportOptions::new().hide_inactive_relationships(true);
+//! std::fs::write("group.dot", graph.to_dot(options))?;
+//! ```
+
+use crate::{
+    client::GleifClient,
+    error::Result,
+    model::{
+        common::GleifApiResponse, lei_record::LeiRecord, relationship_record::RelationshipRecord,
+        reporting_exception::ReportingException,
+    },
+};
+use futures_util::{StreamExt, future, stream};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+mod export;
+
+pub use export::ExportOptions;
+
+/// Default maximum number of levels walked in each direction.
+const DEFAULT_MAX_DEPTH: usize = 10;
+
+/// Default maximum number of concurrent requests.
+const DEFAULT_MAX_CONCURRENCY: usize = 4;
+
+/// Page size used when fetching child lists (the maximum accepted by the API).
+const DEFAULT_PAGE_SIZE: usize = 200;
+
+/// An entity in a [`HierarchyGraph`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct HierarchyNode {
+    /// The LEI record of the entity.
+    pub record: LeiRecord,
+    /// The number of direct relationships between the entity and the nearest root of the graph.
+    pub depth: usize,
+    /// The direct and ultimate parent reporting exceptions, for entities not reporting a parent.
+    pub reporting_exceptions: Vec<ReportingException>,
+}
+
+impl HierarchyNode {
+    /// Returns the LEI of the entity.
+    #[must_use]
+    pub fn lei(&self) -> &str {
+        &self.record.attributes.lei
+    }
+}
+
+/// An in-memory graph of entities ([`LeiRecord`] nodes) and their relationships ([`RelationshipRecord`]
+/// edges, pointing from child to parent).
+///
+/// Graphs are usually built with [`GleifClient::hierarchy`], but can also be assembled from local data
+/// with [`HierarchyGraph::insert_node`] and [`HierarchyGraph::insert_edge`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HierarchyGraph {
+    start: String,
+    nodes: BTreeMap<String, HierarchyNode>,
+    edges: Vec<RelationshipRecord>,
+    edge_ids: HashSet<String>,
+    parent_edges: HashMap<String, Vec<usize>>,
+    child_edges: HashMap<String, Vec<usize>>,
+    cycles: Vec<RelationshipRecord>,
+    truncated: bool,
+}
+
+impl HierarchyGraph {
+    /// Creates an empty graph for a walk starting at the given LEI.
+    #[must_use]
+    pub fn new(start: impl Into<String>) -> Self {
+        Self {
+            start: start.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the LEI the walk started from.
+    #[must_use]
+    pub fn start(&self) -> &str {
+        &self.start
+    }
+
+    /// Returns the number of entities in the graph.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the graph contains no entities.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns `true` if the graph contains the entity with the given LEI.
+    #[must_use]
+    pub fn contains(&self, lei: &str) -> bool {
+        self.nodes.contains_key(lei)
+    }
+
+    /// Returns the entity with the given LEI.
+    #[must_use]
+    pub fn node(&self, lei: &str) -> Option<&HierarchyNode> {
+        self.nodes.get(lei)
+    }
+
+    /// Returns all entities, ordered by LEI.
+    pub fn nodes(&self) -> impl Iterator<Item = &HierarchyNode> {
+        self.nodes.values()
+    }
+
+    /// Returns all relationships of the graph.
+    #[must_use]
+    pub fn edges(&self) -> &[RelationshipRecord] {
+        &self.edges
+    }
+
+    /// Returns the relationships that were not added because they would have closed a cycle.
+    #[must_use]
+    pub fn cycles(&self) -> &[RelationshipRecord] {
+        &self.cycles
+    }
+
+    /// Returns `true` if the walk stopped at the depth limit while more entities were reported.
+    #[must_use]
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the LEIs of the entities without a parent in the graph, ordered by LEI.
+    pub fn roots(&self) -> impl Iterator<Item = &str> {
+        self.nodes
+            .keys()
+            .map(String::as_str)
+            .filter(|lei| self.parents(lei).next().is_none())
+    }
+
+    /// Returns the relationships from the given entity to its parents.
+    pub fn parents<'a>(&'a self, lei: &str) -> impl Iterator<Item = &'a RelationshipRecord> {
+        self.edges_at(&self.parent_edges, lei)
+    }
+
+    /// Returns the relationships from the children of the given entity to it.
+    pub fn children<'a>(&'a self, lei: &str) -> impl Iterator<Item = &'a RelationshipRecord> {
+        self.edges_at(&self.child_edges, lei)
+    }
+
+    /// Returns the relationships indexed under the given LEI in an adjacency map.
+    fn edges_at<'a>(
+        &'a self,
+        adjacency: &'a HashMap<String, Vec<usize>>,
+        lei: &str,
+    ) -> impl Iterator<Item = &'a RelationshipRecord> {
+        adjacency
+            .get(lei)
+            .into_iter()
+            .flatten()
+            .map(|&index| &self.edges[index])
+    }
+
+    /// Adds an entity to the graph, returning `false` if it was already present.
+    pub fn insert_node(&mut self, record: LeiRecord) -> bool {
+        if self.nodes.contains_key(&record.attributes.lei) {
+            return false;
+        }
+        self.nodes.insert(
+            record.attributes.lei.clone(),
+            HierarchyNode {
+                record,
+                depth: 0,
+                reporting_exceptions: Vec::new(),
+            },
+        );
+        true
+    }
+
+    /// Adds a relationship to the graph.
+    ///
+    /// Returns `false` if the relationship is already present, or if the parent is a descendant of the
+    /// child, in which case the relationship is recorded in [`HierarchyGraph::cycles`] instead.
+    pub fn insert_edge(&mut self, relationship: RelationshipRecord) -> bool {
+        if self.edge_ids.contains(&relationship.id) {
+            return false;
+        }
+        if self.is_ancestor(child_of(&relationship), parent_of(&relationship)) {
+            if !self.cycles.iter().any(|edge| edge.id == relationship.id) {
+                self.cycles.push(relationship);
+            }
+            return false;
+        }
+        let index = self.edges.len();
+        self.edge_ids.insert(relationship.id.clone());
+        self.parent_edges
+            .entry(child_of(&relationship).to_string())
+            .or_default()
+            .push(index);
+        self.child_edges
+            .entry(parent_of(&relationship).to_string())
+            .or_default()
+            .push(index);
+        self.edges.push(relationship);
+        true
+    }
+
+    /// Attaches a reporting exception to the entity it was reported for, if present in the graph.
+    pub fn insert_reporting_exception(&mut self, exception: ReportingException) {
+        if let Some(node) = self.nodes.get_mut(&exception.attributes.lei) {
+            node.reporting_exceptions.push(exception);
+        }
+    }
+
+    /// Returns `true` if `ancestor` is `lei` itself or is reachable from it through parent relationships.
+    fn is_ancestor(&self, ancestor: &str, lei: &str) -> bool {
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::from([lei]);
+        while let Some(current) = queue.pop_front() {
+            if current == ancestor {
+                return true;
+            }
+            if seen.insert(current) {
+                queue.extend(self.parents(current).map(parent_of));
+            }
+        }
+        false
+    }
+
+    /// Sets the depth of every entity to its distance from the nearest root.
+    fn update_depths(&mut self) {
+        let mut depths: BTreeMap<String, usize> = BTreeMap::new();
+        let mut queue: VecDeque<(String, usize)> =
+            self.roots().map(|lei| (lei.to_string(), 0)).collect();
+        while let Some((lei, depth)) = queue.pop_front() {
+            if depths.contains_key(&lei) {
+                continue;
+            }
+            queue.extend(
+                self.children(&lei)
+                    .map(|edge| (child_of(edge).to_string(), depth + 1)),
+            );
+            depths.insert(lei, depth);
+        }
+        for (lei, node) in &mut self.nodes {
+            node.depth = depths.get(lei).copied().unwrap_or_default();
+        }
+    }
+}
+
+/// Returns the LEI of the child (start node) of a relationship.
+fn child_of(relationship: &RelationshipRecord) -> &str {
+    &relationship.attributes.relationship.start_node.id
+}
+
+/// Returns the LEI of the parent (end node) of a relationship.
+fn parent_of(relationship: &RelationshipRecord) -> &str {
+    &relationship.attributes.relationship.end_node.id
+}
+
+/// Builder walking the hierarchy around an LEI into a [`HierarchyGraph`].
+///
+/// Obtained with [`GleifClient::hierarchy`].
+#[derive(Clone, Debug)]
+pub struct HierarchyBuilder {
+    client: GleifClient,
+    lei: String,
+    max_depth: usize,
+    max_concurrency: usize,
+    page_size: usize,
+}
+
+impl GleifClient {
+    /// Returns a builder walking the corporate hierarchy around the given LEI.
+    ///
+    /// # Parameters
+    ///
+    /// - `lei` - A string slice representing the LEI identifier to start from.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let graph = client.hierarchy("5493001KJTIIGC8Y1R12").max_depth(3).build().await?;
+    /// ```
+    #[must_use]
+    pub fn hierarchy(&self, lei: &str) -> HierarchyBuilder {
+        HierarchyBuilder {
+            client: self.clone(),
+            lei: lei.to_string(),
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            page_size: DEFAULT_PAGE_SIZE,
+        }
+    }
+}
+
+impl HierarchyBuilder {
+    /// Set the maximum number of levels walked up from the start and down from the top (default 10).
+    #[must_use]
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Set the maximum number of requests in flight at once (default 4, at least 1).
+    #[must_use]
+    pub fn max_concurrency(mut self, concurrency: usize) -> Self {
+        self.max_concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Set the page size used when fetching child lists (default 200).
+    #[must_use]
+    pub fn page_size(mut self, size: usize) -> Self {
+        self.page_size = size;
+        self
+    }
+
+    /// Walk the hierarchy and build the graph.
+    ///
+    /// If the start entity reports an ultimate parent, that entity is added as a node and walked down from,
+    /// but no edge is added for the ultimate parent relationship itself, as the graph holds direct
+    /// relationships only.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`crate::error::GleifError`] if any request fails or a response cannot be deserialized.
+    pub async fn build(self) -> Result<HierarchyGraph> {
+        let mut graph = HierarchyGraph::new(&self.lei);
+        let start: GleifApiResponse<LeiRecord> = self.client.lei_record_by_id(&self.lei).await?;
+        let reports_ultimate_parent = start
+            .data
+            .relationships
+            .ultimate_parent
+            .as_ref()
+            .is_some_and(|link| link.links.lei_record.is_some());
+        graph.insert_node(start.data);
+
+        let mut tops = vec![self.walk_up(&mut graph).await?];
+        if reports_ultimate_parent {
+            // The ultimate parent relationship skips levels, so only the node is added here.
+            let ultimate: GleifApiResponse<LeiRecord> =
+                self.client.ultimate_parent(&self.lei).await?;
+            tops.push(ultimate.data.attributes.lei.clone());
+            graph.insert_node(ultimate.data);
+        }
+        self.walk_down(&mut graph, tops).await?;
+        self.attach_reporting_exceptions(&mut graph).await?;
+        graph.update_depths();
+        Ok(graph)
+    }
+
+    /// Follows the direct parents of the start entity, returning the LEI of the topmost entity reached.
+    async fn walk_up(&self, graph: &mut HierarchyGraph) -> Result<String> {
+        let mut current = self.lei.clone();
+        for _ in 0..self.max_depth {
+            if !graph.node(&current).is_some_and(reports_direct_parent) {
+                return Ok(current);
+            }
+            let relationship: GleifApiResponse<RelationshipRecord> =
+                self.client.direct_parent_relationship(&current).await?;
+            let parent = parent_of(&relationship.data).to_string();
+            if !graph.contains(&parent) {
+                let record: GleifApiResponse<LeiRecord> =
+                    self.client.lei_record_by_id(&parent).await?;
+                graph.insert_node(record.data);
+            }
+            if !graph.insert_edge(relationship.data) {
+                return Ok(current);
+            }
+            current = parent;
+        }
+        graph.truncated |= graph.node(&current).is_some_and(reports_direct_parent);
+        Ok(current)
+    }
+
+    /// Walks the direct children of the given entities level by level, up to the depth limit.
+    async fn walk_down(&self, graph: &mut HierarchyGraph, tops: Vec<String>) -> Result<()> {
+        let mut expanded = HashSet::new();
+        let mut frontier = tops;
+        for level in 0..=self.max_depth {
+            frontier.retain(|lei| {
+                expanded.insert(lei.clone()) && graph.node(lei).is_some_and(reports_direct_children)
+            });
+            if frontier.is_empty() {
+                break;
+            }
+            if level == self.max_depth {
+                graph.truncated = true;
+                break;
+            }
+            let levels: Vec<_> = stream::iter(frontier.drain(..))
+                .map(|lei| self.children(lei))
+                .buffer_unordered(self.max_concurrency)
+                .collect()
+                .await;
+            for result in levels {
+                let (records, relationships) = result?;
+                for record in records {
+                    graph.insert_node(record);
+                }
+                for relationship in relationships {
+                    let child = child_of(&relationship).to_string();
+                    if graph.insert_edge(relationship) && graph.contains(&child) {
+                        frontier.push(child);
+                    }
+                }
+            }
+            frontier.sort();
+        }
+        Ok(())
+    }
+
+    /// Fetches all direct children of an entity and the relationships linking them to it.
+    async fn children(&self, lei: String) -> Result<(Vec<LeiRecord>, Vec<RelationshipRecord>)> {
+        future::try_join(
+            self.client
+                .direct_children(&lei)
+                .page_size(self.page_size)
+                .send_all(),
+            self.client
+                .direct_child_relationships(&lei)
+                .page_size(self.page_size)
+                .send_all(),
+        )
+        .await
+    }
+
+    /// Fetches the reporting exceptions of the entities without a parent in the graph.
+    async fn attach_reporting_exceptions(&self, graph: &mut HierarchyGraph) -> Result<()> {
+        let requests: Vec<(String, bool)> = graph
+            .roots()
+            .filter_map(|lei| graph.node(lei))
+            .flat_map(|node| {
+                let links = &node.record.relationships;
+                let direct = links.direct_parent.as_ref();
+                let ultimate = links.ultimate_parent.as_ref();
+                [(direct, false), (ultimate, true)]
+                    .into_iter()
+                    .filter(|(link, _)| {
+                        link.is_some_and(|link| link.links.reporting_exception.is_some())
+                    })
+                    .map(|(_, ultimate)| (node.lei().to_string(), ultimate))
+            })
+            .collect();
+        let exceptions: Vec<Result<GleifApiResponse<ReportingException>>> = stream::iter(requests)
+            .map(|(lei, ultimate)| async move {
+                if ultimate {
+                    self.client.ultimate_parent_reporting_exception(&lei).await
+                } else {
+                    self.client.direct_parent_reporting_exception(&lei).await
+                }
+            })
+            .buffered(self.max_concurrency)
+            .collect()
+            .await;
+        for exception in exceptions {
+            graph.insert_reporting_exception(exception?.data);
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if the record links to a reported direct parent relationship.
+fn reports_direct_parent(node: &HierarchyNode) -> bool {
+    node.record
+        .relationships
+        .direct_parent
+        .as_ref()
+        .is_some_and(|link| link.links.relationship_record.is_some())
+}
+
+/// Returns `true` if the record links to direct children.
+fn reports_direct_children(node: &HierarchyNode) -> bool {
+    node.record.relationships.direct_children.is_some()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::test_utils::{mock_client, read};
+    use std::fs;
+
+    pub(crate) const LEI: &str = "529900GRZ2BQY5ZM9N49";
+
+    /// An entity without direct children in the sample data, whose child lists are empty.
+    const CHILDLESS: &str = "5493001KJTIIGC8Y1R12";
+
+    /// Returns a client answering the given paths with sample files, and child list requests for any other
+    /// entity with empty lists.
+    async fn sample_client(routes: &[(&str, &str)]) -> GleifClient {
+        let routes: HashMap<String, String> = routes
+            .iter()
+            .map(|(path, file)| (path.to_string(), format!("tests/data/{file}")))
+            .collect();
+        mock_client(move |target| {
+            let path = target.split('?').next().unwrap_or_default();
+            let file = routes.get(path).cloned().or_else(|| {
+                if path.ends_with("/direct-children") {
+                    Some(format!(
+                        "tests/data/lei_records/direct-children_{CHILDLESS}.json"
+                    ))
+                } else if path.ends_with("/direct-child-relationships") {
+                    Some(format!(
+                        "tests/data/relationships/direct-child-relationships_{CHILDLESS}.json"
+                    ))
+                } else {
+                    None
+                }
+            })?;
+            fs::read_to_string(file).ok()
+        })
+        .await
+    }
+
+    /// Builds the graph of an entity and its direct children from the sample data.
+    pub(crate) fn sample_graph() -> HierarchyGraph {
+        let mut graph = HierarchyGraph::new(LEI);
+        graph.insert_node(read(&format!(
+            "tests/data/lei_records/single_lei_record_{LEI}.json"
+        )));
+        let children: Vec<LeiRecord> = read(&format!(
+            "tests/data/lei_records/direct-children_{LEI}.json"
+        ));
+        for child in children {
+            graph.insert_node(child);
+        }
+        let relationships: Vec<RelationshipRecord> = read(&format!(
+            "tests/data/relationships/direct-child-relationships_{LEI}.json"
+        ));
+        for relationship in relationships {
+            assert!(graph.insert_edge(relationship));
+        }
+        graph.update_depths();
+        graph
+    }
+
+    #[test]
+    fn test_graph_from_sample_data() {
+        let graph = sample_graph();
+        assert_eq!(graph.start(), LEI);
+        assert_eq!(graph.roots().collect::<Vec<_>>(), vec![LEI]);
+        assert_eq!(graph.children(LEI).count(), graph.len() - 1);
+        for node in graph.nodes() {
+            let expected = usize::from(node.lei() != LEI);
+            assert_eq!(node.depth, expected, "Unexpected depth of {}", node.lei());
+        }
+        assert!(graph.cycles().is_empty());
+        assert!(!graph.is_truncated());
+    }
+
+    #[test]
+    fn test_insert_edge_detects_cycles() {
+        let mut graph = sample_graph();
+        let mut reversed = graph.edges()[0].clone();
+        let child = child_of(&reversed).to_string();
+        reversed.id = format!("{}-reversed", reversed.id);
+        reversed.attributes.relationship.start_node.id = LEI.to_string();
+        reversed.attributes.relationship.end_node.id = child;
+        assert!(!graph.insert_edge(reversed));
+        assert_eq!(graph.cycles().len(), 1);
+        assert_eq!(graph.parents(LEI).count(), 0);
+
+        // Duplicate relationships are ignored.
+        let duplicate = graph.edges()[0].clone();
+        assert!(!graph.insert_edge(duplicate));
+    }
+
+    #[test]
+    fn test_reporting_exceptions_are_attached() {
+        let mut graph = sample_graph();
+        graph.insert_reporting_exception(read(&format!(
+            "tests/data/reporting_exceptions/direct-parent-reporting-exception_{LEI}.json"
+        )));
+        assert_eq!(graph.node(LEI).unwrap().reporting_exceptions.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_build_walks_down_children() {
+        let client = sample_client(&[
+            (
+                &format!("lei-records/{LEI}"),
+                &format!("lei_records/single_lei_record_{LEI}.json"),
+            ),
+            (
+                &format!("lei-records/{LEI}/direct-children"),
+                &format!("lei_records/direct-children_{LEI}.json"),
+            ),
+            (
+                &format!("lei-records/{LEI}/direct-child-relationships"),
+                &format!("relationships/direct-child-relationships_{LEI}.json"),
+            ),
+            (
+                &format!("lei-records/{LEI}/direct-parent-reporting-exception"),
+                &format!("reporting_exceptions/direct-parent-reporting-exception_{LEI}.json"),
+            ),
+            (
+                &format!("lei-records/{LEI}/ultimate-parent-reporting-exception"),
+                &format!("reporting_exceptions/ultimate-parent-reporting-exception_{LEI}.json"),
+            ),
+        ])
+        .await;
+        let graph = client.hierarchy(LEI).page_size(15).build().await.unwrap();
+        let expected = sample_graph();
+        assert_eq!(graph.len(), expected.len());
+        assert_eq!(graph.edges().len(), expected.edges().len());
+        assert_eq!(graph.roots().collect::<Vec<_>>(), vec![LEI]);
+        for node in expected.nodes() {
+            assert_eq!(graph.node(node.lei()).unwrap().depth, node.depth);
+        }
+        assert_eq!(graph.node(LEI).unwrap().reporting_exceptions.len(), 2);
+        assert!(!graph.is_truncated());
+    }
+
+    #[tokio::test]
+    async fn test_build_walks_up_to_parents() {
+        let parent = "549300B56MD0ZC402L06";
+        let ultimate = "549300RMUDWPHCUQNE66";
+        let client = sample_client(&[
+            (
+                &format!("lei-records/{CHILDLESS}"),
+                &format!("lei_records/single_lei_record_{CHILDLESS}.json"),
+            ),
+            (
+                &format!("lei-records/{CHILDLESS}/direct-parent-relationship"),
+                &format!("relationships/direct-parent-relationship_{CHILDLESS}.json"),
+            ),
+            (
+                &format!("lei-records/{parent}"),
+                &format!("lei_records/direct-parent_{CHILDLESS}.json"),
+            ),
+            (
+                &format!("lei-records/{CHILDLESS}/ultimate-parent"),
+                &format!("lei_records/ultimate-parent_{CHILDLESS}.json"),
+            ),
+        ])
+        .await;
+        // The direct parent reports a parent of its own, which is beyond the depth limit.
+        let graph = client
+            .hierarchy(CHILDLESS)
+            .max_depth(1)
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.edges().len(), 1);
+        assert_eq!(
+            graph.parents(CHILDLESS).map(parent_of).collect::<Vec<_>>(),
+            vec![parent]
+        );
+        assert_eq!(
+            graph.children(parent).map(child_of).collect::<Vec<_>>(),
+            vec![CHILDLESS]
+        );
+        assert_eq!(graph.roots().collect::<Vec<_>>(), vec![parent, ultimate]);
+        assert_eq!(graph.node(CHILDLESS).unwrap().depth, 1);
+        // The ultimate parent is a node without an edge.
+        assert_eq!(graph.children(ultimate).count(), 0);
+        assert!(graph.is_truncated());
+    }
+
+    #[tokio::test]
+    async fn test_build_stops_walking_down_at_depth_limit() {
+        let client = sample_client(&[
+            (
+                &format!("lei-records/{LEI}"),
+                &format!("lei_records/single_lei_record_{LEI}.json"),
+            ),
+            (
+                &format!("lei-records/{LEI}/direct-parent-reporting-exception"),
+                &format!("reporting_exceptions/direct-parent-reporting-exception_{LEI}.json"),
+            ),
+            (
+                &format!("lei-records/{LEI}/ultimate-parent-reporting-exception"),
+                &format!("reporting_exceptions/ultimate-parent-reporting-exception_{LEI}.json"),
+            ),
+        ])
+        .await;
+        let graph = client.hierarchy(LEI).max_depth(0).build().await.unwrap();
+        assert_eq!(graph.len(), 1);
+        assert!(graph.edges().is_empty());
+        assert!(graph.is_truncated());
+    }
+}
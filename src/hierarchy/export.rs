@@ -0,0 +1,277 @@
+//! Exporters rendering a [`HierarchyGraph`] to Graphviz DOT and to a nested JSON tree.
+
+use super::{HierarchyGraph, HierarchyNode, child_of, parent_of};
+use crate::{
+    field::Field,
+    model::{
+        enums::{RegistrationStatus, RelationshipStatus},
+        relationship_record::RelationshipRecord,
+    },
+};
+use serde_json::{Value, json};
+use std::fmt::Write;
+
+/// Options controlling which entities and relationships are exported.
+///
+/// By default everything in the graph is exported.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExportOptions {
+    hide_inactive_relationships: bool,
+    hide_lapsed_registrations: bool,
+}
+
+impl ExportOptions {
+    /// Creates options exporting the whole graph.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hide relationships whose status is [`RelationshipStatus::Inactive`].
+    #[must_use]
+    pub const fn hide_inactive_relationships(mut self, hide: bool) -> Self {
+        self.hide_inactive_relationships = hide;
+        self
+    }
+
+    /// Hide entities and relationships whose registration status is [`RegistrationStatus::Lapsed`].
+    ///
+    /// The children of a hidden entity are exported as top-level entities.
+    #[must_use]
+    pub const fn hide_lapsed_registrations(mut self, hide: bool) -> Self {
+        self.hide_lapsed_registrations = hide;
+        self
+    }
+
+    /// Returns `true` if the entity is exported.
+    fn shows_node(self, node: &HierarchyNode) -> bool {
+        !(self.hide_lapsed_registrations
+            && node.record.attributes.registration.status == RegistrationStatus::Lapsed)
+    }
+
+    /// Returns `true` if the relationship and both of its entities are exported.
+    fn shows_edge(self, graph: &HierarchyGraph, edge: &RelationshipRecord) -> bool {
+        let relationship = &edge.attributes;
+        let hidden = (self.hide_inactive_relationships
+            && relationship.relationship.status == RelationshipStatus::Inactive)
+            || (self.hide_lapsed_registrations
+                && relationship.registration.status == RegistrationStatus::Lapsed);
+        !hidden
+            && [child_of(edge), parent_of(edge)]
+                .into_iter()
+                .all(|lei| graph.node(lei).is_some_and(|node| self.shows_node(node)))
+    }
+}
+
+/// Returns the string form of a field of a relationship, or an empty string.
+fn relationship_value(edge: &RelationshipRecord, field: Field) -> String {
+    edge.get(field)
+        .map(|value| value.to_string())
+        .unwrap_or_default()
+}
+
+/// Returns the string form of a field of an entity, or an empty string.
+fn node_value(node: &HierarchyNode, field: Field) -> String {
+    node.record
+        .get(field)
+        .map(|value| value.to_string())
+        .unwrap_or_default()
+}
+
+/// Escapes a string for use inside a double-quoted DOT identifier or label.
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+impl HierarchyGraph {
+    /// Renders the graph in the Graphviz DOT language.
+    ///
+    /// Entities are labeled with their legal name and LEI, relationships with their type and status.
+    /// Edges point from child to parent, and parents are drawn above their children.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let dot = graph.to_dot(ExportOptions::new().hide_inactive_relationships(true));
+    /// std::fs::write("group.dot", dot)?;
+    /// ```
+    #[must_use]
+    pub fn to_dot(&self, options: ExportOptions) -> String {
+        let mut dot = String::from("digraph hierarchy {\n    rankdir=BT;\n    node [shape=box];\n");
+        for node in self.nodes().filter(|node| options.shows_node(node)) {
+            let label = format!(
+                "{}\n{}",
+                node.record.attributes.entity.legal_name.name,
+                node.lei()
+            );
+            let _ = writeln!(
+                dot,
+                "    \"{}\" [label=\"{}\"];",
+                escape_dot(node.lei()),
+                escape_dot(&label)
+            );
+        }
+        for edge in self
+            .edges()
+            .iter()
+            .filter(|edge| options.shows_edge(self, edge))
+        {
+            let label = format!(
+                "{}\n{}",
+                relationship_value(edge, Field::RelationshipType),
+                relationship_value(edge, Field::RelationshipStatus)
+            );
+            let _ = writeln!(
+                dot,
+                "    \"{}\" -> \"{}\" [label=\"{}\"];",
+                escape_dot(child_of(edge)),
+                escape_dot(parent_of(edge)),
+                escape_dot(&label)
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders the graph as a nested JSON tree.
+    ///
+    /// Returns an array with one object per top-level entity. Each object carries the `lei`,
+    /// `legalName`, `entityStatus` and `registrationStatus` of the entity, the `relationship` (type and
+    /// status) linking it to its parent (`null` at the top level), its `reportingExceptions` (category
+    /// and reason) and its `children`, recursively.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let tree = graph.to_json_tree(ExportOptions::new().hide_lapsed_registrations(true));
+    /// println!("{tree:#}");
+    /// ```
+    #[must_use]
+    pub fn to_json_tree(&self, options: ExportOptions) -> Value {
+        let roots = self
+            .nodes()
+            .filter(|node| options.shows_node(node))
+            .filter(|node| {
+                !self
+                    .parents(node.lei())
+                    .any(|edge| options.shows_edge(self, edge))
+            })
+            .map(|node| self.json_node(node, None, options))
+            .collect();
+        Value::Array(roots)
+    }
+
+    /// Renders an entity and its exported descendants as a JSON object.
+    fn json_node(
+        &self,
+        node: &HierarchyNode,
+        edge: Option<&RelationshipRecord>,
+        options: ExportOptions,
+    ) -> Value {
+        let children: Vec<Value> = self
+            .children(node.lei())
+            .filter(|edge| options.shows_edge(self, edge))
+            .filter_map(|edge| {
+                self.node(child_of(edge))
+                    .map(|child| self.json_node(child, Some(edge), options))
+            })
+            .collect();
+        let relationship = edge.map(|edge| {
+            json!({
+                "type": relationship_value(edge, Field::RelationshipType),
+                "status": relationship_value(edge, Field::RelationshipStatus),
+            })
+        });
+        let exceptions: Vec<Value> = node
+            .reporting_exceptions
+            .iter()
+            .map(|exception| {
+                json!({
+                    "category": exception.attributes.category,
+                    "reason": exception.attributes.reason,
+                })
+            })
+            .collect();
+        json!({
+            "lei": node.lei(),
+            "legalName": node.record.attributes.entity.legal_name.name,
+            "entityStatus": node_value(node, Field::EntityStatus),
+            "registrationStatus": node_value(node, Field::RegistrationStatus),
+            "relationship": relationship,
+            "reportingExceptions": exceptions,
+            "children": children,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hierarchy::tests::{LEI, sample_graph};
+
+    #[test]
+    fn test_to_dot() {
+        let graph = sample_graph();
+        let dot = graph.to_dot(ExportOptions::new());
+        assert!(dot.starts_with("digraph hierarchy {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert_eq!(dot.matches(" -> ").count(), graph.edges().len());
+        assert!(dot.contains(&format!("-> \"{LEI}\"")));
+        assert!(dot.contains("IS_DIRECTLY_CONSOLIDATED_BY\\n"));
+        assert_eq!(escape_dot("A \"B\"\nC"), "A \\\"B\\\"\\nC");
+    }
+
+    #[test]
+    fn test_to_json_tree() {
+        let graph = sample_graph();
+        let tree = graph.to_json_tree(ExportOptions::new());
+        let roots = tree.as_array().unwrap();
+        assert_eq!(roots.len(), 1);
+        assert_eq!(roots[0]["lei"], LEI);
+        assert!(roots[0]["relationship"].is_null());
+        let children = roots[0]["children"].as_array().unwrap();
+        assert_eq!(children.len(), graph.edges().len());
+        assert_eq!(
+            children[0]["relationship"]["type"],
+            "IS_DIRECTLY_CONSOLIDATED_BY"
+        );
+    }
+
+    #[test]
+    fn test_export_options() {
+        let mut graph = sample_graph();
+        let inactive = graph.edges().len();
+        for edge in &mut graph.edges {
+            edge.attributes.relationship.status = RelationshipStatus::Inactive;
+        }
+        let options = ExportOptions::new().hide_inactive_relationships(true);
+        assert_eq!(graph.to_dot(options).matches(" -> ").count(), 0);
+        // Without visible relationships, every entity is exported at the top level.
+        assert_eq!(
+            graph.to_json_tree(options).as_array().unwrap().len(),
+            inactive + 1
+        );
+
+        let mut graph = sample_graph();
+        graph
+            .nodes
+            .get_mut(LEI)
+            .unwrap()
+            .record
+            .attributes
+            .registration
+            .status = RegistrationStatus::Lapsed;
+        let options = ExportOptions::new().hide_lapsed_registrations(true);
+        assert!(!graph.to_dot(options).contains(&format!("\"{LEI}\" [")));
+        assert!(
+            graph
+                .to_json_tree(options)
+                .as_array()
+                .unwrap()
+                .iter()
+                .all(|root| root["lei"] != LEI)
+        );
+    }
+}
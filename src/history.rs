@@ -0,0 +1,507 @@
+//! # Record History ([`RecordHistory`]) - Point-in-Time Reconstruction of LEI Records
+//!
+//! The GLEIF API only serves the current state of an LEI record, but the
+//! [`field_modifications`](crate::client::GleifClient::field_modifications) endpoint reports every
+//! change with its date and its old and new values. This module replays those modifications backwards
+//! from the current record to reconstruct the record as it was at a given date.
+//!
+//! ## Key Features
+//!
+//! - **Point-in-Time Records:** [`RecordHistory::as_of`] (or [`LeiRecord::as_of`]) returns the record as
+//!   it stood at the end of a given instant.
+//! - **Transparent Gaps:** Modifications that cannot be reverted on the [`LeiRecord`] model, such as changes
+//!   to repeated elements like legal entity events, are returned alongside the record in
+//!   [`HistoricalRecord::unapplied`] instead of being silently dropped.
+//! - **Registration Awareness:** [`HistoricalRecord::is_registered`] tells whether the LEI had been issued
+//!   at the requested date.
+//!
+//! Modifications are identified by the `LEI-CDF` `XPath` of the modified element. Entity names, addresses,
+//! jurisdiction, category, legal form, status, creation date and registration authority, as well as all
+//! registration dates, statuses and validation details are supported.
+//!
+//! ## Example
+//!
+//! ```rust, ignore
+//! use chrono::{TimeZone, Utc};
+//! use gleif_rs::{client::GleifClient, history::RecordHistory, model::LeiRecord};
+//!
+//! let client = GleifClient::new();
+//! let record: LeiRecord = client.lei_record_by_id("529900GRZ2BQY5ZM9N49").await?;
+//! let modifications = client
+//!     .field_modifications("529900GRZ2BQY5ZM9N49")
+//!     .page_size(200)
+//!     .send_all()
+//!     .await?;
+//! let history = RecordHistory::new(record.data, modifications);
+//! let past = history.as_of(Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap());
+//! println!("Status on 2023-01-01: {:?}", past.record.attributes.registration.status);
+//! ```
+
+use crate::{
+    error::{GleifError, ParseErrorKind, Result},
+    model::{
+        field_modification::FieldModification,
+        lei_record::{Address, Entity, LeiRecord, Registration},
+    },
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::de::DeserializeOwned;
+
+/// Record type of field modifications applying to LEI records.
+const LEI_RECORD_TYPE: &str = "LEI";
+
+/// Modification type of the values recorded when an LEI is first registered.
+const INITIAL_MODIFICATION: &str = "INITIAL";
+
+/// `XPath` prefix of the elements of an LEI record.
+const LEI_RECORD_XPATH: &str = "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/";
+
+/// The current state of an LEI record together with the modifications that led to it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordHistory {
+    current: LeiRecord,
+    modifications: Vec<FieldModification>,
+}
+
+impl RecordHistory {
+    /// Creates the history of a record from its current state and its field modifications.
+    ///
+    /// Modifications of other LEIs and of relationship or reporting exception records are ignored. The
+    /// remaining modifications are kept in chronological order.
+    #[must_use]
+    pub fn new<I>(current: LeiRecord, modifications: I) -> Self
+    where
+        I: IntoIterator<Item = FieldModification>,
+    {
+        let mut modifications: Vec<FieldModification> = modifications
+            .into_iter()
+            .filter(|modification| {
+                modification.attributes.lei == current.attributes.lei
+                    && modification.attributes.record_type == LEI_RECORD_TYPE
+            })
+            .collect();
+        modifications.sort_by_key(|modification| modification.attributes.date);
+        Self {
+            current,
+            modifications,
+        }
+    }
+
+    /// Returns the current state of the record.
+    #[must_use]
+    pub fn current(&self) -> &LeiRecord {
+        &self.current
+    }
+
+    /// Returns the modifications of the record, oldest first.
+    #[must_use]
+    pub fn modifications(&self) -> &[FieldModification] {
+        &self.modifications
+    }
+
+    /// Reconstructs the record as it was at the given date.
+    ///
+    /// Modifications dated after `date` are reverted, newest first, by restoring their old value.
+    /// Modifications dated exactly at `date` are considered to have taken effect.
+    #[must_use]
+    pub fn as_of(&self, date: DateTime<Utc>) -> HistoricalRecord {
+        let mut record = self.current.clone();
+        let mut unapplied = Vec::new();
+        for modification in self
+            .modifications
+            .iter()
+            .rev()
+            .take_while(|modification| modification.attributes.date > date)
+        {
+            let attributes = &modification.attributes;
+            if attributes.modification_type == INITIAL_MODIFICATION {
+                continue;
+            }
+            let reverted = attributes
+                .field
+                .strip_prefix(LEI_RECORD_XPATH)
+                .is_some_and(|path| {
+                    revert(
+                        &mut record,
+                        path,
+                        attributes.value_old.as_deref(),
+                        &attributes.value_new,
+                    )
+                });
+            if !reverted {
+                unapplied.push(modification.clone());
+            }
+        }
+        HistoricalRecord {
+            record,
+            date,
+            unapplied,
+        }
+    }
+}
+
+/// An LEI record reconstructed at a past date.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoricalRecord {
+    /// The reconstructed record.
+    pub record: LeiRecord,
+    /// The date the record was reconstructed at.
+    pub date: DateTime<Utc>,
+    /// Modifications made after the date that could not be reverted, newest first.
+    pub unapplied: Vec<FieldModification>,
+}
+
+impl HistoricalRecord {
+    /// Returns `true` if every modification made after the date was reverted.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.unapplied.is_empty()
+    }
+
+    /// Returns `true` if the LEI had been registered at the date.
+    #[must_use]
+    pub fn is_registered(&self) -> bool {
+        self.record
+            .attributes
+            .registration
+            .initial_registration_date
+            <= self.date
+    }
+}
+
+impl LeiRecord {
+    /// Reconstructs this record as it was at the given date from its field modifications.
+    ///
+    /// See [`RecordHistory::as_of`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let past = record.as_of(Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(), &modifications);
+    /// assert!(past.is_complete());
+    /// ```
+    #[must_use]
+    pub fn as_of(
+        &self,
+        date: DateTime<Utc>,
+        modifications: &[FieldModification],
+    ) -> HistoricalRecord {
+        RecordHistory::new(self.clone(), modifications.iter().cloned()).as_of(date)
+    }
+}
+
+/// Restores the old value of the element at `path` (relative to the LEI record), returning `true` on
+/// success.
+fn revert(record: &mut LeiRecord, path: &str, old: Option<&str>, new: &str) -> bool {
+    let attributes = &mut record.attributes;
+    if let Some(path) = path.strip_prefix("lei:Entity/") {
+        revert_entity(&mut attributes.entity, path, old, new)
+    } else if let Some(path) = path.strip_prefix("lei:Registration/") {
+        revert_registration(&mut attributes.registration, path, old)
+    } else {
+        false
+    }
+}
+
+/// Restores an element of the entity data.
+fn revert_entity(entity: &mut Entity, path: &str, old: Option<&str>, new: &str) -> bool {
+    if let Some(path) = path.strip_prefix("lei:LegalAddress/") {
+        return revert_address(&mut entity.legal_address, path, old, new);
+    }
+    if let Some(path) = path.strip_prefix("lei:HeadquartersAddress/") {
+        return revert_address(&mut entity.headquarters_address, path, old, new);
+    }
+    match path {
+        "lei:LegalName" => set(&mut entity.legal_name.name, old),
+        "lei:LegalName/@xml:lang" => set_optional(&mut entity.legal_name.language, old),
+        "lei:LegalJurisdiction" => set(&mut entity.jurisdiction, old),
+        "lei:EntityCategory" => set_parsed(&mut entity.category, old, parse_code),
+        "lei:EntitySubCategory" => set_optional_parsed(&mut entity.sub_category, old, parse_code),
+        "lei:LegalForm/lei:EntityLegalFormCode" => set(&mut entity.legal_form.id, old),
+        "lei:LegalForm/lei:OtherLegalForm" => set_optional(&mut entity.legal_form.other, old),
+        "lei:EntityStatus" => set_parsed(&mut entity.status, old, parse_code),
+        "lei:EntityCreationDate" => set_optional_parsed(&mut entity.creation_date, old, parse_date),
+        "lei:RegistrationAuthority/lei:RegistrationAuthorityID" => {
+            set(&mut entity.registered_at.id, old)
+        }
+        "lei:RegistrationAuthority/lei:OtherRegistrationAuthorityID" => {
+            set_optional(&mut entity.registered_at.other, old)
+        }
+        "lei:RegistrationAuthority/lei:RegistrationAuthorityEntityID" => {
+            set_optional(&mut entity.registered_as, old)
+        }
+        _ => false,
+    }
+}
+
+/// Restores an element of an address.
+///
+/// The first address line is the first entry of [`Address::address_lines`]; additional address lines are
+/// identified by their new value among the remaining entries.
+fn revert_address(address: &mut Address, path: &str, old: Option<&str>, new: &str) -> bool {
+    match path {
+        "lei:FirstAddressLine" => match (address.address_lines.first_mut(), old) {
+            (Some(line), Some(old)) => {
+                *line = old.to_string();
+                true
+            }
+            _ => false,
+        },
+        "lei:AdditionalAddressLine" => {
+            let Some(index) = address
+                .address_lines
+                .iter()
+                .skip(1)
+                .position(|line| line == new)
+            else {
+                return false;
+            };
+            match old {
+                Some(old) => address.address_lines[index + 1] = old.to_string(),
+                None => {
+                    address.address_lines.remove(index + 1);
+                }
+            }
+            true
+        }
+        "@xml:lang" => set_optional(&mut address.language, old),
+        "lei:AddressNumber" => set_optional(&mut address.address_number, old),
+        "lei:AddressNumberWithinBuilding" => {
+            set_optional(&mut address.address_number_within_building, old)
+        }
+        "lei:MailRouting" => set_optional(&mut address.mail_routing, old),
+        "lei:City" => set(&mut address.city, old),
+        "lei:Region" => set_optional(&mut address.region, old),
+        "lei:Country" => set(&mut address.country, old),
+        "lei:PostalCode" => set_optional(&mut address.postal_code, old),
+        _ => false,
+    }
+}
+
+/// Restores an element of the registration data.
+fn revert_registration(registration: &mut Registration, path: &str, old: Option<&str>) -> bool {
+    match path {
+        "lei:InitialRegistrationDate" => {
+            set_parsed(&mut registration.initial_registration_date, old, parse_date)
+        }
+        "lei:LastUpdateDate" => set_parsed(&mut registration.last_update_date, old, parse_date),
+        "lei:RegistrationStatus" => set_parsed(&mut registration.status, old, parse_code),
+        "lei:NextRenewalDate" => set_parsed(&mut registration.next_renewal_date, old, parse_date),
+        "lei:ManagingLOU" => set(&mut registration.managing_lou, old),
+        "lei:ValidationSources" => {
+            set_parsed(&mut registration.corroboration_level, old, parse_code)
+        }
+        "lei:ValidationAuthority/lei:ValidationAuthorityID" => {
+            set(&mut registration.validated_at.id, old)
+        }
+        "lei:ValidationAuthority/lei:OtherValidationAuthorityID" => {
+            set_optional(&mut registration.validated_at.other, old)
+        }
+        "lei:ValidationAuthority/lei:ValidationAuthorityEntityID" => {
+            set_optional(&mut registration.validated_as, old)
+        }
+        _ => false,
+    }
+}
+
+/// Restores a mandatory text element, which cannot be reverted to "absent".
+fn set(target: &mut String, old: Option<&str>) -> bool {
+    set_parsed(target, old, |value| Ok(value.to_string()))
+}
+
+/// Restores an optional text element.
+fn set_optional(target: &mut Option<String>, old: Option<&str>) -> bool {
+    *target = old.map(str::to_string);
+    true
+}
+
+/// Restores a mandatory element from its `LEI-CDF` string form.
+fn set_parsed<T>(target: &mut T, old: Option<&str>, parse: fn(&str) -> Result<T>) -> bool {
+    match old.and_then(|old| parse(old).ok()) {
+        Some(value) => {
+            *target = value;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Restores an optional element from its `LEI-CDF` string form.
+fn set_optional_parsed<T>(
+    target: &mut Option<T>,
+    old: Option<&str>,
+    parse: fn(&str) -> Result<T>,
+) -> bool {
+    match old.map(parse).transpose() {
+        Ok(value) => {
+            *target = value;
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+/// Parses an `LEI-CDF` date or date-time (`xs:dateTime` or plain `xs:date`), normalized to UTC.
+pub(crate) fn parse_date(value: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|date| date.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|date| date.and_utc())
+        })
+        .ok_or_else(|| GleifError::ParseError {
+            kind: ParseErrorKind::Value,
+            message: format!("invalid date `{value}`"),
+        })
+}
+
+/// Parses an `LEI-CDF` code into one of the model enums.
+pub(crate) fn parse_code<T: DeserializeOwned>(value: &str) -> Result<T> {
+    serde_json::from_value(serde_json::Value::String(value.to_string())).map_err(|_| {
+        GleifError::ParseError {
+            kind: ParseErrorKind::Value,
+            message: format!("invalid code `{value}`"),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::enums::{CorroborationLevel, RegistrationStatus},
+        test_utils::read,
+    };
+    use chrono::TimeZone;
+
+    fn history(lei: &str) -> RecordHistory {
+        RecordHistory::new(
+            read(&format!(
+                "tests/data/lei_records/single_lei_record_{lei}.json"
+            )),
+            read::<Vec<FieldModification>>(&format!(
+                "tests/data/field_modifications/field_modifications_{lei}.json"
+            )),
+        )
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_current_state_is_unchanged() {
+        let history = history("529900GRZ2BQY5ZM9N49");
+        let now = history.as_of(date(2100, 1, 1));
+        assert_eq!(&now.record, history.current());
+        assert!(now.is_complete());
+        assert!(now.is_registered());
+        assert!(
+            history
+                .modifications()
+                .windows(2)
+                .all(|pair| pair[0].attributes.date <= pair[1].attributes.date)
+        );
+    }
+
+    #[test]
+    fn test_registration_dates_are_reverted() {
+        let history = history("529900GRZ2BQY5ZM9N49");
+        let past = history.as_of(date(2024, 1, 1));
+        let registration = &past.record.attributes.registration;
+        assert_eq!(
+            registration.next_renewal_date,
+            date(2024, 10, 22) + chrono::Duration::hours(22)
+        );
+        assert_eq!(
+            registration.last_update_date,
+            Utc.with_ymd_and_hms(2023, 9, 8, 10, 15, 42).unwrap()
+        );
+        assert_eq!(
+            past.record.attributes.entity.creation_date,
+            Some(date(2011, 4, 13) + chrono::Duration::hours(22))
+        );
+        assert!(past.is_complete());
+    }
+
+    #[test]
+    fn test_address_lines_are_reverted() {
+        let history = history("549300YX4S1LLSMK2627");
+        let past = history.as_of(date(2025, 1, 1));
+        let lines = &past.record.attributes.entity.legal_address.address_lines;
+        assert_eq!(
+            lines,
+            &[
+                "C/O THE CORPORATION TRUST COMPANY",
+                "CORPORATION TRUST CENTER",
+                "1209 ORANGE STREET"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enums_and_unsupported_fields() {
+        let record: LeiRecord =
+            read("tests/data/lei_records/single_lei_record_529900GRZ2BQY5ZM9N49.json");
+        let mut modifications: Vec<FieldModification> =
+            read("tests/data/field_modifications/field_modifications_529900GRZ2BQY5ZM9N49.json");
+        let template = modifications[0].clone();
+        let modification = |field: &str, old: Option<&str>, new: &str| {
+            let mut modification = template.clone();
+            modification.attributes.field = format!("{LEI_RECORD_XPATH}{field}");
+            modification.attributes.date = date(2030, 1, 1);
+            modification.attributes.value_old = old.map(str::to_string);
+            modification.attributes.value_new = new.to_string();
+            modification
+        };
+        modifications.push(modification(
+            "lei:Registration/lei:RegistrationStatus",
+            Some("LAPSED"),
+            "ISSUED",
+        ));
+        modifications.push(modification(
+            "lei:Registration/lei:ValidationSources",
+            Some("PARTIALLY_CORROBORATED"),
+            "FULLY_CORROBORATED",
+        ));
+        modifications.push(modification(
+            "lei:Entity/lei:LegalEntityEvents/lei:LegalEntityEvent/lei:LegalEntityEventType",
+            Some("CHANGE_LEGAL_NAME"),
+            "CHANGE_LEGAL_ADDRESS",
+        ));
+        modifications.push(modification("lei:Entity/lei:LegalName", None, "PUMA SE"));
+
+        let past = record.as_of(date(2029, 1, 1), &modifications);
+        let registration = &past.record.attributes.registration;
+        assert_eq!(registration.status, RegistrationStatus::Lapsed);
+        assert_eq!(
+            registration.corroboration_level,
+            CorroborationLevel::PartiallyCorroborated
+        );
+        assert_eq!(past.unapplied.len(), 2);
+        assert!(!past.is_complete());
+        assert!(
+            !record
+                .as_of(date(2000, 1, 1), &modifications)
+                .is_registered()
+        );
+    }
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(
+            parse_date("1952-10-17T00:00:00+01:00")
+                .unwrap()
+                .to_rfc3339(),
+            "1952-10-16T23:00:00+00:00"
+        );
+        assert_eq!(
+            parse_date("2020-01-02").unwrap().to_rfc3339(),
+            "2020-01-02T00:00:00+00:00"
+        );
+        assert!(parse_date("02.01.2020").is_err());
+    }
+}
@@ -0,0 +1,170 @@
+//! # LEI Code Utilities ([`lei`](crate::lei)) - ISO 17442 Structure and Check Digits
+//!
+//! This module provides helpers for working with Legal Entity Identifier (LEI) codes as defined by
+//! [ISO 17442](https://www.gleif.org/en/about-lei/iso-17442-the-lei-code-structure). An LEI is a
+//! 20-character, alpha-numeric code made up of:
+//!
+//! - Characters 1-4: the prefix of the issuing Local Operating Unit (LOU).
+//! - Characters 5-18: the entity-specific part assigned by the LOU.
+//! - Characters 19-20: two check digits computed with the ISO 7064 MOD 97-10 algorithm.
+//!
+//! ## Key Features
+//!
+//! - **Check Digit Computation:** Derive the two check digits for an 18-character LEI base via [`check_digits`],
+//!   or assemble a full code from its parts via [`from_parts`].
+//! - **Validation:** Verify the structure and check digits of a full LEI via [`validate`] or [`is_valid`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use gleif_rs::lei;
+//!
+//! // Bloomberg Finance L.P.
+//! assert!(lei::is_valid("5493001KJTIIGC8Y1R12"));
+//! assert_eq!(lei::check_digits("5493001KJTIIGC8Y1R").unwrap(), "12");
+//!
+//! // A single changed character invalidates the check digits
+//! assert!(!lei::is_valid("5493001KJTIIGC8Y1R13"));
+//! ```
+
+use crate::error::{GleifError, ParseErrorKind, Result};
+
+/// Total length of an LEI code.
+pub const LEI_LENGTH: usize = 20;
+
+/// Length of the LOU prefix at the start of an LEI code.
+pub const LOU_PREFIX_LENGTH: usize = 4;
+
+/// Length of the entity-specific part of an LEI code.
+pub const ENTITY_PART_LENGTH: usize = 14;
+
+/// Length of an LEI code without its two check digits.
+pub const BASE_LENGTH: usize = LOU_PREFIX_LENGTH + ENTITY_PART_LENGTH;
+
+/// Computes the two ISO 7064 MOD 97-10 check digits for an 18-character LEI base.
+///
+/// # Errors
+///
+/// Returns [`crate::error::GleifError::ParseError`] if `base` is not exactly 18 upper-case alpha-numeric characters.
+pub fn check_digits(base: &str) -> Result<String> {
+    if base.len() != BASE_LENGTH {
+        return Err(parse_error(format!(
+            "LEI base must be {BASE_LENGTH} characters, got {}",
+            base.len()
+        )));
+    }
+    // Appending "00" to the base before taking the remainder is equivalent to multiplying by 100.
+    let remainder = mod97(base)? * 100 % 97;
+    Ok(format!("{:02}", 98 - remainder))
+}
+
+/// Build a full LEI from an LOU prefix and an entity-specific part by appending the check digits.
+///
+/// # Errors
+///
+/// Returns [`crate::error::GleifError::ParseError`] if the prefix is not 4 characters, the entity part is not
+/// 14 characters, or either contains characters outside `0-9` and `A-Z`.
+pub fn from_parts(prefix: &str, entity_part: &str) -> Result<String> {
+    if prefix.len() != LOU_PREFIX_LENGTH || entity_part.len() != ENTITY_PART_LENGTH {
+        return Err(parse_error(format!(
+            "LEI parts must be {LOU_PREFIX_LENGTH} and {ENTITY_PART_LENGTH} characters long"
+        )));
+    }
+    let base = format!("{prefix}{entity_part}");
+    let check = check_digits(&base)?;
+    Ok(format!("{base}{check}"))
+}
+
+/// Validates the structure and check digits of an LEI code.
+///
+/// # Errors
+///
+/// Returns [`crate::error::GleifError::ParseError`] if `lei` is not 20 upper-case alpha-numeric characters,
+/// if the last two characters are not digits, or if the check digits do not match.
+pub fn validate(lei: &str) -> Result<()> {
+    if lei.len() != LEI_LENGTH {
+        return Err(parse_error(format!(
+            "LEI must be {LEI_LENGTH} characters, got {}",
+            lei.len()
+        )));
+    }
+    // Work on bytes: `len` counts bytes, so slicing the str could split a multi-byte character.
+    if !lei.as_bytes()[BASE_LENGTH..].iter().all(u8::is_ascii_digit) {
+        return Err(parse_error("LEI check digits must be numeric".to_string()));
+    }
+    if mod97(lei)? != 1 {
+        return Err(parse_error("LEI check digits do not match".to_string()));
+    }
+    Ok(())
+}
+
+/// Returns `true` if `lei` is a structurally valid LEI code with matching check digits.
+#[must_use]
+pub fn is_valid(lei: &str) -> bool {
+    validate(lei).is_ok()
+}
+
+/// Private helper computing the remainder modulo 97 of the numeric expansion of `input`,
+/// where digits map to themselves and letters `A`-`Z` map to `10`-`35`.
+fn mod97(input: &str) -> Result<u32> {
+    input.bytes().try_fold(0u32, |acc, b| match b {
+        b'0'..=b'9' => Ok((acc * 10 + u32::from(b - b'0')) % 97),
+        b'A'..=b'Z' => Ok((acc * 100 + u32::from(b - b'A') + 10) % 97),
+        _ => Err(parse_error(format!(
+            "LEI contains invalid character '{}'",
+            char::from(b)
+        ))),
+    })
+}
+
+/// Private helper to build a value parse error.
+fn parse_error(message: String) -> GleifError {
+    GleifError::ParseError {
+        kind: ParseErrorKind::Value,
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_digits_known_leis() {
+        for lei in [
+            "5493001KJTIIGC8Y1R12",
+            "851WYGNLUQLFZBSYGB56",
+            "529900GRZ2BQY5ZM9N49",
+            "INR2EJN1ERAN0W5ZP974",
+            "984500983AD71E4FBC41",
+        ] {
+            assert_eq!(
+                check_digits(&lei[..BASE_LENGTH]).unwrap(),
+                &lei[BASE_LENGTH..]
+            );
+            assert!(is_valid(lei), "{lei} should be valid");
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_input() {
+        assert!(validate("5493001KJTIIGC8Y1R13").is_err());
+        assert!(validate("5493001KJTIIGC8Y1R1").is_err());
+        assert!(validate("5493001kjtiigc8y1r12").is_err());
+        assert!(validate("5493001KJTIIGC8Y1RAB").is_err());
+        assert!(check_digits("5493001KJTIIGC8Y1").is_err());
+        // 20 bytes, with a multi-byte character across the check digit boundary
+        assert!(validate("AAAAAAAAAAAAAAAAAé1").is_err());
+        assert!(validate("AAAAAAAAAAAAAAAAAAé").is_err());
+        assert!(check_digits("AAAAAAAAAAAAAAAAé").is_err());
+    }
+
+    #[test]
+    fn test_from_parts() {
+        assert_eq!(
+            from_parts("5493", "001KJTIIGC8Y1R").unwrap(),
+            "5493001KJTIIGC8Y1R12"
+        );
+        assert!(from_parts("5493", "001KJTIIGC8Y1").is_err());
+    }
+}
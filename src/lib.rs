@@ -6,9 +6,21 @@
 //! ## Features
 //!
 //! - **Simple API Requests:** Easily fetch and filter LEI records via the fluent interface ([`crate::client::GleifClient::lei_records`]).
-//! - **Type-Safe Fields & Values:** Use enums like [`crate::field::Field`] and [`crate::value::EntityCategory`] to avoid typos and invalid values.
+//! - **Type-Safe Fields & Values:** Use enums like [`crate::field::Field`] and [`crate::value::EntityCategory`] to avoid typos and invalid values, and validate queries locally with per-field metadata ([`crate::request_builder::GleifRequestBuilder::validate`]) or the live metadata of a [`crate::field::FieldCatalog`].
 //! - **Comprehensive Error Handling:** Centralized error management via [`crate::error::GleifError`].
 //! - **Customizable Requests:** Build and refine API queries with [`crate::request_builder::GleifRequestBuilder`].
+//! - **Offline Filtering:** Evaluate the filters and sort order of a request against local records with [`crate::filter::RecordFilter`].
+//! - **Corporate Hierarchies:** Walk the full ownership tree of a group into an in-memory graph with [`crate::hierarchy::HierarchyGraph`].
+//! - **Parent Resolution:** Get the direct or ultimate parent of an entity, or the reporting exception explaining its absence, in one call with [`crate::client::GleifClient::resolve_parent`].
+//! - **Successor Resolution:** Follow duplicate, merged and retired LEIs to the currently valid entity with [`crate::client::GleifClient::resolve_successor`].
+//! - **Identifier Resolution:** Map ISINs, BICs, MICs and other external identifiers to LEI records with [`crate::client::GleifClient::resolve_identifier`].
+//! - **Record History:** Reconstruct an LEI record as it was at a past date from its field modifications with [`crate::history::RecordHistory`].
+//! - **Event Timelines:** Flatten the legal entity events of a record into a chronological, categorized timeline, optionally merged with its field modifications, with [`crate::timeline::Timeline`].
+//! - **Golden Copy Files:** Stream the records of LEI-CDF 3.1 (XML or CSV), RR-CDF 2.1 and reporting exceptions Golden Copy and delta files into the API model types with the readers in the `golden_copy` module, write records back into LEI-CDF and RR-CDF XML, download Golden Copy publications, apply delta files to a local record store, and index the LEI-to-ISIN, BIC, MIC and `OpenCorporates` mapping files (requires the `golden-copy` feature).
+//! - **CSV Export:** Write query results or Golden Copy records to CSV with configurable [`crate::field::Field`] columns, page by page, with the `CsvExporter` in the `export` module (requires the `csv` feature).
+//! - **Arrow and Parquet Export:** Convert LEI records, relationship records and ISINs into Arrow record batches and write them to Parquet files with the `ArrowRecord` trait and the `ParquetExporter` in the `export` module (requires the `arrow` feature).
+//! - **LEI Validation:** Check ISO 17442 structure and check digits with [`crate::lei`].
+//! - **Test Fixtures:** Generate synthetic LEIs and records with the `testing` module (requires the `testing` feature).
 //! - **Extensible HTTP Client:** Bring your own [`reqwest::Client`] or use middleware for retries, logging, and more.
 //!
 //! ## Getting Started
@@ -117,11 +129,23 @@
 pub mod client;
 pub mod endpoint;
 pub mod error;
+#[cfg(any(feature = "arrow", feature = "csv"))]
+pub mod export;
 pub mod field;
+pub mod filter;
+#[cfg(feature = "golden-copy")]
+pub mod golden_copy;
+pub mod hierarchy;
+pub mod history;
+pub mod lei;
 pub mod model;
 pub mod request_builder;
+pub mod resolve;
 #[cfg(test)]
 pub mod test_utils;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod timeline;
 pub mod value;
 
 /// Library version
@@ -129,3 +153,6 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// The default base URL for the GLEIF API v1.
 pub const DEFAULT_BASE_URL: &str = "https://api.gleif.org/api/v1/";
+
+/// The default base URL for the GLEIF Golden Copy publication API v2.
+pub const DEFAULT_GOLDEN_COPY_URL: &str = "https://goldencopy.gleif.org/api/v2/";
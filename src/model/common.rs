@@ -106,3 +106,20 @@ pub struct RelatedLink {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lei_record: Option<String>,
 }
+
+impl RelatedLink {
+    /// Returns the primary URL of the link.
+    ///
+    /// The first available of `related`, `lei-record`, `relationship-record`, `relationship-records` and
+    /// `reporting-exception` is returned, so that a link pointing at a related entity resolves to the entity
+    /// itself rather than to the relationship describing it.
+    #[must_use]
+    pub fn url(&self) -> Option<&str> {
+        self.related
+            .as_deref()
+            .or(self.lei_record.as_deref())
+            .or(self.relationship_record.as_deref())
+            .or(self.relationship_records.as_deref())
+            .or(self.reporting_exception.as_deref())
+    }
+}
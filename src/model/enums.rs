@@ -64,6 +64,14 @@ pub enum OtherAddressType {
     AlternativeLanguageLegalAddress,
     /// Address of the headquarters of the entity, in an alternative language used in the legal jurisdiction.
     AlternativeLanguageHeadquartersAddress,
+    /// Legal address of the entity transliterated to ASCII characters, provided by the entity for this purpose.
+    PreferredAsciiTransliteratedLegalAddress,
+    /// Legal address of the entity transliterated to ASCII characters, auto-transliterated by the managing LOU.
+    AutoAsciiTransliteratedLegalAddress,
+    /// Headquarters address of the entity transliterated to ASCII characters, provided by the entity for this purpose.
+    PreferredAsciiTransliteratedHeadquartersAddress,
+    /// Headquarters address of the entity transliterated to ASCII characters, auto-transliterated by the managing LOU.
+    AutoAsciiTransliteratedHeadquartersAddress,
 }
 
 /// Represents the type of the transliterated address.
@@ -221,7 +229,7 @@ pub enum RelationshipType {
     /// `StartNode` is an international branch of the legal entity designated by `EndNode` (in jurisdiction country of `StartNode`). The `EndNode` is the Head Office and SHALL be an LEI.
     IsInternationalBranchOf,
     /// `StartNode` is a fund managed by a main management entity. The `EndNode` is legally responsible for the constitution and operation of the fund.
-    #[serde(rename = " IS_FUND-MANAGED_BY")]
+    #[serde(rename = "IS_FUND-MANAGED_BY", alias = " IS_FUND-MANAGED_BY")]
     IsFundManagedBy,
     /// `StartNode` is a sub-fund to an umbrella fund. The `EndNode` is a legal entity with one or more than one sub-funds/compartments where each sub-fund/compartment has its own investment objectives, separate investment policies and strategies, segregation of assets, separate investors and which has segregated liability between sub-funds/compartments.
     IsSubfundOf,
@@ -28,7 +28,7 @@ pub struct FieldAttributes {
     /// The data type of the field.
     pub data_type: String,
     /// The possible enum values for the field, if any.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
     pub enum_values: Option<Vec<String>>,
     /// The resource associated with the field, if any.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5,6 +5,7 @@
 //!
 //! For endpoint usage and client methods, see [`crate::endpoint::lei_record`] (`src/endpoint/lei_record.rs`).
 
+use crate::field::Field;
 use crate::model::{
     common::RelationshipLinks,
     enums::{
@@ -13,6 +14,7 @@ use crate::model::{
         ExpirationReason, GroupType, OtherAddressType, RegistrationStatus, TransliteratedNameType,
     },
 };
+use crate::value::FieldValue;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -406,6 +408,662 @@ pub struct Relationships {
     pub branches: Option<RelationshipLinks>,
 }
 
+/// Private pattern matching the [`Field`]s of the given groups, which partition all fields:
+///
+/// - `record`: fields read from [`LeiRecordAttributes`] itself,
+/// - `entity`: the `entity.*` fields other than addresses, read by [`Entity::get`],
+/// - `address`: the `entity.*` address fields, read by [`Entity::get`],
+/// - `registration`: the `registration.*` fields, read by [`Registration::get`],
+/// - `unrelated`: fields that are not part of the LEI record data or its model (such as the
+///   language of successor entity names).
+///
+/// The `get` methods match on these groups without a wildcard, so a field added to the catalogue
+/// does not compile until it is mapped. The groups are joined into a single flat or-pattern.
+macro_rules! lei_fields {
+    ($($group:ident),+) => {
+        lei_fields!(@ [] $($group)+)
+    };
+    (@ [$($done:tt)*] record $($rest:ident)*) => {
+        lei_fields!(@ [
+            $($done)*
+                | Field::Bic
+                | Field::ConformityFlag
+                | Field::Lei
+                | Field::Mic
+                | Field::Ocid
+                | Field::Qcc
+                | Field::Spglobal
+        ] $($rest)*)
+    };
+    (@ [$($done:tt)*] entity $($rest:ident)*) => {
+        lei_fields!(@ [
+            $($done)*
+                | Field::EntityAssociatedEntity
+                | Field::EntityAssociatedEntityLei
+                | Field::EntityAssociatedEntityName
+                | Field::EntityCategory
+                | Field::EntityCreationDate
+                | Field::EntityExpirationDate
+                | Field::EntityExpirationReason
+                | Field::EntityJurisdiction
+                | Field::EntityLegalForm
+                | Field::EntityLegalFormCode
+                | Field::EntityLegalFormOther
+                | Field::EntityLegalName
+                | Field::EntityLegalNameLanguage
+                | Field::EntityNames
+                | Field::EntityOtherNames
+                | Field::EntityOtherNamesLanguage
+                | Field::EntityOtherNamesType
+                | Field::EntityBusinessRegisterNumber
+                | Field::EntityRegisteredAt
+                | Field::EntityRegisteredAtId
+                | Field::EntityRegisteredAtOther
+                | Field::EntityStatus
+                | Field::EntitySubCategory
+                | Field::EntitySuccessorEntities
+                | Field::EntitySuccessorEntitiesLei
+                | Field::EntitySuccessorEntitiesName
+                | Field::EntitySuccessorEntityLei
+                | Field::EntitySuccessorEntityName
+                | Field::EntityTransliteratedOtherNames
+                | Field::EntityTransliteratedOtherNamesLanguage
+                | Field::EntityTransliteratedOtherNamesType
+                | Field::EntityLegalFormId
+        ] $($rest)*)
+    };
+    (@ [$($done:tt)*] address $($rest:ident)*) => {
+        lei_fields!(@ [
+            $($done)*
+                | Field::EntityAddressesAddressLines
+                | Field::EntityAddressesAddressNumber
+                | Field::EntityAddressesAddressNumberWithinBuilding
+                | Field::EntityAddressesLanguage
+                | Field::EntityAddressesMailRouting
+                | Field::EntityAddressesType
+                | Field::EntityAddresses
+                | Field::EntityAddressesCity
+                | Field::EntityAddressesCountry
+                | Field::EntityAddressesFieldType
+                | Field::EntityAddressesPostalCode
+                | Field::EntityAddressesRegion
+                | Field::EntityHqAddressAddressNumber
+                | Field::EntityHqAddressAddressNumberWithinBuilding
+                | Field::EntityHqAddressAddressLines
+                | Field::EntityHqAddressCity
+                | Field::EntityHqAddressCountry
+                | Field::EntityHqAddressLanguage
+                | Field::EntityHqAddressLine1
+                | Field::EntityHqAddressLine2
+                | Field::EntityHqAddressLine3
+                | Field::EntityHqAddressLine4
+                | Field::EntityHqAddressMailRouting
+                | Field::EntityHqAddressPostalCode
+                | Field::EntityHqAddressRegion
+                | Field::EntityLegalAddressAddressLines
+                | Field::EntityLegalAddressAddressNumber
+                | Field::EntityLegalAddressAddressNumberWithinBuilding
+                | Field::EntityLegalAddressCity
+                | Field::EntityLegalAddressCountry
+                | Field::EntityLegalAddressLanguage
+                | Field::EntityLegalAddressLine1
+                | Field::EntityLegalAddressLine2
+                | Field::EntityLegalAddressLine3
+                | Field::EntityLegalAddressLine4
+                | Field::EntityLegalAddressMailRouting
+                | Field::EntityLegalAddressPostalCode
+                | Field::EntityLegalAddressRegion
+        ] $($rest)*)
+    };
+    (@ [$($done:tt)*] registration $($rest:ident)*) => {
+        lei_fields!(@ [
+            $($done)*
+                | Field::RegistrationCorroborationLevel
+                | Field::RegistrationInitialRegistrationDate
+                | Field::RegistrationLastUpdateDate
+                | Field::RegistrationManagingLou
+                | Field::RegistrationNextRenewalDate
+                | Field::RegistrationOtherValidationAuthoritiesValidatedAs
+                | Field::RegistrationOtherValidationAuthoritiesValidatedAtId
+                | Field::RegistrationOtherValidationAuthoritiesValidatedAtOther
+                | Field::RegistrationStatus
+                | Field::RegistrationValidatedAs
+                | Field::RegistrationValidatedAt
+                | Field::RegistrationValidatedAtId
+                | Field::RegistrationValidatedAtOther
+        ] $($rest)*)
+    };
+    (@ [$($done:tt)*] unrelated $($rest:ident)*) => {
+        lei_fields!(@ [
+            $($done)*
+                | Field::MetaValidFrom
+                | Field::MetaValidTo
+                | Field::BranchCount
+                | Field::BranchesAddressesCity
+                | Field::BranchesAddressesCountry
+                | Field::BranchesAddressesPostalCode
+                | Field::BranchesAddressesRegion
+                | Field::BranchesEntityStatus
+                | Field::BranchesLegalName
+                | Field::BranchesLei
+                | Field::BranchesManagingLou
+                | Field::BranchesNames
+                | Field::BranchesValidFrom
+                | Field::BranchesValidTo
+                | Field::DirectChildCount
+                | Field::DirectChildrenAddressesCity
+                | Field::DirectChildrenAddressesCountry
+                | Field::DirectChildrenAddressesPostalCode
+                | Field::DirectChildrenAddressesRegion
+                | Field::DirectChildrenEntityStatus
+                | Field::DirectChildrenLegalName
+                | Field::DirectChildrenLei
+                | Field::DirectChildrenManagingLou
+                | Field::DirectChildrenNames
+                | Field::DirectChildrenValidFrom
+                | Field::DirectChildrenValidTo
+                | Field::DirectParentAddressesCity
+                | Field::DirectParentAddressesCountry
+                | Field::DirectParentAddressesPostalCode
+                | Field::DirectParentAddressesRegion
+                | Field::DirectParentEntityStatus
+                | Field::DirectParentExceptionReason
+                | Field::DirectParentExceptionValidFrom
+                | Field::DirectParentExceptionValidTo
+                | Field::DirectParentLegalName
+                | Field::DirectParentLei
+                | Field::DirectParentManagingLou
+                | Field::DirectParentNames
+                | Field::DirectParentValidFrom
+                | Field::DirectParentValidTo
+                | Field::FeederFundCount
+                | Field::FeederFundsAddressesCity
+                | Field::FeederFundsAddressesCountry
+                | Field::FeederFundsAddressesPostalCode
+                | Field::FeederFundsAddressesRegion
+                | Field::FeederFundsEntityStatus
+                | Field::FeederFundsLegalName
+                | Field::FeederFundsLei
+                | Field::FeederFundsManagingLou
+                | Field::FeederFundsNames
+                | Field::FeederFundsValidFrom
+                | Field::FeederFundsValidTo
+                | Field::Fulltext
+                | Field::FundManagerAddressesCity
+                | Field::FundManagerAddressesCountry
+                | Field::FundManagerAddressesPostalCode
+                | Field::FundManagerAddressesRegion
+                | Field::FundManagerEntityStatus
+                | Field::FundManagerLegalName
+                | Field::FundManagerLei
+                | Field::FundManagerManagingLou
+                | Field::FundManagerNames
+                | Field::FundManagerValidFrom
+                | Field::FundManagerValidTo
+                | Field::HeadOfficeAddressesCity
+                | Field::HeadOfficeAddressesCountry
+                | Field::HeadOfficeAddressesPostalCode
+                | Field::HeadOfficeAddressesRegion
+                | Field::HeadOfficeEntityStatus
+                | Field::HeadOfficeLegalName
+                | Field::HeadOfficeLei
+                | Field::HeadOfficeManagingLou
+                | Field::HeadOfficeNames
+                | Field::HeadOfficeValidFrom
+                | Field::HeadOfficeValidTo
+                | Field::Isin
+                | Field::ManagedFundCount
+                | Field::ManagedFundsAddressesCity
+                | Field::ManagedFundsAddressesCountry
+                | Field::ManagedFundsAddressesPostalCode
+                | Field::ManagedFundsAddressesRegion
+                | Field::ManagedFundsEntityStatus
+                | Field::ManagedFundsLegalName
+                | Field::ManagedFundsLei
+                | Field::ManagedFundsManagingLou
+                | Field::ManagedFundsNames
+                | Field::ManagedFundsValidFrom
+                | Field::ManagedFundsValidTo
+                | Field::MasterFundAddressesCity
+                | Field::MasterFundAddressesCountry
+                | Field::MasterFundAddressesPostalCode
+                | Field::MasterFundAddressesRegion
+                | Field::MasterFundEntityStatus
+                | Field::MasterFundLegalName
+                | Field::MasterFundLei
+                | Field::MasterFundManagingLou
+                | Field::MasterFundNames
+                | Field::MasterFundValidFrom
+                | Field::MasterFundValidTo
+                | Field::OwnedBy
+                | Field::Owns
+                | Field::SubFundCount
+                | Field::SubFundsAddressesCity
+                | Field::SubFundsAddressesCountry
+                | Field::SubFundsAddressesPostalCode
+                | Field::SubFundsAddressesRegion
+                | Field::SubFundsEntityStatus
+                | Field::SubFundsLegalName
+                | Field::SubFundsLei
+                | Field::SubFundsManagingLou
+                | Field::SubFundsNames
+                | Field::SubFundsValidFrom
+                | Field::SubFundsValidTo
+                | Field::UltimateChildCount
+                | Field::UltimateChildrenAddressesCity
+                | Field::UltimateChildrenAddressesCountry
+                | Field::UltimateChildrenAddressesPostalCode
+                | Field::UltimateChildrenAddressesRegion
+                | Field::UltimateChildrenEntityStatus
+                | Field::UltimateChildrenLegalName
+                | Field::UltimateChildrenLei
+                | Field::UltimateChildrenManagingLou
+                | Field::UltimateChildrenNames
+                | Field::UltimateChildrenValidFrom
+                | Field::UltimateChildrenValidTo
+                | Field::UltimateParentAddressesCity
+                | Field::UltimateParentAddressesCountry
+                | Field::UltimateParentAddressesPostalCode
+                | Field::UltimateParentAddressesRegion
+                | Field::UltimateParentEntityStatus
+                | Field::UltimateParentExceptionReason
+                | Field::UltimateParentExceptionValidFrom
+                | Field::UltimateParentExceptionValidTo
+                | Field::UltimateParentLegalName
+                | Field::UltimateParentLei
+                | Field::UltimateParentManagingLou
+                | Field::UltimateParentNames
+                | Field::UltimateParentValidFrom
+                | Field::UltimateParentValidTo
+                | Field::UmbrellaFundAddressesCity
+                | Field::UmbrellaFundAddressesCountry
+                | Field::UmbrellaFundAddressesPostalCode
+                | Field::UmbrellaFundAddressesRegion
+                | Field::UmbrellaFundEntityStatus
+                | Field::UmbrellaFundLegalName
+                | Field::UmbrellaFundLei
+                | Field::UmbrellaFundManagingLou
+                | Field::UmbrellaFundNames
+                | Field::UmbrellaFundValidFrom
+                | Field::UmbrellaFundValidTo
+                | Field::RelationshipStartDate
+                | Field::RelationshipEndDate
+                | Field::RelationshipStatus
+                | Field::RelationshipType
+                | Field::EntitySuccessorEntitiesLang
+        ] $($rest)*)
+    };
+    (@ [$($done:tt)*]) => {
+        $($done)*
+    };
+}
+
+impl LeiRecord {
+    /// Reads the value of a [`Field`] from this record.
+    ///
+    /// This allows generic tooling (column selection, client-side filtering, diffing) to work from the
+    /// same [`Field`] identifiers that drive API queries. Enum values are returned in their canonical API
+    /// representation (e.g. `"ISSUED"`), timestamps as [`FieldValue::Date`], and multi-valued attributes
+    /// such as BICs or other names as [`FieldValue::List`].
+    ///
+    /// # Parameters
+    ///
+    /// - `field`: The field to read.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the field is not present on this record, or if it is not part of the LEI record data
+    /// (e.g. [`Field::Isin`], [`Field::Fulltext`], the related-entity filters such as
+    /// [`Field::DirectParentLegalName`], or the relationship fields).
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let record = client.lei_record_by_id::<LeiRecord>("5493001KJTIIGC8Y1R12").await?;
+    /// let city = record.data.get(Field::EntityLegalAddressCity);
+    /// assert_eq!(city.unwrap().to_string(), "Wilmington");
+    /// ```
+    #[must_use]
+    pub fn get(&self, field: Field) -> Option<FieldValue> {
+        self.attributes.get(field)
+    }
+}
+
+impl LeiRecordAttributes {
+    /// Reads the value of a [`Field`] from these attributes. See [`LeiRecord::get`].
+    #[must_use]
+    pub fn get(&self, field: Field) -> Option<FieldValue> {
+        match field {
+            Field::Lei => Some(FieldValue::Text(self.lei.clone())),
+            Field::Bic => self
+                .bic
+                .as_ref()
+                .and_then(|bic| FieldValue::list(bic.iter().cloned())),
+            Field::Mic => self
+                .mic
+                .as_ref()
+                .and_then(|mic| FieldValue::list(mic.iter().cloned())),
+            Field::Ocid => FieldValue::text(self.ocid.as_ref()),
+            Field::Qcc => FieldValue::text(self.qcc.as_ref()),
+            Field::Spglobal => self
+                .spglobal
+                .as_ref()
+                .and_then(|ids| FieldValue::list(ids.iter().cloned())),
+            Field::ConformityFlag => self.conformity_flag.as_ref().and_then(FieldValue::variant),
+            lei_fields!(entity, address) => self.entity.get(field),
+            lei_fields!(registration) => self.registration.get(field),
+            lei_fields!(unrelated) => None,
+        }
+    }
+}
+
+impl Entity {
+    /// Returns the successor entities: the [`Entity::successor_entities`] list, or the single
+    /// [`Entity::successor_entity`] of records reporting only that one.
+    #[must_use]
+    pub fn successors(&self) -> &[SuccessorEntity] {
+        let successor = &self.successor_entity;
+        if self.successor_entities.is_empty()
+            && (successor.lei.is_some() || successor.name.is_some())
+        {
+            std::slice::from_ref(successor)
+        } else {
+            &self.successor_entities
+        }
+    }
+
+    /// Reads the value of an `entity.*` [`Field`]. See [`LeiRecord::get`].
+    #[must_use]
+    pub fn get(&self, field: Field) -> Option<FieldValue> {
+        match field {
+            Field::EntityLegalName => Some(FieldValue::Text(self.legal_name.name.clone())),
+            Field::EntityLegalNameLanguage => FieldValue::text(self.legal_name.language.as_ref()),
+            Field::EntityNames => FieldValue::list(
+                std::iter::once(&self.legal_name.name)
+                    .chain(self.other_names.iter().map(|name| &name.name))
+                    .chain(
+                        self.transliterated_other_names
+                            .iter()
+                            .map(|name| &name.name),
+                    )
+                    .cloned(),
+            ),
+            Field::EntityOtherNames => {
+                FieldValue::list(self.other_names.iter().map(|name| name.name.clone()))
+            }
+            Field::EntityOtherNamesLanguage => FieldValue::list(
+                self.other_names
+                    .iter()
+                    .filter_map(|name| name.language.clone()),
+            ),
+            Field::EntityOtherNamesType => FieldValue::list(
+                self.other_names
+                    .iter()
+                    .filter_map(|name| FieldValue::variant(&name.r#type))
+                    .map(|value| value.to_string()),
+            ),
+            Field::EntityTransliteratedOtherNames => FieldValue::list(
+                self.transliterated_other_names
+                    .iter()
+                    .map(|name| name.name.clone()),
+            ),
+            Field::EntityTransliteratedOtherNamesLanguage => FieldValue::list(
+                self.transliterated_other_names
+                    .iter()
+                    .filter_map(|name| name.language.clone()),
+            ),
+            Field::EntityTransliteratedOtherNamesType => FieldValue::list(
+                self.transliterated_other_names
+                    .iter()
+                    .filter_map(|name| FieldValue::variant(&name.r#type))
+                    .map(|value| value.to_string()),
+            ),
+            Field::EntityLegalForm | Field::EntityLegalFormId | Field::EntityLegalFormCode => {
+                Some(FieldValue::Text(self.legal_form.id.clone()))
+            }
+            Field::EntityLegalFormOther => FieldValue::text(self.legal_form.other.as_ref()),
+            Field::EntityCategory => FieldValue::variant(&self.category),
+            Field::EntitySubCategory => self.sub_category.as_ref().and_then(FieldValue::variant),
+            Field::EntityStatus => FieldValue::variant(&self.status),
+            Field::EntityJurisdiction => Some(FieldValue::Text(self.jurisdiction.clone())),
+            Field::EntityBusinessRegisterNumber => FieldValue::text(self.registered_as.as_ref()),
+            Field::EntityRegisteredAt | Field::EntityRegisteredAtId => {
+                Some(FieldValue::Text(self.registered_at.id.clone()))
+            }
+            Field::EntityRegisteredAtOther => FieldValue::text(self.registered_at.other.as_ref()),
+            Field::EntityCreationDate => self.creation_date.map(FieldValue::Date),
+            Field::EntityExpirationDate => self.expiration.date.map(FieldValue::Date),
+            Field::EntityExpirationReason => self
+                .expiration
+                .reason
+                .as_ref()
+                .and_then(FieldValue::variant),
+            Field::EntityAssociatedEntity | Field::EntityAssociatedEntityLei => {
+                FieldValue::text(self.associated_entity.lei.as_ref())
+            }
+            Field::EntityAssociatedEntityName => {
+                FieldValue::text(self.associated_entity.name.as_ref())
+            }
+            Field::EntitySuccessorEntityLei => FieldValue::text(self.successor_entity.lei.as_ref()),
+            Field::EntitySuccessorEntityName => {
+                FieldValue::text(self.successor_entity.name.as_ref())
+            }
+            Field::EntitySuccessorEntities | Field::EntitySuccessorEntitiesLei => FieldValue::list(
+                self.successor_entities
+                    .iter()
+                    .filter_map(|successor| successor.lei.clone()),
+            ),
+            Field::EntitySuccessorEntitiesName => FieldValue::list(
+                self.successor_entities
+                    .iter()
+                    .filter_map(|successor| successor.name.clone()),
+            ),
+            lei_fields!(address) => self.address(field),
+            lei_fields!(record, registration, unrelated) => None,
+        }
+    }
+
+    /// Private helper reading an address [`Field`] from the legal, headquarters or all addresses.
+    fn address(&self, field: Field) -> Option<FieldValue> {
+        let legal = |part| AddressView::from(&self.legal_address).part(part);
+        let headquarters = |part| AddressView::from(&self.headquarters_address).part(part);
+        let addresses = |part| {
+            let values = self.addresses().filter_map(|address| address.part(part));
+            FieldValue::list(values.flat_map(|value| match value {
+                FieldValue::List(list) => list,
+                other => vec![other.to_string()],
+            }))
+        };
+        match field {
+            Field::EntityLegalAddressLanguage => legal(AddressPart::Language),
+            Field::EntityLegalAddressAddressLines => legal(AddressPart::AddressLines),
+            Field::EntityLegalAddressLine1 => legal(AddressPart::Line(0)),
+            Field::EntityLegalAddressLine2 => legal(AddressPart::Line(1)),
+            Field::EntityLegalAddressLine3 => legal(AddressPart::Line(2)),
+            Field::EntityLegalAddressLine4 => legal(AddressPart::Line(3)),
+            Field::EntityLegalAddressAddressNumber => legal(AddressPart::AddressNumber),
+            Field::EntityLegalAddressAddressNumberWithinBuilding => {
+                legal(AddressPart::AddressNumberWithinBuilding)
+            }
+            Field::EntityLegalAddressMailRouting => legal(AddressPart::MailRouting),
+            Field::EntityLegalAddressCity => legal(AddressPart::City),
+            Field::EntityLegalAddressRegion => legal(AddressPart::Region),
+            Field::EntityLegalAddressCountry => legal(AddressPart::Country),
+            Field::EntityLegalAddressPostalCode => legal(AddressPart::PostalCode),
+            Field::EntityHqAddressLanguage => headquarters(AddressPart::Language),
+            Field::EntityHqAddressAddressLines => headquarters(AddressPart::AddressLines),
+            Field::EntityHqAddressLine1 => headquarters(AddressPart::Line(0)),
+            Field::EntityHqAddressLine2 => headquarters(AddressPart::Line(1)),
+            Field::EntityHqAddressLine3 => headquarters(AddressPart::Line(2)),
+            Field::EntityHqAddressLine4 => headquarters(AddressPart::Line(3)),
+            Field::EntityHqAddressAddressNumber => headquarters(AddressPart::AddressNumber),
+            Field::EntityHqAddressAddressNumberWithinBuilding => {
+                headquarters(AddressPart::AddressNumberWithinBuilding)
+            }
+            Field::EntityHqAddressMailRouting => headquarters(AddressPart::MailRouting),
+            Field::EntityHqAddressCity => headquarters(AddressPart::City),
+            Field::EntityHqAddressRegion => headquarters(AddressPart::Region),
+            Field::EntityHqAddressCountry => headquarters(AddressPart::Country),
+            Field::EntityHqAddressPostalCode => headquarters(AddressPart::PostalCode),
+            Field::EntityAddresses | Field::EntityAddressesAddressLines => {
+                addresses(AddressPart::AddressLines)
+            }
+            Field::EntityAddressesAddressNumber => addresses(AddressPart::AddressNumber),
+            Field::EntityAddressesAddressNumberWithinBuilding => {
+                addresses(AddressPart::AddressNumberWithinBuilding)
+            }
+            Field::EntityAddressesLanguage => addresses(AddressPart::Language),
+            Field::EntityAddressesMailRouting => addresses(AddressPart::MailRouting),
+            Field::EntityAddressesType => addresses(AddressPart::Type),
+            Field::EntityAddressesCity => addresses(AddressPart::City),
+            Field::EntityAddressesCountry => addresses(AddressPart::Country),
+            Field::EntityAddressesFieldType => addresses(AddressPart::FieldType),
+            Field::EntityAddressesPostalCode => addresses(AddressPart::PostalCode),
+            Field::EntityAddressesRegion => addresses(AddressPart::Region),
+            lei_fields!(record, entity, registration, unrelated) => None,
+        }
+    }
+
+    /// Private helper iterating over the legal, headquarters and other addresses.
+    fn addresses(&self) -> impl Iterator<Item = AddressView<'_>> {
+        [&self.legal_address, &self.headquarters_address]
+            .into_iter()
+            .map(AddressView::from)
+            .chain(self.other_addresses.iter().map(AddressView::from))
+    }
+}
+
+impl Registration {
+    /// Reads the value of a `registration.*` [`Field`]. See [`LeiRecord::get`].
+    #[must_use]
+    pub fn get(&self, field: Field) -> Option<FieldValue> {
+        match field {
+            Field::RegistrationStatus => FieldValue::variant(&self.status),
+            Field::RegistrationInitialRegistrationDate => {
+                Some(FieldValue::Date(self.initial_registration_date))
+            }
+            Field::RegistrationLastUpdateDate => Some(FieldValue::Date(self.last_update_date)),
+            Field::RegistrationNextRenewalDate => Some(FieldValue::Date(self.next_renewal_date)),
+            Field::RegistrationManagingLou => Some(FieldValue::Text(self.managing_lou.clone())),
+            Field::RegistrationCorroborationLevel => FieldValue::variant(&self.corroboration_level),
+            Field::RegistrationValidatedAt | Field::RegistrationValidatedAtId => {
+                Some(FieldValue::Text(self.validated_at.id.clone()))
+            }
+            Field::RegistrationValidatedAtOther => {
+                FieldValue::text(self.validated_at.other.as_ref())
+            }
+            Field::RegistrationValidatedAs => FieldValue::text(self.validated_as.as_ref()),
+            Field::RegistrationOtherValidationAuthoritiesValidatedAtId => FieldValue::list(
+                self.other_validation_authorities
+                    .iter()
+                    .map(|authority| authority.validated_at.id.clone()),
+            ),
+            Field::RegistrationOtherValidationAuthoritiesValidatedAtOther => FieldValue::list(
+                self.other_validation_authorities
+                    .iter()
+                    .filter_map(|authority| authority.validated_at.other.clone()),
+            ),
+            Field::RegistrationOtherValidationAuthoritiesValidatedAs => FieldValue::list(
+                self.other_validation_authorities
+                    .iter()
+                    .map(|authority| authority.validated_as.clone()),
+            ),
+            lei_fields!(record, entity, address, unrelated) => None,
+        }
+    }
+}
+
+/// Private address part read by an address [`Field`].
+#[derive(Clone, Copy)]
+enum AddressPart {
+    Language,
+    AddressLines,
+    /// The address line at the given index.
+    Line(usize),
+    AddressNumber,
+    AddressNumberWithinBuilding,
+    MailRouting,
+    City,
+    Region,
+    Country,
+    PostalCode,
+    FieldType,
+    Type,
+}
+
+/// Private borrowed view over [`Address`] and [`OtherAddress`], used to read address fields uniformly.
+struct AddressView<'a> {
+    language: Option<&'a String>,
+    address_lines: &'a [String],
+    address_number: Option<&'a String>,
+    address_number_within_building: Option<&'a String>,
+    mail_routing: Option<&'a String>,
+    city: &'a String,
+    region: Option<&'a String>,
+    country: &'a String,
+    postal_code: Option<&'a String>,
+    field_type: Option<&'a String>,
+    r#type: Option<&'a OtherAddressType>,
+}
+
+impl AddressView<'_> {
+    /// Reads a part of the address.
+    fn part(&self, part: AddressPart) -> Option<FieldValue> {
+        match part {
+            AddressPart::Language => FieldValue::text(self.language),
+            AddressPart::AddressLines => FieldValue::list(self.address_lines.iter().cloned()),
+            AddressPart::Line(index) => FieldValue::text(self.address_lines.get(index)),
+            AddressPart::AddressNumber => FieldValue::text(self.address_number),
+            AddressPart::AddressNumberWithinBuilding => {
+                FieldValue::text(self.address_number_within_building)
+            }
+            AddressPart::MailRouting => FieldValue::text(self.mail_routing),
+            AddressPart::City => Some(FieldValue::Text(self.city.clone())),
+            AddressPart::Region => FieldValue::text(self.region),
+            AddressPart::Country => Some(FieldValue::Text(self.country.clone())),
+            AddressPart::PostalCode => FieldValue::text(self.postal_code),
+            AddressPart::FieldType => FieldValue::text(self.field_type),
+            AddressPart::Type => self.r#type.and_then(FieldValue::variant),
+        }
+    }
+}
+
+impl<'a> From<&'a Address> for AddressView<'a> {
+    fn from(address: &'a Address) -> Self {
+        Self {
+            language: address.language.as_ref(),
+            address_lines: &address.address_lines,
+            address_number: address.address_number.as_ref(),
+            address_number_within_building: address.address_number_within_building.as_ref(),
+            mail_routing: address.mail_routing.as_ref(),
+            city: &address.city,
+            region: address.region.as_ref(),
+            country: &address.country,
+            postal_code: address.postal_code.as_ref(),
+            field_type: None,
+            r#type: None,
+        }
+    }
+}
+
+impl<'a> From<&'a OtherAddress> for AddressView<'a> {
+    fn from(address: &'a OtherAddress) -> Self {
+        Self {
+            language: address.language.as_ref(),
+            address_lines: &address.address_lines,
+            address_number: address.address_number.as_ref(),
+            address_number_within_building: address.address_number_within_building.as_ref(),
+            mail_routing: address.mail_routing.as_ref(),
+            city: &address.city,
+            region: address.region.as_ref(),
+            country: &address.country,
+            postal_code: address.postal_code.as_ref(),
+            field_type: Some(&address.field_type),
+            r#type: Some(&address.r#type),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -474,4 +1132,69 @@ mod tests {
             dir,
         );
     }
+
+    #[test]
+    fn test_get_field_values() {
+        let dir = Path::new("tests/data/lei_records");
+        test_model_files(
+            |filename| filename == "single_lei_record_5493001KJTIIGC8Y1R12.json",
+            |data| serde_json::from_str::<GleifApiResponse<LeiRecord>>(data),
+            |_, record| {
+                let record = &record.data;
+                let text = |field| record.get(field).map(|value| value.to_string());
+                assert_eq!(text(Field::Lei).as_deref(), Some("5493001KJTIIGC8Y1R12"));
+                assert_eq!(
+                    text(Field::EntityLegalName).as_deref(),
+                    Some("Bloomberg Finance L.P.")
+                );
+                assert_eq!(
+                    text(Field::EntityLegalAddressCity).as_deref(),
+                    Some("Wilmington")
+                );
+                assert_eq!(
+                    text(Field::EntityLegalAddressLine1).as_deref(),
+                    Some("c/o Corporation Service Company")
+                );
+                assert_eq!(
+                    text(Field::EntityHqAddressCity).as_deref(),
+                    Some("New York")
+                );
+                assert_eq!(text(Field::EntityLegalFormCode).as_deref(), Some("T91T"));
+                assert_eq!(text(Field::EntityCategory).as_deref(), Some("GENERAL"));
+                assert_eq!(text(Field::RegistrationStatus).as_deref(), Some("ISSUED"));
+                assert_eq!(text(Field::ConformityFlag).as_deref(), Some("CONFORMING"));
+                assert_eq!(
+                    text(Field::RegistrationInitialRegistrationDate).as_deref(),
+                    Some("2012-12-06T20:55:22Z")
+                );
+                assert_eq!(text(Field::EntityStatus).as_deref(), Some("ACTIVE"));
+                assert_eq!(text(Field::EntityHqAddressRegion).as_deref(), Some("US-NY"));
+                assert_eq!(
+                    text(Field::EntityAddressesCity).as_deref(),
+                    Some("Wilmington,New York")
+                );
+                assert_eq!(
+                    text(Field::EntityAddressesLanguage).as_deref(),
+                    Some("en,en")
+                );
+                assert_eq!(
+                    record.get(Field::EntityAddresses),
+                    record.get(Field::EntityAddressesAddressLines)
+                );
+                assert_eq!(
+                    text(Field::RegistrationCorroborationLevel).as_deref(),
+                    Some("FULLY_CORROBORATED")
+                );
+                assert_eq!(
+                    text(Field::RegistrationValidatedAs).as_deref(),
+                    Some("4348344")
+                );
+                assert_eq!(record.get(Field::Bic), None);
+                assert_eq!(record.get(Field::DirectParentLegalName), None);
+                assert_eq!(record.get(Field::Fulltext), None);
+                assert_eq!(record.get(Field::MetaValidFrom), None);
+            },
+            dir,
+        );
+    }
 }
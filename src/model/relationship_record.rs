@@ -5,11 +5,13 @@
 //!
 //! For endpoint usage and client methods, see [`crate::endpoint::relationship_record`] (`src/endpoint/relationship_record.rs`).
 
+use crate::field::Field;
 use crate::model::common::RelationshipLinks;
 use crate::model::enums::{
     CorroborationDocuments, CorroborationLevel, RegistrationStatus, RelationshipPeriodType,
     RelationshipStatus, RelationshipType,
 };
+use crate::value::FieldValue;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -57,6 +59,35 @@ pub struct RelationshipDetails {
     pub status: RelationshipStatus,
     /// The periods during which the relationship is/was valid.
     pub periods: Vec<RelationshipPeriod>,
+    /// Additional qualitative attributes of the relationship, such as the accounting standard applied.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub qualifiers: Vec<RelationshipQualifier>,
+    /// Additional quantitative attributes of the relationship, such as the ownership percentage.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub quantifiers: Vec<RelationshipQuantifier>,
+}
+
+/// A qualitative attribute of a relationship.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelationshipQualifier {
+    /// The dimension the qualifier describes, e.g. `ACCOUNTING_STANDARD`.
+    pub dimension: String,
+    /// The value of the qualifier along its dimension, e.g. `IFRS`.
+    pub category: String,
+}
+
+/// A quantitative attribute of a relationship.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelationshipQuantifier {
+    /// The method used to determine the quantity, e.g. `ACCOUNTING_CONSOLIDATION`.
+    pub measurement_method: String,
+    /// The quantity.
+    pub quantity: f64,
+    /// The units of the quantity, e.g. `PERCENTAGE`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units: Option<String>,
 }
 
 /// A node in a relationship (start or end).
@@ -125,6 +156,78 @@ pub struct RelationshipRecordRelationships {
     pub end_node: RelationshipLinks,
 }
 
+impl RelationshipRecord {
+    /// Reads the value of a [`Field`] from this relationship record.
+    ///
+    /// The start node is the child entity and the end node the parent, so [`Field::OwnedBy`] yields the
+    /// end node LEI and [`Field::Owns`] the start node LEI. [`Field::RelationshipStartDate`] and
+    /// [`Field::RelationshipEndDate`] are read from the `RELATIONSHIP_PERIOD` period. The registration
+    /// fields read the relationship's own registration data.
+    ///
+    /// # Parameters
+    ///
+    /// - `field`: The field to read.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the field is not present on this record or does not apply to relationship records.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let record = client.direct_parent_relationship::<RelationshipRecord>("5493001KJTIIGC8Y1R12").await?;
+    /// let parent = record.data.get(Field::OwnedBy);
+    /// ```
+    #[must_use]
+    pub fn get(&self, field: Field) -> Option<FieldValue> {
+        self.attributes.get(field)
+    }
+}
+
+impl RelationshipRecordAttributes {
+    /// Reads the value of a [`Field`] from these attributes. See [`RelationshipRecord::get`].
+    #[must_use]
+    pub fn get(&self, field: Field) -> Option<FieldValue> {
+        let relationship = &self.relationship;
+        let registration = &self.registration;
+        match field {
+            Field::Owns => Some(FieldValue::Text(relationship.start_node.id.clone())),
+            Field::OwnedBy => Some(FieldValue::Text(relationship.end_node.id.clone())),
+            Field::RelationshipStartDate => self
+                .relationship_period()
+                .map(|period| FieldValue::Date(period.start_date)),
+            Field::RelationshipEndDate => self
+                .relationship_period()
+                .and_then(|period| period.end_date)
+                .map(FieldValue::Date),
+            Field::RelationshipStatus => FieldValue::variant(&relationship.status),
+            Field::RelationshipType => FieldValue::variant(&relationship.r#type),
+            Field::RegistrationStatus => FieldValue::variant(&registration.status),
+            Field::RegistrationInitialRegistrationDate => {
+                Some(FieldValue::Date(registration.initial_registration_date))
+            }
+            Field::RegistrationLastUpdateDate => {
+                registration.last_update_date.map(FieldValue::Date)
+            }
+            Field::RegistrationNextRenewalDate => {
+                Some(FieldValue::Date(registration.next_renewal_date))
+            }
+            Field::RegistrationManagingLou => {
+                Some(FieldValue::Text(registration.managing_lou.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Private helper returning the period describing the validity of the relationship itself.
+    fn relationship_period(&self) -> Option<&RelationshipPeriod> {
+        self.relationship
+            .periods
+            .iter()
+            .find(|period| period.r#type == RelationshipPeriodType::RelationshipPeriod)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,4 +280,35 @@ mod tests {
             dir,
         );
     }
+
+    #[test]
+    fn test_get_field_values() {
+        let dir = Path::new("tests/data/relationships");
+        test_model_files(
+            |filename| filename.contains("ultimate-child-relationships_529900GRZ2BQY5ZM9N49"),
+            |data| serde_json::from_str::<GleifApiResponse<Vec<RelationshipRecord>>>(data),
+            |_, list| {
+                let record = &list.data[0];
+                let text = |field| record.get(field).map(|value| value.to_string());
+                assert_eq!(text(Field::Owns).as_deref(), Some("529900TDXM6P1Z03OM28"));
+                assert_eq!(
+                    text(Field::OwnedBy).as_deref(),
+                    Some("529900GRZ2BQY5ZM9N49")
+                );
+                assert_eq!(
+                    text(Field::RelationshipType).as_deref(),
+                    Some("IS_ULTIMATELY_CONSOLIDATED_BY")
+                );
+                assert_eq!(text(Field::RelationshipStatus).as_deref(), Some("ACTIVE"));
+                assert_eq!(
+                    text(Field::RelationshipStartDate).as_deref(),
+                    Some("2019-01-13T23:00:00Z")
+                );
+                assert_eq!(record.get(Field::RelationshipEndDate), None);
+                assert_eq!(text(Field::RegistrationStatus).as_deref(), Some("LAPSED"));
+                assert_eq!(record.get(Field::EntityLegalName), None);
+            },
+            dir,
+        );
+    }
 }
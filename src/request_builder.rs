@@ -30,7 +30,7 @@
 //!   - **Comparisons:** e.g., `filter[registration.lastUpdateDate]>=2023-01-01` via [`crate::request_builder::GleifRequestBuilder::filter_gte`]
 //!   - **Set Inclusion/Exclusion:** e.g., `filter[entity.category]=FUND,BRANCH` via [`crate::request_builder::GleifRequestBuilder::filter_in`]
 //! - **Flexible Sorting:** Specify one or more fields for sorting results.
-//! - **Easy Pagination:** Control `page[number]` and `page[size]` for navigating through record sets.
+//! - **Easy Pagination:** Control `page[number]` and `page[size]` for navigating through record sets, or collect every page with [`crate::request_builder::GleifRequestBuilder::send_all`] or stream it with [`crate::request_builder::GleifRequestBuilder::stream_all`].
 //! - **Customizability:** Add arbitrary query parameters to accommodate unique or evolving API features.
 //! - **Typed Responses:** Deserialize JSON responses directly into your defined Rust types.
 //! - **Raw Data Access:** Option to retrieve the raw `serde_json::Value` for cases requiring flexible parsing.
@@ -68,12 +68,18 @@
 use crate::{
     client::GleifClient,
     error::{GleifError, ResponseContent, Result},
+    field::{FieldCatalog, FieldRule},
+    model::common::GleifApiResponse,
 };
+use futures_util::{Stream, TryStreamExt, stream};
 use reqwest::Method;
 use serde::de::DeserializeOwned;
 use std::collections::HashMap;
 use url::Url;
 
+/// Path of the LEI record collection, the only endpoint validated against a client's [`FieldCatalog`].
+const LEI_RECORDS_PATH: &str = "lei-records";
+
 /// Builder for constructing and executing GLEIF API requests generically.
 #[derive(Debug, Clone)]
 pub struct GleifRequestBuilder {
@@ -253,6 +259,122 @@ impl GleifRequestBuilder {
         self
     }
 
+    /// Validate the filter and sort parameters against the field metadata, without sending the request.
+    ///
+    /// Each `filter[...]` parameter must name a known [`crate::field::Field`] that supports the operator expressed by its
+    /// value (see [`crate::field::Field::operators`]). Comma-separated lists are accepted for any field supporting
+    /// [`crate::field::Operator::Match`], as the API treats them as a match on any of the values. Values of enum fields
+    /// must be among [`crate::field::Field::enum_values`]. Each `sort` field must be sortable (see [`crate::field::Field::is_sortable`]).
+    /// Other parameters are not checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::GleifError::ParseError`] describing the first unknown field, unsupported
+    /// operator, invalid enum value, or unsortable field.
+    pub fn validate(&self) -> Result<()> {
+        self.check_query(FieldRule::from_name)
+    }
+
+    /// Validate the filter and sort parameters against a runtime [`FieldCatalog`], without sending the request.
+    ///
+    /// Applies the same checks as [`GleifRequestBuilder::validate`], using the metadata reported by the
+    /// `fields` endpoint for the fields the catalogue lists and the static [`crate::field::Field`] metadata otherwise.
+    /// This runs automatically in [`GleifRequestBuilder::send`] when the client carries a catalogue
+    /// (see [`GleifClient::with_field_catalog`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::GleifError::ParseError`] describing the first unknown field, unsupported
+    /// operator, invalid enum value, or unsortable field.
+    pub fn validate_with(&self, catalog: &FieldCatalog) -> Result<()> {
+        self.check_query(|name| catalog.rule(name))
+    }
+
+    /// Private helper checking the filter and sort parameters with the rules returned by `rule`.
+    fn check_query<F>(&self, rule: F) -> Result<()>
+    where
+        F: Fn(&str) -> Result<FieldRule>,
+    {
+        for (key, value) in &self.query {
+            if let Some(name) = key
+                .strip_prefix("filter[")
+                .and_then(|rest| rest.strip_suffix(']'))
+            {
+                rule(name)?.check_filter(value)?;
+            }
+        }
+        if let Some(sort) = self.query.get("sort") {
+            for name in sort.split(',').filter(|name| !name.is_empty()) {
+                rule(name.strip_prefix('-').unwrap_or(name))?.check_sort()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Build and execute the request for every page of a list endpoint, returning all items.
+    ///
+    /// Pages are requested one after another, starting from the configured page number (or the first
+    /// page), until the `meta.pagination` of a response reports the last page. Set a larger
+    /// [`GleifRequestBuilder::page_size`] to reduce the number of requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`crate::error::GleifError`] if any page fails as described for [`GleifRequestBuilder::send`].
+    pub async fn send_all<T>(self) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        self.stream_all().try_collect().await
+    }
+
+    /// Build and execute the request for every page of a list endpoint, yielding the items as the
+    /// pages arrive.
+    ///
+    /// Pages are requested as in [`GleifRequestBuilder::send_all`], but only when the items of the
+    /// previous page have been consumed, so large result sets can be processed (e.g. written to a
+    /// file) without holding them in memory. The stream ends after the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let mut records = client.lei_records().page_size(200).stream_all::<LeiRecord>();
+    /// while let Some(record) = records.try_next().await? {
+    ///     println!("{}", record.attributes.entity.legal_name.name);
+    /// }
+    /// ```
+    pub fn stream_all<T>(self) -> impl Stream<Item = Result<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let first: usize = self
+            .query
+            .get("page[number]")
+            .and_then(|number| number.parse().ok())
+            .unwrap_or(1);
+        stream::try_unfold(Some(first), move |page| {
+            let request = self.clone();
+            async move {
+                let Some(page) = page else {
+                    return Ok::<_, GleifError>(None);
+                };
+                let response: GleifApiResponse<Vec<T>> = request.page_number(page).send().await?;
+                let next = match response.meta.and_then(|meta| meta.pagination) {
+                    Some(pagination) if pagination.current_page < pagination.last_page => {
+                        Some(page + 1)
+                    }
+                    _ => None,
+                };
+                Ok(Some((
+                    stream::iter(response.data.into_iter().map(Ok)),
+                    next,
+                )))
+            }
+        })
+        .try_flatten()
+    }
+
     /// Private helper to construct the full request URL.
     fn build_url(&self) -> Result<Url> {
         self.client
@@ -275,14 +397,24 @@ impl GleifRequestBuilder {
     /// The type `R` can be `serde_json::Value` for raw JSON, or a specific
     /// strongly-typed struct (e.g., `GleifApiResponse`<`LeiRecord`>).
     ///
+    /// If the client carries a [`FieldCatalog`], queries of the `lei-records` collection are validated
+    /// against it first (see [`GleifRequestBuilder::validate_with`]). The catalogue describes LEI record
+    /// fields only, so requests to other endpoints are sent as they are.
+    ///
     /// # Errors
     ///
-    /// Returns a [`crate::error::GleifError`] if the request fails, the response is not valid JSON
-    /// that can be deserialized into `R`, or the URL cannot be constructed.
+    /// Returns a [`crate::error::GleifError`] if the query is rejected by the client's field catalogue,
+    /// the request fails, the response is not valid JSON that can be deserialized into `R`, or the URL
+    /// cannot be constructed.
     pub async fn send<R>(self) -> Result<R>
     where
         R: DeserializeOwned,
     {
+        if let Some(catalog) = self.client.field_catalog()
+            && self.path == LEI_RECORDS_PATH
+        {
+            self.validate_with(catalog)?;
+        }
         let url = self.build_url()?;
         let req = self.build_request(url);
         let resp = req.send().await.map_err(GleifError::from)?;
@@ -307,8 +439,15 @@ impl GleifRequestBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::client::GleifClient;
+    use crate::{
+        client::GleifClient, error::ParseErrorKind, field::Field, test_utils::mock_client,
+    };
     use reqwest::Client as ReqwestClient;
+    use serde_json::json;
+    use std::{
+        pin::pin,
+        sync::{Arc, Mutex},
+    };
 
     fn test_client() -> GleifClient {
         let reqwest_client = ReqwestClient::new();
@@ -390,4 +529,160 @@ mod tests {
         assert_eq!(builder.query.get("page[size]").unwrap(), "10");
         assert_eq!(builder.query.get("custom").unwrap(), "value");
     }
+
+    #[test]
+    fn test_validate() {
+        let builder = GleifRequestBuilder::new(test_client(), Method::GET, "lei-records")
+            .filter_in(Field::Lei, ["5493001KJTIIGC8Y1R12", "851WYGNLUQLFZBSYGB56"])
+            .filter_gte(Field::RegistrationInitialRegistrationDate, "2020-01-01")
+            .sort(Field::EntityLegalName)
+            .page_size(10);
+        assert!(builder.validate().is_ok());
+
+        let builder = GleifRequestBuilder::new(test_client(), Method::GET, "lei-records")
+            .filter_gt(Field::Lei, "5493001KJTIIGC8Y1R12");
+        assert!(builder.validate().is_err());
+
+        let builder =
+            GleifRequestBuilder::new(test_client(), Method::GET, "lei-records").sort(Field::Bic);
+        assert!(builder.validate().is_err());
+
+        let builder = GleifRequestBuilder::new(test_client(), Method::GET, "lei-records")
+            .filter_eq("entity.unknown", "value");
+        assert!(builder.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_enum_values() {
+        let builder = GleifRequestBuilder::new(test_client(), Method::GET, "lei-records")
+            .filter_in(Field::EntityCategory, ["FUND", "BRANCH"]);
+        assert!(builder.validate().is_ok());
+
+        let builder = GleifRequestBuilder::new(test_client(), Method::GET, "lei-records")
+            .filter_not(Field::EntityCategory, "COMPANY");
+        assert!(builder.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_with_catalog() {
+        let catalog: FieldCatalog = serde_json::from_str::<crate::model::FieldList>(
+            &std::fs::read_to_string("tests/data/fields/fields_all.json").unwrap(),
+        )
+        .unwrap()
+        .into();
+        let client = test_client().with_field_catalog(catalog);
+        let catalog = client.field_catalog().unwrap();
+
+        let builder = GleifRequestBuilder::new(client.clone(), Method::GET, "lei-records")
+            .filter_eq(Field::RegistrationStatus, "ISSUED")
+            .filter_eq(Field::RelationshipType, "IS_DIRECTLY_CONSOLIDATED_BY")
+            .sort(Field::EntityLegalName);
+        assert!(builder.validate_with(catalog).is_ok());
+
+        let builder = GleifRequestBuilder::new(client.clone(), Method::GET, "lei-records")
+            .filter_eq(Field::RegistrationStatus, "ACTIVE");
+        assert!(builder.validate_with(catalog).is_err());
+
+        let builder =
+            GleifRequestBuilder::new(client.clone(), Method::GET, "lei-records").sort(Field::Bic);
+        assert!(builder.validate_with(catalog).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_send_rejects_invalid_query_with_catalog() {
+        let client = test_client().with_field_catalog(FieldCatalog::default());
+        let result: Result<serde_json::Value> = client.lei_records().sort(Field::Bic).send().await;
+        assert!(matches!(
+            result,
+            Err(GleifError::ParseError {
+                kind: ParseErrorKind::Field,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_skips_catalog_for_other_endpoints() {
+        let client = mock_client(|target| {
+            target
+                .starts_with("lei-issuers?")
+                .then(|| r#"{"data":[]}"#.to_string())
+        })
+        .await
+        .with_field_catalog(FieldCatalog::default());
+        let result: Result<serde_json::Value> = client.lei_issuers().sort("name").send().await;
+        assert!(result.is_ok());
+        let result: Result<serde_json::Value> = client.lei_records().sort("name").send().await;
+        assert!(result.is_err());
+    }
+
+    /// Returns a client serving the `lei-issuers` list page by page, together with the page numbers
+    /// requested so far.
+    ///
+    /// Page 1 holds the items `a` and `b` and page 2 holds `c`. The pagination reports `last_page`
+    /// pages, and pages beyond 2 are answered with `404 Not Found`.
+    async fn paged_client(last_page: u32) -> (GleifClient, Arc<Mutex<Vec<u32>>>) {
+        let requested = Arc::new(Mutex::new(Vec::new()));
+        let client = mock_client({
+            let requested = Arc::clone(&requested);
+            move |target| {
+                let url = Url::parse(&format!("http://localhost/{target}")).ok()?;
+                let page: u32 = url
+                    .query_pairs()
+                    .find(|(key, _)| key == "page[number]")?
+                    .1
+                    .parse()
+                    .ok()?;
+                requested.lock().unwrap().push(page);
+                let ids = match page {
+                    1 => vec!["a", "b"],
+                    2 => vec!["c"],
+                    _ => return None,
+                };
+                let data: Vec<_> = ids.iter().map(|id| json!({ "id": id })).collect();
+                let pagination = json!({
+                    "currentPage": page,
+                    "perPage": 2,
+                    "total": 3,
+                    "lastPage": last_page,
+                });
+                Some(json!({ "meta": { "pagination": pagination }, "data": data }).to_string())
+            }
+        })
+        .await;
+        (client, requested)
+    }
+
+    #[tokio::test]
+    async fn test_send_all_pages() {
+        let (client, requested) = paged_client(2).await;
+        let items: Vec<serde_json::Value> =
+            client.lei_issuers().page_size(2).send_all().await.unwrap();
+        let ids: Vec<_> = items
+            .iter()
+            .map(|item| item["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, ["a", "b", "c"]);
+        assert_eq!(*requested.lock().unwrap(), [1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_stream_all_fetches_pages_lazily() {
+        let (client, requested) = paged_client(3).await;
+        let mut items = pin!(
+            client
+                .lei_issuers()
+                .page_size(2)
+                .stream_all::<serde_json::Value>()
+        );
+        for (id, pages) in [("a", vec![1]), ("b", vec![1]), ("c", vec![1, 2])] {
+            let item = items.try_next().await.unwrap().unwrap();
+            assert_eq!(item["id"], id);
+            assert_eq!(*requested.lock().unwrap(), pages);
+        }
+        // Page 3 fails, which ends the stream.
+        assert!(items.try_next().await.is_err());
+        assert!(items.try_next().await.unwrap().is_none());
+        assert_eq!(*requested.lock().unwrap(), [1, 2, 3]);
+    }
 }
@@ -0,0 +1,52 @@
+//! # Resolvers ([`ParentInfo`]) - One Authoritative Answer from Several Endpoints
+//!
+//! Some questions about a legal entity can only be answered by combining several GLEIF endpoints,
+//! each with its own "not found" behavior. The resolvers in this module pick the right endpoints from
+//! the links of the entity's LEI record and merge the responses into a single typed answer.
+//!
+//! ## Key Features
+//!
+//! - **Parent Resolution:** Find the direct or ultimate accounting consolidation parent of an entity,
+//!   or the reporting exception explaining why no parent is reported ([`GleifClient::resolve_parent`]).
+//! - **Successor Resolution:** Follow duplicate, merged and retired registrations through their
+//!   successors to the currently valid entity ([`GleifClient::resolve_successor`]).
+//! - **Identifier Resolution:** Look up the LEI records carrying an ISIN, BIC, MIC, `OpenCorporates` ID,
+//!   QCC code or S&P Global ID, with ambiguous matches reported ([`GleifClient::resolve_identifier`]).
+//!
+//! ## Example
+//!
+//! ```rust, ignore
+//! use gleif_rs::{
+//!     client::GleifClient,
+//!     resolve::{Identifier, ParentInfo, ParentLevel},
+//! };
+//!
+//! let client = GleifClient::new();
+//! match client.resolve_parent("5493001KJTIIGC8Y1R12", ParentLevel::Ultimate).await? {
+//!     ParentInfo::Reported(parent, _) => println!("Parent: {}", parent.attributes.lei),
+//!     ParentInfo::Exception(exception) => println!("Not reported: {}", exception.attributes.reason),
+//!     ParentInfo::Unknown => println!("No parent information"),
+//! }
+//!
+//! let chain = client.resolve_successor("5493000IBP32UQZ0KL24").await?;
+//! if let Some(current) = chain.terminal() {
+//!     println!("Use {} instead", current.attributes.lei);
+//! }
+//!
+//! let matches = client.resolve_identifier(Identifier::Bic("DEUTDEFF".to_string())).await?;
+//! if matches.is_ambiguous() {
+//!     println!("{} entities share this BIC", matches.records().len());
+//! }
+//! ```
+//!
+//! [`GleifClient::resolve_parent`]: crate::client::GleifClient::resolve_parent
+//! [`GleifClient::resolve_successor`]: crate::client::GleifClient::resolve_successor
+//! [`GleifClient::resolve_identifier`]: crate::client::GleifClient::resolve_identifier
+
+mod identifier;
+mod parent;
+mod successor;
+
+pub use identifier::{Identifier, IdentifierMatch};
+pub use parent::{ParentInfo, ParentLevel};
+pub use successor::{SuccessorChain, is_superseded};
@@ -0,0 +1,260 @@
+//! Resolution of external identifiers (ISIN, BIC, MIC, OCID, QCC, S&P Global) to LEI records.
+
+use crate::{
+    client::GleifClient, error::Result, field::Field, model::lei_record::LeiRecord,
+    request_builder::GleifRequestBuilder,
+};
+use std::fmt;
+
+/// Page size used when fetching matching records (the maximum accepted by the API).
+const IDENTIFIER_PAGE_SIZE: usize = 200;
+
+/// An identifier assigned to a legal entity outside of the LEI system.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Identifier {
+    /// An International Securities Identification Number (ISO 6166) of a security issued by the entity.
+    Isin(String),
+    /// A Business Identifier Code (ISO 9362).
+    Bic(String),
+    /// A Market Identifier Code (ISO 10383).
+    Mic(String),
+    /// An `OpenCorporates` ID, e.g. `gb/01234567`.
+    Ocid(String),
+    /// A Qichacha (QCC) code.
+    Qcc(String),
+    /// An S&P Global Company ID.
+    SpGlobal(String),
+}
+
+impl Identifier {
+    /// Returns the field the identifier is filtered on.
+    #[must_use]
+    pub fn field(&self) -> Field {
+        match self {
+            Identifier::Isin(_) => Field::Isin,
+            Identifier::Bic(_) => Field::Bic,
+            Identifier::Mic(_) => Field::Mic,
+            Identifier::Ocid(_) => Field::Ocid,
+            Identifier::Qcc(_) => Field::Qcc,
+            Identifier::SpGlobal(_) => Field::Spglobal,
+        }
+    }
+
+    /// Returns the identifier value as given.
+    #[must_use]
+    pub fn value(&self) -> &str {
+        match self {
+            Identifier::Isin(value)
+            | Identifier::Bic(value)
+            | Identifier::Mic(value)
+            | Identifier::Ocid(value)
+            | Identifier::Qcc(value)
+            | Identifier::SpGlobal(value) => value,
+        }
+    }
+
+    /// Returns the identifier with its value normalized as by [`Identifier::normalized_value`].
+    #[cfg(feature = "golden-copy")]
+    pub(crate) fn normalized(&self) -> Self {
+        let value = self.normalized_value();
+        match self {
+            Identifier::Isin(_) => Identifier::Isin(value),
+            Identifier::Bic(_) => Identifier::Bic(value),
+            Identifier::Mic(_) => Identifier::Mic(value),
+            Identifier::Ocid(_) => Identifier::Ocid(value),
+            Identifier::Qcc(_) => Identifier::Qcc(value),
+            Identifier::SpGlobal(_) => Identifier::SpGlobal(value),
+        }
+    }
+
+    /// Returns the value as sent to the API: trimmed, and upper-cased for the ISO identifiers.
+    fn normalized_value(&self) -> String {
+        let value = self.value().trim();
+        match self {
+            Identifier::Isin(_) | Identifier::Bic(_) | Identifier::Mic(_) => {
+                value.to_ascii_uppercase()
+            }
+            Identifier::Ocid(_) | Identifier::Qcc(_) | Identifier::SpGlobal(_) => value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            Identifier::Isin(_) => "ISIN",
+            Identifier::Bic(_) => "BIC",
+            Identifier::Mic(_) => "MIC",
+            Identifier::Ocid(_) => "OCID",
+            Identifier::Qcc(_) => "QCC",
+            Identifier::SpGlobal(_) => "S&P Global ID",
+        };
+        write!(f, "{kind} {}", self.value())
+    }
+}
+
+/// The LEI records matching an [`Identifier`].
+///
+/// The record of a unique match is boxed to keep the enum small.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IdentifierMatch {
+    /// No LEI record carries the identifier.
+    NotFound,
+    /// Exactly one LEI record carries the identifier.
+    Unique(Box<LeiRecord>),
+    /// Several LEI records carry the identifier, e.g. a BIC shared by a head office and its branches.
+    Ambiguous(Vec<LeiRecord>),
+}
+
+impl IdentifierMatch {
+    /// Classifies the matching records, ignoring duplicate LEIs.
+    fn from_records(records: Vec<LeiRecord>) -> Self {
+        let mut unique: Vec<LeiRecord> = Vec::with_capacity(records.len());
+        for record in records {
+            if !unique
+                .iter()
+                .any(|other| other.attributes.lei == record.attributes.lei)
+            {
+                unique.push(record);
+            }
+        }
+        match unique.len() {
+            0 => IdentifierMatch::NotFound,
+            1 => IdentifierMatch::Unique(Box::new(unique.remove(0))),
+            _ => IdentifierMatch::Ambiguous(unique),
+        }
+    }
+
+    /// Returns the matching record, if the match is unique.
+    #[must_use]
+    pub fn unique(&self) -> Option<&LeiRecord> {
+        match self {
+            IdentifierMatch::Unique(record) => Some(record),
+            _ => None,
+        }
+    }
+
+    /// Returns all matching records.
+    #[must_use]
+    pub fn records(&self) -> &[LeiRecord] {
+        match self {
+            IdentifierMatch::NotFound => &[],
+            IdentifierMatch::Unique(record) => std::slice::from_ref(record),
+            IdentifierMatch::Ambiguous(records) => records,
+        }
+    }
+
+    /// Returns `true` if more than one record matches.
+    #[must_use]
+    pub fn is_ambiguous(&self) -> bool {
+        matches!(self, IdentifierMatch::Ambiguous(_))
+    }
+}
+
+impl GleifClient {
+    /// Looks up the LEI records carrying an external identifier.
+    ///
+    /// The identifier is routed to the matching `filter[...]` query of the `/lei-records` endpoint
+    /// (`isin`, `bic`, `mic`, `ocid`, `qcc` or `spglobal`), and all pages of results are collected.
+    /// ISINs, BICs and MICs are upper-cased before the lookup.
+    ///
+    /// # Parameters
+    ///
+    /// - `identifier` - The external identifier to look up.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`crate::error::GleifError`] in the following cases:
+    /// - The request could not be completed due to network or server issues.
+    /// - The response body could not be deserialized into the expected type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let matches = client
+    ///     .resolve_identifier(Identifier::Isin("US0378331005".to_string()))
+    ///     .await?;
+    /// match matches {
+    ///     IdentifierMatch::Unique(record) => println!("LEI: {}", record.attributes.lei),
+    ///     IdentifierMatch::Ambiguous(records) => println!("{} candidates", records.len()),
+    ///     IdentifierMatch::NotFound => println!("No match"),
+    /// }
+    /// ```
+    pub async fn resolve_identifier(&self, identifier: Identifier) -> Result<IdentifierMatch> {
+        let records = self.identifier_request(&identifier).send_all().await?;
+        Ok(IdentifierMatch::from_records(records))
+    }
+
+    /// Builds the `/lei-records` request filtering on the identifier.
+    fn identifier_request(&self, identifier: &Identifier) -> GleifRequestBuilder {
+        self.lei_records()
+            .filter_eq(identifier.field(), identifier.normalized_value())
+            .page_size(IDENTIFIER_PAGE_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::sample_records;
+
+    #[test]
+    fn test_identifier_request() {
+        let client = GleifClient::new();
+        let cases = [
+            (
+                Identifier::Isin(" us0378331005 ".to_string()),
+                "isin",
+                "US0378331005",
+            ),
+            (Identifier::Bic("deutdeff".to_string()), "bic", "DEUTDEFF"),
+            (Identifier::Mic("xlon".to_string()), "mic", "XLON"),
+            (
+                Identifier::Ocid("gb/01234567".to_string()),
+                "ocid",
+                "gb/01234567",
+            ),
+            (Identifier::Qcc("q123".to_string()), "qcc", "q123"),
+            (
+                Identifier::SpGlobal("4004205".to_string()),
+                "spglobal",
+                "4004205",
+            ),
+        ];
+        for (identifier, field, value) in cases {
+            let builder = client.identifier_request(&identifier);
+            assert_eq!(
+                builder
+                    .get_query()
+                    .get(&format!("filter[{field}]"))
+                    .unwrap(),
+                value
+            );
+            assert!(builder.validate().is_ok(), "Invalid query for {identifier}");
+        }
+        assert_eq!(
+            Identifier::SpGlobal("4004205".to_string()).to_string(),
+            "S&P Global ID 4004205"
+        );
+    }
+
+    #[test]
+    fn test_identifier_match() {
+        let records = sample_records();
+        assert!(records.len() > 1);
+
+        let matches = IdentifierMatch::from_records(records.clone());
+        assert!(matches.is_ambiguous());
+        assert!(matches.unique().is_none());
+        assert_eq!(matches.records().len(), records.len());
+
+        let first = records[0].clone();
+        let matches = IdentifierMatch::from_records(vec![first.clone(), first.clone()]);
+        assert_eq!(matches.unique(), Some(&first));
+        assert_eq!(matches.records(), [first]);
+
+        let matches = IdentifierMatch::from_records(Vec::new());
+        assert_eq!(matches, IdentifierMatch::NotFound);
+        assert!(matches.records().is_empty());
+    }
+}
@@ -0,0 +1,343 @@
+//! Resolution of direct and ultimate accounting consolidation parents.
+
+use crate::{
+    client::GleifClient,
+    error::Result,
+    model::{
+        common::{GleifApiResponse, RelatedLink},
+        lei_record::LeiRecord,
+        relationship_record::RelationshipRecord,
+        reporting_exception::ReportingException,
+    },
+};
+use futures_util::future;
+
+/// The level of the accounting consolidation parent to resolve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParentLevel {
+    /// The direct accounting consolidation parent.
+    Direct,
+    /// The ultimate accounting consolidation parent.
+    Ultimate,
+}
+
+impl ParentLevel {
+    /// Returns the links of the record describing the parent at this level, if any.
+    fn links(self, record: &LeiRecord) -> Option<&RelatedLink> {
+        let relationships = &record.relationships;
+        match self {
+            ParentLevel::Direct => relationships.direct_parent.as_ref(),
+            ParentLevel::Ultimate => relationships.ultimate_parent.as_ref(),
+        }
+        .map(|link| &link.links)
+    }
+}
+
+/// What GLEIF knows about the parent of an entity.
+///
+/// The records are boxed to keep the enum small.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParentInfo {
+    /// The parent is reported: its LEI record and the relationship linking the entity to it.
+    Reported(Box<LeiRecord>, Box<RelationshipRecord>),
+    /// The entity declined to report a parent, for the reason given in the reporting exception.
+    Exception(Box<ReportingException>),
+    /// Neither a parent nor a reporting exception is on record.
+    Unknown,
+}
+
+impl ParentInfo {
+    /// Returns the LEI record of the parent, if it is reported.
+    #[must_use]
+    pub fn parent(&self) -> Option<&LeiRecord> {
+        match self {
+            ParentInfo::Reported(parent, _) => Some(parent),
+            _ => None,
+        }
+    }
+
+    /// Returns the relationship linking the entity to its parent, if it is reported.
+    #[must_use]
+    pub fn relationship(&self) -> Option<&RelationshipRecord> {
+        match self {
+            ParentInfo::Reported(_, relationship) => Some(relationship),
+            _ => None,
+        }
+    }
+
+    /// Returns the reporting exception, if the entity declined to report a parent.
+    #[must_use]
+    pub fn exception(&self) -> Option<&ReportingException> {
+        match self {
+            ParentInfo::Exception(exception) => Some(exception),
+            _ => None,
+        }
+    }
+}
+
+/// The endpoint answering for the parent of an entity, as advertised by the links of its LEI record.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ParentSource {
+    Relationship,
+    Exception,
+    None,
+}
+
+impl ParentSource {
+    /// Determines the source from the links of the record.
+    fn of(record: &LeiRecord, level: ParentLevel) -> Self {
+        match level.links(record) {
+            Some(links) if links.relationship_record.is_some() => ParentSource::Relationship,
+            Some(links) if links.reporting_exception.is_some() => ParentSource::Exception,
+            _ => ParentSource::None,
+        }
+    }
+}
+
+impl GleifClient {
+    /// Resolves the direct or ultimate accounting consolidation parent of an entity.
+    ///
+    /// Instead of calling [`GleifClient::direct_parent`], [`GleifClient::direct_parent_relationship`] and
+    /// [`GleifClient::direct_parent_reporting_exception`] (or their ultimate counterparts) and
+    /// interpreting their "not found" responses, this method fetches the LEI record of the entity and
+    /// follows the parent links it advertises. The parent record and its relationship are fetched
+    /// concurrently.
+    ///
+    /// # Parameters
+    ///
+    /// - `lei` - A string slice representing the LEI identifier.
+    /// - `level` - Whether to resolve the direct or the ultimate parent.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`crate::error::GleifError`] in the following cases:
+    /// - The LEI record does not exist.
+    /// - The request could not be completed due to network or server issues.
+    /// - The response body could not be deserialized into the expected type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let info = client.resolve_parent("5493001KJTIIGC8Y1R12", ParentLevel::Direct).await?;
+    /// if let ParentInfo::Reported(parent, relationship) = info {
+    ///     println!("{} ({})", parent.attributes.entity.legal_name.name, relationship.id);
+    /// }
+    /// ```
+    pub async fn resolve_parent(&self, lei: &str, level: ParentLevel) -> Result<ParentInfo> {
+        let record: GleifApiResponse<LeiRecord> = self.lei_record_by_id(lei).await?;
+        self.resolve_parent_of(&record.data, level).await
+    }
+
+    /// Resolves the direct or ultimate accounting consolidation parent of an entity whose LEI record
+    /// has already been fetched.
+    ///
+    /// See [`GleifClient::resolve_parent`].
+    ///
+    /// # Parameters
+    ///
+    /// - `record` - The LEI record of the entity.
+    /// - `level` - Whether to resolve the direct or the ultimate parent.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`crate::error::GleifError`] in the following cases:
+    /// - The request could not be completed due to network or server issues.
+    /// - The response body could not be deserialized into the expected type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let record: LeiRecord = client.lei_record_by_id("5493001KJTIIGC8Y1R12").await?;
+    /// let info = client.resolve_parent_of(&record.data, ParentLevel::Ultimate).await?;
+    /// ```
+    pub async fn resolve_parent_of(
+        &self,
+        record: &LeiRecord,
+        level: ParentLevel,
+    ) -> Result<ParentInfo> {
+        let lei = record.attributes.lei.as_str();
+        match (ParentSource::of(record, level), level) {
+            (ParentSource::Relationship, ParentLevel::Direct) => {
+                let (parent, relationship): (
+                    GleifApiResponse<LeiRecord>,
+                    GleifApiResponse<RelationshipRecord>,
+                ) = future::try_join(
+                    self.direct_parent(lei),
+                    self.direct_parent_relationship(lei),
+                )
+                .await?;
+                Ok(ParentInfo::Reported(
+                    Box::new(parent.data),
+                    Box::new(relationship.data),
+                ))
+            }
+            (ParentSource::Relationship, ParentLevel::Ultimate) => {
+                let (parent, relationship): (
+                    GleifApiResponse<LeiRecord>,
+                    GleifApiResponse<RelationshipRecord>,
+                ) = future::try_join(
+                    self.ultimate_parent(lei),
+                    self.ultimate_parent_relationship(lei),
+                )
+                .await?;
+                Ok(ParentInfo::Reported(
+                    Box::new(parent.data),
+                    Box::new(relationship.data),
+                ))
+            }
+            (ParentSource::Exception, ParentLevel::Direct) => {
+                let exception: GleifApiResponse<ReportingException> =
+                    self.direct_parent_reporting_exception(lei).await?;
+                Ok(ParentInfo::Exception(Box::new(exception.data)))
+            }
+            (ParentSource::Exception, ParentLevel::Ultimate) => {
+                let exception: GleifApiResponse<ReportingException> =
+                    self.ultimate_parent_reporting_exception(lei).await?;
+                Ok(ParentInfo::Exception(Box::new(exception.data)))
+            }
+            (ParentSource::None, _) => Ok(ParentInfo::Unknown),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_client, read};
+    use std::fs;
+
+    fn record(lei: &str) -> LeiRecord {
+        read(&format!(
+            "tests/data/lei_records/single_lei_record_{lei}.json"
+        ))
+    }
+
+    #[test]
+    fn test_parent_source() {
+        let reported = record("549300YX4S1LLSMK2627");
+        assert_eq!(
+            ParentSource::of(&reported, ParentLevel::Direct),
+            ParentSource::Relationship
+        );
+        assert_eq!(
+            ParentSource::of(&reported, ParentLevel::Ultimate),
+            ParentSource::Relationship
+        );
+
+        let mixed = record("254900LNRYNOQ9YPU758");
+        assert_eq!(
+            ParentSource::of(&mixed, ParentLevel::Direct),
+            ParentSource::Exception
+        );
+        assert_eq!(
+            ParentSource::of(&mixed, ParentLevel::Ultimate),
+            ParentSource::Relationship
+        );
+
+        let mut unknown = record("529900GRZ2BQY5ZM9N49");
+        unknown.relationships.direct_parent = None;
+        assert_eq!(
+            ParentSource::of(&unknown, ParentLevel::Direct),
+            ParentSource::None
+        );
+        assert_eq!(
+            ParentSource::of(&unknown, ParentLevel::Ultimate),
+            ParentSource::Exception
+        );
+    }
+
+    #[test]
+    fn test_parent_info_accessors() {
+        let lei = "549300YX4S1LLSMK2627";
+        let info = ParentInfo::Reported(
+            Box::new(read(&format!(
+                "tests/data/lei_records/direct-parent_{lei}.json"
+            ))),
+            Box::new(read(&format!(
+                "tests/data/relationships/direct-parent-relationship_{lei}.json"
+            ))),
+        );
+        let parent = info.parent().unwrap();
+        let relationship = info.relationship().unwrap();
+        assert_eq!(
+            relationship.attributes.relationship.end_node.id,
+            parent.attributes.lei
+        );
+        assert!(info.exception().is_none());
+
+        let lei = "529900GRZ2BQY5ZM9N49";
+        let info = ParentInfo::Exception(Box::new(read(&format!(
+            "tests/data/reporting_exceptions/direct-parent-reporting-exception_{lei}.json"
+        ))));
+        assert_eq!(info.exception().unwrap().attributes.lei, lei);
+        assert!(info.parent().is_none());
+        assert!(ParentInfo::Unknown.relationship().is_none());
+    }
+
+    /// Returns a client answering the parent endpoints of `lei` with their sample files.
+    async fn sample_client(lei: &'static str) -> GleifClient {
+        mock_client(move |target| {
+            let endpoint = target.strip_prefix(&format!("lei-records/{lei}/"))?;
+            let dir = match endpoint {
+                "direct-parent" | "ultimate-parent" => "lei_records",
+                "direct-parent-relationship" | "ultimate-parent-relationship" => "relationships",
+                _ => "reporting_exceptions",
+            };
+            fs::read_to_string(format!("tests/data/{dir}/{endpoint}_{lei}.json")).ok()
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_resolve_parent_reported() {
+        let lei = "549300YX4S1LLSMK2627";
+        let client = sample_client(lei).await;
+        let record = record(lei);
+        for (level, prefix) in [
+            (ParentLevel::Direct, "direct"),
+            (ParentLevel::Ultimate, "ultimate"),
+        ] {
+            let info = client.resolve_parent_of(&record, level).await.unwrap();
+            assert_eq!(
+                info,
+                ParentInfo::Reported(
+                    Box::new(read(&format!(
+                        "tests/data/lei_records/{prefix}-parent_{lei}.json"
+                    ))),
+                    Box::new(read(&format!(
+                        "tests/data/relationships/{prefix}-parent-relationship_{lei}.json"
+                    ))),
+                )
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_parent_exception() {
+        for (lei, level, prefix) in [
+            ("254900LNRYNOQ9YPU758", ParentLevel::Direct, "direct"),
+            ("529900GRZ2BQY5ZM9N49", ParentLevel::Ultimate, "ultimate"),
+        ] {
+            let client = sample_client(lei).await;
+            let info = client.resolve_parent_of(&record(lei), level).await.unwrap();
+            assert_eq!(
+                info,
+                ParentInfo::Exception(Box::new(read(&format!(
+                    "tests/data/reporting_exceptions/{prefix}-parent-reporting-exception_{lei}.json"
+                ))))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_parent_without_links_is_unknown() {
+        let mut record = record("529900GRZ2BQY5ZM9N49");
+        record.relationships.ultimate_parent = None;
+        let client = GleifClient::new();
+        let info = client
+            .resolve_parent_of(&record, ParentLevel::Ultimate)
+            .await
+            .unwrap();
+        assert_eq!(info, ParentInfo::Unknown);
+    }
+}
@@ -0,0 +1,400 @@
+//! Resolution of successor chains for superseded LEI registrations.
+
+use crate::{
+    client::GleifClient,
+    error::Result,
+    model::{
+        common::GleifApiResponse,
+        enums::{EntityStatus, RegistrationStatus},
+        lei_record::LeiRecord,
+    },
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Page size used when fetching successor lists (the maximum accepted by the API).
+const SUCCESSOR_PAGE_SIZE: usize = 200;
+
+/// Returns `true` if the record no longer identifies a current legal entity.
+///
+/// This is the case for registrations that are [`RegistrationStatus::Duplicate`],
+/// [`RegistrationStatus::Merged`], [`RegistrationStatus::Retired`] or [`RegistrationStatus::Annulled`],
+/// and for entities whose status is [`EntityStatus::Inactive`].
+#[must_use]
+pub fn is_superseded(record: &LeiRecord) -> bool {
+    matches!(
+        record.attributes.registration.status,
+        RegistrationStatus::Duplicate
+            | RegistrationStatus::Merged
+            | RegistrationStatus::Retired
+            | RegistrationStatus::Annulled
+    ) || record.attributes.entity.status == EntityStatus::Inactive
+}
+
+/// Returns the LEIs of the successors reported in the entity data of the record, without duplicates.
+fn successor_leis(record: &LeiRecord) -> Vec<String> {
+    let entity = &record.attributes.entity;
+    let mut leis: Vec<String> = Vec::new();
+    for lei in std::iter::once(&entity.successor_entity)
+        .chain(&entity.successor_entities)
+        .filter_map(|successor| successor.lei.as_ref())
+    {
+        if !leis.contains(lei) {
+            leis.push(lei.clone());
+        }
+    }
+    leis
+}
+
+/// The records visited while following the successors of an LEI.
+///
+/// The walk starts at the requested record and follows the successors of every superseded record
+/// (see [`is_superseded`]) until it reaches current records, the terminals of the chain. A record
+/// may have several successors, e.g. after a demerger, in which case the chain branches.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SuccessorChain {
+    records: Vec<LeiRecord>,
+    predecessors: HashMap<String, String>,
+    cycles: Vec<(String, String)>,
+}
+
+impl SuccessorChain {
+    /// Returns the requested record.
+    #[must_use]
+    pub fn start(&self) -> Option<&LeiRecord> {
+        self.records.first()
+    }
+
+    /// Returns every record visited, in the order they were reached, starting with the requested one.
+    #[must_use]
+    pub fn records(&self) -> &[LeiRecord] {
+        &self.records
+    }
+
+    /// Returns the record with the given LEI, if it was visited.
+    #[must_use]
+    pub fn record(&self, lei: &str) -> Option<&LeiRecord> {
+        self.records
+            .iter()
+            .find(|record| record.attributes.lei == lei)
+    }
+
+    /// Returns the current records the chain ends in.
+    pub fn terminals(&self) -> impl Iterator<Item = &LeiRecord> {
+        self.records.iter().filter(|record| !is_superseded(record))
+    }
+
+    /// Returns the currently valid record, if the chain ends in exactly one.
+    ///
+    /// Returns `None` if every branch ends in a superseded record without reported successors, or if
+    /// the chain branches into several current records; use [`SuccessorChain::terminals`] to tell the
+    /// two apart.
+    #[must_use]
+    pub fn terminal(&self) -> Option<&LeiRecord> {
+        let mut terminals = self.terminals();
+        match (terminals.next(), terminals.next()) {
+            (Some(terminal), None) => Some(terminal),
+            _ => None,
+        }
+    }
+
+    /// Returns the records leading from the requested record to the record with the given LEI,
+    /// both included.
+    ///
+    /// Returns an empty vector if the LEI was not visited.
+    #[must_use]
+    pub fn path(&self, lei: &str) -> Vec<&LeiRecord> {
+        let mut path = Vec::new();
+        let mut current = self.record(lei);
+        while let Some(record) = current {
+            path.push(record);
+            current = self
+                .predecessors
+                .get(&record.attributes.lei)
+                .and_then(|predecessor| self.record(predecessor));
+        }
+        path.reverse();
+        path
+    }
+
+    /// Returns the successor links, as `(lei, successor)` pairs, that point back to a record earlier in
+    /// the chain and were therefore not followed.
+    #[must_use]
+    pub fn cycles(&self) -> &[(String, String)] {
+        &self.cycles
+    }
+
+    /// Registers a successor link, returning `true` if the successor has not been visited yet.
+    fn visit(&mut self, seen: &mut HashSet<String>, lei: &str, successor: &str) -> bool {
+        if seen.insert(successor.to_string()) {
+            self.predecessors
+                .insert(successor.to_string(), lei.to_string());
+            return true;
+        }
+        if self.is_predecessor(successor, lei) {
+            self.cycles.push((lei.to_string(), successor.to_string()));
+        }
+        false
+    }
+
+    /// Returns `true` if `predecessor` is `lei` itself or precedes it in the chain.
+    fn is_predecessor(&self, predecessor: &str, lei: &str) -> bool {
+        let mut current = Some(lei);
+        while let Some(lei) = current {
+            if lei == predecessor {
+                return true;
+            }
+            current = self.predecessors.get(lei).map(String::as_str);
+        }
+        false
+    }
+}
+
+/// Where successor records are fetched from.
+trait SuccessorSource {
+    /// Fetches the record with the given LEI.
+    async fn record(&self, lei: &str) -> Result<LeiRecord>;
+
+    /// Fetches the successors of a record that reports none in its entity data.
+    async fn successors(&self, record: &LeiRecord) -> Result<Vec<LeiRecord>>;
+}
+
+impl SuccessorSource for GleifClient {
+    async fn record(&self, lei: &str) -> Result<LeiRecord> {
+        let record: GleifApiResponse<LeiRecord> = self.lei_record_by_id(lei).await?;
+        Ok(record.data)
+    }
+
+    async fn successors(&self, record: &LeiRecord) -> Result<Vec<LeiRecord>> {
+        let lei = record.attributes.lei.as_str();
+        let links = &record.relationships;
+        if links.successor_entities.is_some() {
+            return self
+                .successor_entities(lei)
+                .page_size(SUCCESSOR_PAGE_SIZE)
+                .send_all()
+                .await;
+        }
+        if links.successor_entity.is_some() {
+            let successor: GleifApiResponse<LeiRecord> = self.successor_entity(lei).await?;
+            return Ok(vec![successor.data]);
+        }
+        Ok(Vec::new())
+    }
+}
+
+/// Follows the successors of the start record breadth-first, fetching each record at most once.
+async fn walk<S: SuccessorSource>(source: &S, start: LeiRecord) -> Result<SuccessorChain> {
+    let mut chain = SuccessorChain::default();
+    let mut seen = HashSet::from([start.attributes.lei.clone()]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(record) = queue.pop_front() {
+        if is_superseded(&record) {
+            let lei = record.attributes.lei.as_str();
+            let leis = successor_leis(&record);
+            if leis.is_empty() {
+                for successor in source.successors(&record).await? {
+                    if chain.visit(&mut seen, lei, &successor.attributes.lei) {
+                        queue.push_back(successor);
+                    }
+                }
+            } else {
+                for successor in leis {
+                    if chain.visit(&mut seen, lei, &successor) {
+                        queue.push_back(source.record(&successor).await?);
+                    }
+                }
+            }
+        }
+        chain.records.push(record);
+    }
+    Ok(chain)
+}
+
+impl GleifClient {
+    /// Follows the successors of a superseded LEI to the currently valid entity.
+    ///
+    /// Starting from the given LEI, the successors of every duplicate, merged, retired, annulled or
+    /// inactive record are followed transitively. Successors are taken from the `successorEntity` and
+    /// `successorEntities` entity data, or from the `successor-entity` and `successor-entities`
+    /// endpoints when the entity data does not report their LEIs. Every record is fetched at most once,
+    /// so cyclic successor links terminate the walk; they are reported by [`SuccessorChain::cycles`].
+    ///
+    /// # Parameters
+    ///
+    /// - `lei` - A string slice representing the LEI identifier.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`crate::error::GleifError`] in the following cases:
+    /// - The LEI record, or the record of a reported successor, does not exist.
+    /// - The request could not be completed due to network or server issues.
+    /// - The response body could not be deserialized into the expected type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let chain = client.resolve_successor("5493000IBP32UQZ0KL24").await?;
+    /// if let Some(current) = chain.terminal() {
+    ///     println!("Use {} instead", current.attributes.lei);
+    /// }
+    /// ```
+    pub async fn resolve_successor(&self, lei: &str) -> Result<SuccessorChain> {
+        let record = SuccessorSource::record(self, lei).await?;
+        walk(self, record).await
+    }
+
+    /// Follows the successors of an LEI record that has already been fetched.
+    ///
+    /// See [`GleifClient::resolve_successor`].
+    ///
+    /// # Parameters
+    ///
+    /// - `record` - The LEI record to start from.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a [`crate::error::GleifError`] in the following cases:
+    /// - The record of a reported successor does not exist.
+    /// - The request could not be completed due to network or server issues.
+    /// - The response body could not be deserialized into the expected type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// let record: LeiRecord = client.lei_record_by_id("5493000IBP32UQZ0KL24").await?;
+    /// let chain = client.resolve_successor_of(record.data).await?;
+    /// ```
+    pub async fn resolve_successor_of(&self, record: LeiRecord) -> Result<SuccessorChain> {
+        walk(self, record).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::GleifError, model::lei_record::SuccessorEntity, test_utils::read};
+
+    const LEI: &str = "529900GRZ2BQY5ZM9N49";
+
+    /// Records served from memory, keyed by LEI.
+    struct Records(HashMap<String, LeiRecord>);
+
+    impl SuccessorSource for Records {
+        async fn record(&self, lei: &str) -> Result<LeiRecord> {
+            self.0.get(lei).cloned().ok_or(GleifError::ParseError {
+                kind: crate::error::ParseErrorKind::Value,
+                message: format!("Unknown LEI {lei}"),
+            })
+        }
+
+        async fn successors(&self, _record: &LeiRecord) -> Result<Vec<LeiRecord>> {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Builds a record from the sample data with the given LEI, status and successors.
+    fn record(lei: &str, status: RegistrationStatus, successors: &[&str]) -> LeiRecord {
+        let mut record = read::<LeiRecord>(&format!(
+            "tests/data/lei_records/single_lei_record_{LEI}.json"
+        ));
+        record.attributes.lei = lei.to_string();
+        record.attributes.registration.status = status;
+        record.attributes.entity.successor_entities = successors
+            .iter()
+            .map(|lei| SuccessorEntity {
+                lei: Some((*lei).to_string()),
+                name: None,
+            })
+            .collect();
+        record
+    }
+
+    fn records(records: Vec<LeiRecord>) -> Records {
+        Records(
+            records
+                .into_iter()
+                .map(|record| (record.attributes.lei.clone(), record))
+                .collect(),
+        )
+    }
+
+    fn leis<'a>(records: impl IntoIterator<Item = &'a LeiRecord>) -> Vec<&'a str> {
+        records
+            .into_iter()
+            .map(|record| record.attributes.lei.as_str())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_current_record_is_its_own_terminal() {
+        let start = record("A", RegistrationStatus::Issued, &["B"]);
+        let chain = walk(&records(Vec::new()), start).await.unwrap();
+        assert_eq!(leis(chain.records()), ["A"]);
+        assert_eq!(chain.terminal().unwrap().attributes.lei, "A");
+    }
+
+    #[tokio::test]
+    async fn test_successor_chain() {
+        let source = records(vec![
+            record("B", RegistrationStatus::Duplicate, &["C"]),
+            record("C", RegistrationStatus::Lapsed, &[]),
+        ]);
+        let start = record("A", RegistrationStatus::Merged, &["B"]);
+        let chain = walk(&source, start).await.unwrap();
+        assert_eq!(leis(chain.records()), ["A", "B", "C"]);
+        assert_eq!(chain.terminal().unwrap().attributes.lei, "C");
+        assert_eq!(leis(chain.path("C")), ["A", "B", "C"]);
+        assert!(chain.path("D").is_empty());
+        assert!(chain.cycles().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_branching_and_dead_ends() {
+        let source = records(vec![
+            record("B", RegistrationStatus::Issued, &[]),
+            record("C", RegistrationStatus::Issued, &[]),
+        ]);
+        let start = record("A", RegistrationStatus::Retired, &["B", "C", "B"]);
+        let chain = walk(&source, start).await.unwrap();
+        assert_eq!(leis(chain.terminals()), ["B", "C"]);
+        assert!(chain.terminal().is_none());
+
+        let start = record("A", RegistrationStatus::Retired, &[]);
+        let chain = walk(&source, start).await.unwrap();
+        assert_eq!(chain.terminals().count(), 0);
+        assert!(chain.terminal().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cycles_are_reported() {
+        let source = records(vec![
+            record("B", RegistrationStatus::Duplicate, &["A", "C"]),
+            record("C", RegistrationStatus::Duplicate, &["B"]),
+        ]);
+        let start = record("A", RegistrationStatus::Duplicate, &["B"]);
+        let chain = walk(&source, start).await.unwrap();
+        assert_eq!(leis(chain.records()), ["A", "B", "C"]);
+        assert_eq!(
+            chain.cycles(),
+            [
+                ("B".to_string(), "A".to_string()),
+                ("C".to_string(), "B".to_string())
+            ]
+        );
+        assert!(chain.terminal().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_converging_branches_are_not_cycles() {
+        let source = records(vec![
+            record("B", RegistrationStatus::Merged, &["D"]),
+            record("C", RegistrationStatus::Merged, &["D"]),
+            record("D", RegistrationStatus::Issued, &[]),
+        ]);
+        let start = record("A", RegistrationStatus::Retired, &["B", "C"]);
+        let chain = walk(&source, start).await.unwrap();
+        assert_eq!(leis(chain.records()), ["A", "B", "C", "D"]);
+        assert!(chain.cycles().is_empty());
+        assert_eq!(chain.terminal().unwrap().attributes.lei, "D");
+    }
+}
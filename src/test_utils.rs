@@ -20,8 +20,17 @@
 //! ```
 
 //! Test utilities for the `model` module.
+use crate::{
+    client::GleifClient,
+    model::{common::GleifApiResponse, lei_record::LeiRecord},
+};
+use serde::de::DeserializeOwned;
 use std::fs;
 use std::path::Path;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
 
 /// Helper to test deserialization and validation of model files.
 ///
@@ -63,3 +72,73 @@ pub(crate) fn test_model_files<T, F, D, V, E>(
     }
     assert!(checked_files > 0, "No model files were checked");
 }
+
+/// Reads the `data` of a sample response.
+pub(crate) fn read<T: DeserializeOwned>(path: &str) -> T {
+    let data = fs::read_to_string(path).expect("Failed to read sample data");
+    serde_json::from_str::<GleifApiResponse<T>>(&data)
+        .expect("Failed to deserialize sample data")
+        .data
+}
+
+/// Reads the sample list of LEI records shared by the filtering and export tests.
+pub(crate) fn sample_records() -> Vec<LeiRecord> {
+    read("tests/data/lei_records/multi_lei_records.json")
+}
+
+/// Serves canned responses on a local port and returns its base URL (e.g. `http://127.0.0.1:4321`).
+///
+/// # Parameters
+/// - `respond`: Maps the full request target (the path and query, e.g.
+///   `/api/v2/golden-copies/publishes/latest`) to a response body. Unknown targets are answered with
+///   `404 Not Found`.
+pub(crate) async fn mock_server<F>(respond: F) -> String
+where
+    F: Fn(&str) -> Option<Vec<u8>> + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = vec![0; 8192];
+            let read = socket.read(&mut request).await.unwrap();
+            let request = String::from_utf8_lossy(&request[..read]);
+            let target = request.split_whitespace().nth(1).unwrap_or_default();
+            let (status, body) = match respond(target) {
+                Some(body) => ("200 OK", body),
+                None => (
+                    "404 Not Found",
+                    br#"{"errors":[{"status":"404"}]}"#.to_vec(),
+                ),
+            };
+            let head = format!(
+                "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            );
+            socket.write_all(head.as_bytes()).await.unwrap();
+            socket.write_all(&body).await.unwrap();
+        }
+    });
+    base
+}
+
+/// Serves canned responses on a local port, standing in for the GLEIF API, and returns a client
+/// pointed at it.
+///
+/// # Parameters
+/// - `respond`: Maps the request target (the path and query after the base URL, e.g.
+///   `lei-records/5493001KJTIIGC8Y1R12/direct-parent`) to a JSON response body. Unknown targets are
+///   answered with `404 Not Found`.
+pub(crate) async fn mock_client<F>(respond: F) -> GleifClient
+where
+    F: Fn(&str) -> Option<String> + Send + 'static,
+{
+    let base =
+        mock_server(move |target| respond(target.trim_start_matches('/')).map(String::into_bytes))
+            .await;
+    GleifClient::builder()
+        .base_url(format!("{base}/"))
+        .build()
+        .unwrap()
+}
@@ -0,0 +1,593 @@
+//! # Test Fixtures ([`testing`](crate::testing)) - Synthetic LEIs and Records
+//!
+//! This module, available behind the `testing` cargo feature, helps downstream test suites build realistic
+//! but entirely fake GLEIF data. Generated LEIs follow the ISO 17442 structure and carry valid
+//! MOD 97-10 check digits (see [`crate::lei`]), so they pass the same validation as real codes.
+//!
+//! ## Key Features
+//!
+//! - **LEI Generation:** [`LeiGenerator`] produces valid LEIs for a given LOU prefix, either randomly or from a seed
+//!   for reproducible fixtures.
+//! - **Record Building:** [`LeiRecordBuilder`] produces complete [`LeiRecord`] models whose relationship links
+//!   are consistent with the configured parents and children.
+//! - **Relationship Records:** [`LeiRecordBuilder::build_relationships`] and [`relationship_record`] produce the
+//!   matching Level 2 [`RelationshipRecord`] models.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use gleif_rs::{lei, testing::LeiGenerator};
+//!
+//! let mut generator = LeiGenerator::seeded("5493", 42).unwrap();
+//! let parent = generator.generate();
+//! let child = generator.record_builder().direct_parent(&parent).build();
+//!
+//! assert!(lei::is_valid(&parent));
+//! assert!(lei::is_valid(&child.attributes.lei));
+//! assert!(child.relationships.direct_parent.is_some());
+//! ```
+
+use crate::{
+    DEFAULT_BASE_URL,
+    error::{GleifError, ParseErrorKind, Result},
+    lei,
+    model::{
+        common::{RelatedLink, RelationshipLinks},
+        enums::{
+            CorroborationDocuments, CorroborationLevel, EntityCategory, EntityStatus,
+            RegistrationStatus, RelationshipPeriodType, RelationshipStatus, RelationshipType,
+        },
+        lei_record::{
+            Address, AssociatedEntity, Entity, Expiration, LegalForm, LeiRecord,
+            LeiRecordAttributes, Name, Registration, RegistrationAuthority, Relationships,
+            SuccessorEntity, ValidationAuthority,
+        },
+        relationship_record::{
+            RelationshipDetails, RelationshipExtension, RelationshipNode, RelationshipPeriod,
+            RelationshipRecord, RelationshipRecordAttributes, RelationshipRecordRelationships,
+            RelationshipRegistration,
+        },
+    },
+};
+use chrono::{DateTime, TimeZone, Utc};
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+};
+
+/// Characters allowed in the entity-specific part of an LEI.
+const ENTITY_ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Generator for synthetic LEI codes with valid check digits.
+///
+/// The entity-specific part is drawn from a small deterministic pseudo-random generator, so two
+/// generators created with the same prefix and seed always yield the same sequence of LEIs.
+#[derive(Debug, Clone)]
+pub struct LeiGenerator {
+    prefix: String,
+    state: u64,
+}
+
+impl LeiGenerator {
+    /// Create a generator for the given LOU prefix, seeded from the process' random state.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::GleifError::ParseError`] if `prefix` is not 4 upper-case alpha-numeric characters.
+    pub fn new(prefix: &str) -> Result<Self> {
+        Self::seeded(prefix, RandomState::new().build_hasher().finish())
+    }
+
+    /// Create a generator for the given LOU prefix with a fixed seed for reproducible output.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::GleifError::ParseError`] if `prefix` is not 4 upper-case alpha-numeric characters.
+    pub fn seeded(prefix: &str, seed: u64) -> Result<Self> {
+        if prefix.len() != lei::LOU_PREFIX_LENGTH || !is_lei_alphabet(prefix) {
+            return Err(GleifError::ParseError {
+                kind: ParseErrorKind::Value,
+                message: format!(
+                    "LOU prefix must be {} upper-case alpha-numeric characters",
+                    lei::LOU_PREFIX_LENGTH
+                ),
+            });
+        }
+        Ok(Self {
+            prefix: prefix.to_string(),
+            state: seed,
+        })
+    }
+
+    /// Returns the LOU prefix used by this generator.
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Generate the next LEI with a pseudo-random entity part.
+    pub fn generate(&mut self) -> String {
+        let entity_part: String = (0..lei::ENTITY_PART_LENGTH)
+            .map(|_| {
+                let index = usize::try_from(self.next_u64() % ENTITY_ALPHABET.len() as u64)
+                    .unwrap_or_default();
+                char::from(ENTITY_ALPHABET[index])
+            })
+            .collect();
+        // The prefix is validated on construction and the entity part only uses the LEI alphabet.
+        lei::from_parts(&self.prefix, &entity_part).unwrap_or_default()
+    }
+
+    /// Returns a [`LeiRecordBuilder`] for a freshly generated LEI, managed by this generator's LOU.
+    pub fn record_builder(&mut self) -> LeiRecordBuilder {
+        let lei = self.generate();
+        LeiRecordBuilder::new(lei).managing_lou(lou_lei(&self.prefix))
+    }
+
+    /// Private helper implementing `SplitMix64`.
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl Iterator for LeiGenerator {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.generate())
+    }
+}
+
+/// Builder for complete, internally consistent [`LeiRecord`] fixtures.
+///
+/// Every mandatory attribute is filled with a plausible default. Relationship links mirror what the
+/// GLEIF API returns: a reported parent yields `relationship-record` and `lei-record` links, while an
+/// unreported parent yields a `reporting-exception` link.
+#[derive(Debug, Clone)]
+pub struct LeiRecordBuilder {
+    lei: String,
+    legal_name: String,
+    country: String,
+    category: EntityCategory,
+    entity_status: EntityStatus,
+    registration_status: RegistrationStatus,
+    managing_lou: String,
+    direct_parent: Option<String>,
+    ultimate_parent: Option<String>,
+    has_children: bool,
+    registered_at: DateTime<Utc>,
+}
+
+impl LeiRecordBuilder {
+    /// Create a new builder for the given LEI with default attributes.
+    #[must_use]
+    pub fn new(lei: impl Into<String>) -> Self {
+        let lei = lei.into();
+        let managing_lou = lou_lei(lei.get(..lei::LOU_PREFIX_LENGTH).unwrap_or("0000"));
+        Self {
+            legal_name: format!("Test Entity {lei}"),
+            lei,
+            country: "US".to_string(),
+            category: EntityCategory::General,
+            entity_status: EntityStatus::Active,
+            registration_status: RegistrationStatus::Issued,
+            managing_lou,
+            direct_parent: None,
+            ultimate_parent: None,
+            has_children: false,
+            registered_at: Utc
+                .with_ymd_and_hms(2014, 1, 1, 0, 0, 0)
+                .single()
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Set the legal name of the entity.
+    #[must_use]
+    pub fn legal_name(mut self, name: impl Into<String>) -> Self {
+        self.legal_name = name.into();
+        self
+    }
+
+    /// Set the country (ISO 3166-1 alpha-2) used for the jurisdiction and both addresses.
+    #[must_use]
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.country = country.into();
+        self
+    }
+
+    /// Set the entity category.
+    #[must_use]
+    pub fn category(mut self, category: EntityCategory) -> Self {
+        self.category = category;
+        self
+    }
+
+    /// Set the entity status.
+    #[must_use]
+    pub fn entity_status(mut self, status: EntityStatus) -> Self {
+        self.entity_status = status;
+        self
+    }
+
+    /// Set the registration status.
+    #[must_use]
+    pub fn registration_status(mut self, status: RegistrationStatus) -> Self {
+        self.registration_status = status;
+        self
+    }
+
+    /// Set the LEI of the managing LOU.
+    #[must_use]
+    pub fn managing_lou(mut self, lou: impl Into<String>) -> Self {
+        self.managing_lou = lou.into();
+        self
+    }
+
+    /// Report a direct accounting consolidation parent.
+    #[must_use]
+    pub fn direct_parent(mut self, lei: impl Into<String>) -> Self {
+        self.direct_parent = Some(lei.into());
+        self
+    }
+
+    /// Report an ultimate accounting consolidation parent.
+    #[must_use]
+    pub fn ultimate_parent(mut self, lei: impl Into<String>) -> Self {
+        self.ultimate_parent = Some(lei.into());
+        self
+    }
+
+    /// Mark the entity as having direct and ultimate children, adding the corresponding links.
+    #[must_use]
+    pub fn with_children(mut self) -> Self {
+        self.has_children = true;
+        self
+    }
+
+    /// Set the initial registration date; the last update and renewal dates are derived from it.
+    #[must_use]
+    pub fn registered_at(mut self, date: DateTime<Utc>) -> Self {
+        self.registered_at = date;
+        self
+    }
+
+    /// Build the [`LeiRecord`].
+    #[must_use]
+    pub fn build(&self) -> LeiRecord {
+        LeiRecord {
+            r#type: "lei-records".to_string(),
+            id: self.lei.clone(),
+            attributes: LeiRecordAttributes {
+                lei: self.lei.clone(),
+                entity: self.entity(),
+                registration: self.registration(),
+                bic: None,
+                mic: None,
+                ocid: None,
+                qcc: None,
+                spglobal: None,
+                conformity_flag: None,
+            },
+            relationships: self.relationships(),
+        }
+    }
+
+    /// Build the Level 2 [`RelationshipRecord`]s matching the configured parents.
+    #[must_use]
+    pub fn build_relationships(&self) -> Vec<RelationshipRecord> {
+        let direct = self.direct_parent.as_deref().map(|parent| {
+            relationship_record(
+                &self.lei,
+                parent,
+                RelationshipType::IsDirectlyConsolidatedBy,
+            )
+        });
+        let ultimate = self.ultimate_parent.as_deref().map(|parent| {
+            relationship_record(
+                &self.lei,
+                parent,
+                RelationshipType::IsUltimatelyConsolidatedBy,
+            )
+        });
+        direct.into_iter().chain(ultimate).collect()
+    }
+
+    /// Private helper returning the entity-specific part, used as the register identifier.
+    fn entity_part(&self) -> String {
+        self.lei
+            .get(lei::LOU_PREFIX_LENGTH..)
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    /// Private helper building the entity block.
+    fn entity(&self) -> Entity {
+        let address = Address {
+            language: Some("en".to_string()),
+            address_lines: vec!["1 Test Street".to_string()],
+            address_number: None,
+            address_number_within_building: None,
+            mail_routing: None,
+            additional_address_line: None,
+            city: "Testville".to_string(),
+            region: None,
+            country: self.country.clone(),
+            postal_code: Some("00000".to_string()),
+        };
+        Entity {
+            legal_name: Name {
+                name: self.legal_name.clone(),
+                language: Some("en".to_string()),
+            },
+            other_names: Vec::new(),
+            transliterated_other_names: Vec::new(),
+            legal_address: address.clone(),
+            headquarters_address: address,
+            other_addresses: Vec::new(),
+            transliterated_other_addresses: None,
+            registered_at: RegistrationAuthority {
+                id: "RA999999".to_string(),
+                other: None,
+                entity_id: None,
+            },
+            registered_as: Some(self.entity_part()),
+            jurisdiction: self.country.clone(),
+            category: self.category.clone(),
+            sub_category: None,
+            legal_form: LegalForm {
+                id: "8888".to_string(),
+                other: None,
+            },
+            associated_entity: AssociatedEntity {
+                lei: None,
+                name: None,
+                r#type: None,
+            },
+            status: self.entity_status.clone(),
+            creation_date: Some(self.registered_at),
+            expiration: Expiration {
+                date: None,
+                reason: None,
+            },
+            successor_entity: SuccessorEntity {
+                lei: None,
+                name: None,
+            },
+            successor_entities: Vec::new(),
+            event_groups: Vec::new(),
+        }
+    }
+
+    /// Private helper building the registration block.
+    fn registration(&self) -> Registration {
+        Registration {
+            initial_registration_date: self.registered_at,
+            last_update_date: self.registered_at + chrono::Duration::days(180),
+            status: self.registration_status.clone(),
+            next_renewal_date: self.registered_at + chrono::Duration::days(365),
+            managing_lou: self.managing_lou.clone(),
+            corroboration_level: CorroborationLevel::FullyCorroborated,
+            validated_at: ValidationAuthority {
+                id: "RA999999".to_string(),
+                other: None,
+            },
+            validated_as: Some(self.entity_part()),
+            other_validation_authorities: Vec::new(),
+        }
+    }
+
+    /// Private helper building the relationship links.
+    fn relationships(&self) -> Relationships {
+        let base = format!("{DEFAULT_BASE_URL}lei-records/{}", self.lei);
+        let children = |level: &str| {
+            self.has_children.then(|| RelationshipLinks {
+                links: RelatedLink {
+                    relationship_records: Some(format!("{base}/{level}-child-relationships")),
+                    related: Some(format!("{base}/{level}-children")),
+                    ..empty_link()
+                },
+            })
+        };
+        Relationships {
+            managing_lou: related(format!("{base}/managing-lou")),
+            lei_issuer: related(format!("{base}/lei-issuer")),
+            field_modifications: related(format!("{base}/field-modifications")),
+            direct_parent: Some(parent_links(&base, "direct", self.direct_parent.is_some())),
+            ultimate_parent: Some(parent_links(
+                &base,
+                "ultimate",
+                self.ultimate_parent.is_some(),
+            )),
+            head_office: None,
+            direct_children: children("direct"),
+            ultimate_children: children("ultimate"),
+            successor_entity: None,
+            successor_entities: None,
+            isins: None,
+            fund_manager: None,
+            umbrella_fund: None,
+            managed_funds: None,
+            branches: None,
+        }
+    }
+}
+
+/// Build an active, published [`RelationshipRecord`] from `child` (start node) to `parent` (end node).
+#[must_use]
+pub fn relationship_record(
+    child: &str,
+    parent: &str,
+    relationship_type: RelationshipType,
+) -> RelationshipRecord {
+    let start = Utc
+        .with_ymd_and_hms(2014, 1, 1, 0, 0, 0)
+        .single()
+        .unwrap_or_default();
+    let type_name = serde_json::to_value(&relationship_type)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+    let node = |id: &str| RelationshipNode {
+        id: id.to_string(),
+        r#type: "LEI".to_string(),
+    };
+    RelationshipRecord {
+        r#type: "relationship-records".to_string(),
+        id: format!("{child}|LEI|{type_name}|{parent}"),
+        attributes: RelationshipRecordAttributes {
+            valid_from: start,
+            valid_to: None,
+            relationship: RelationshipDetails {
+                start_node: node(child),
+                end_node: node(parent),
+                r#type: relationship_type,
+                status: RelationshipStatus::Active,
+                periods: vec![RelationshipPeriod {
+                    start_date: start,
+                    end_date: None,
+                    r#type: RelationshipPeriodType::RelationshipPeriod,
+                }],
+                qualifiers: Vec::new(),
+                quantifiers: Vec::new(),
+            },
+            registration: RelationshipRegistration {
+                initial_registration_date: start,
+                last_update_date: Some(start),
+                status: RegistrationStatus::Published,
+                next_renewal_date: start + chrono::Duration::days(365),
+                managing_lou: lou_lei(child.get(..lei::LOU_PREFIX_LENGTH).unwrap_or("0000")),
+                corroboration_level: CorroborationLevel::FullyCorroborated,
+                corroboration_documents: CorroborationDocuments::AccountsFiling,
+                corroboration_reference: None,
+            },
+            extension: RelationshipExtension { deleted_at: None },
+        },
+        relationships: RelationshipRecordRelationships {
+            start_node: related(format!("{DEFAULT_BASE_URL}lei-records/{child}")),
+            end_node: related(format!("{DEFAULT_BASE_URL}lei-records/{parent}")),
+        },
+    }
+}
+
+/// Private helper returning the conventional LEI of the LOU owning `prefix` (all-zero entity part).
+fn lou_lei(prefix: &str) -> String {
+    lei::from_parts(prefix, &"0".repeat(lei::ENTITY_PART_LENGTH)).unwrap_or_default()
+}
+
+/// Private helper checking that `s` only contains characters from the LEI alphabet.
+fn is_lei_alphabet(s: &str) -> bool {
+    s.bytes().all(|b| ENTITY_ALPHABET.contains(&b))
+}
+
+/// Private helper returning a link object with no URLs set.
+fn empty_link() -> RelatedLink {
+    RelatedLink {
+        reporting_exception: None,
+        relationship_record: None,
+        relationship_records: None,
+        related: None,
+        lei_record: None,
+    }
+}
+
+/// Private helper returning relationship links with only a `related` URL.
+fn related(url: String) -> RelationshipLinks {
+    RelationshipLinks {
+        links: RelatedLink {
+            related: Some(url),
+            ..empty_link()
+        },
+    }
+}
+
+/// Private helper returning the parent links, depending on whether the parent is reported.
+fn parent_links(base: &str, level: &str, reported: bool) -> RelationshipLinks {
+    let links = if reported {
+        RelatedLink {
+            relationship_record: Some(format!("{base}/{level}-parent-relationship")),
+            lei_record: Some(format!("{base}/{level}-parent")),
+            ..empty_link()
+        }
+    } else {
+        RelatedLink {
+            reporting_exception: Some(format!("{base}/{level}-parent-reporting-exception")),
+            ..empty_link()
+        }
+    };
+    RelationshipLinks { links }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_leis_are_valid() {
+        let mut generator = LeiGenerator::new("5493").unwrap();
+        for lei in generator.by_ref().take(100) {
+            assert!(lei::is_valid(&lei), "{lei} should be valid");
+            assert!(lei.starts_with("5493"));
+        }
+        assert!(LeiGenerator::new("549").is_err());
+        assert!(LeiGenerator::new("54a3").is_err());
+    }
+
+    #[test]
+    fn test_seeded_generator_is_reproducible() {
+        let a: Vec<String> = LeiGenerator::seeded("2138", 7).unwrap().take(5).collect();
+        let b: Vec<String> = LeiGenerator::seeded("2138", 7).unwrap().take(5).collect();
+        let c: Vec<String> = LeiGenerator::seeded("2138", 8).unwrap().take(5).collect();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_record_builder_consistency() {
+        let mut generator = LeiGenerator::seeded("9845", 1).unwrap();
+        let parent = generator.generate();
+        let builder = generator
+            .record_builder()
+            .direct_parent(&parent)
+            .ultimate_parent(&parent);
+        let record = builder.build();
+        let lei = &record.attributes.lei;
+
+        assert_eq!(&record.id, lei);
+        assert!(lei::is_valid(&record.attributes.registration.managing_lou));
+        let direct = &record.relationships.direct_parent.as_ref().unwrap().links;
+        assert!(
+            direct
+                .lei_record
+                .as_ref()
+                .unwrap()
+                .ends_with("/direct-parent")
+        );
+        assert!(direct.reporting_exception.is_none());
+        assert!(record.relationships.direct_children.is_none());
+
+        let relationships = builder.build_relationships();
+        assert_eq!(relationships.len(), 2);
+        assert_eq!(relationships[0].attributes.relationship.start_node.id, *lei);
+        assert_eq!(relationships[0].attributes.relationship.end_node.id, parent);
+
+        // Round-trip through JSON to ensure the fixture matches the API model.
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(serde_json::from_str::<LeiRecord>(&json).unwrap(), record);
+    }
+
+    #[test]
+    fn test_record_builder_without_parents() {
+        let record = LeiRecordBuilder::new("5493001KJTIIGC8Y1R12")
+            .with_children()
+            .build();
+        let direct = &record.relationships.direct_parent.as_ref().unwrap().links;
+        assert!(direct.reporting_exception.is_some());
+        assert!(direct.lei_record.is_none());
+        assert!(record.relationships.direct_children.is_some());
+    }
+}
@@ -0,0 +1,425 @@
+//! # Corporate Event Timelines ([`Timeline`]) - Legal Entity Events in Chronological Order
+//!
+//! LEI records carry the legal entity events reported for an entity (name and address changes,
+//! mergers and acquisitions, liquidations, ...) as nested [`EventGroup`]s. This module flattens them
+//! into a single chronological [`Timeline`], classifies them into broad [`EventCategory`]s, links the
+//! `XPath`s of their affected fields to [`Field`]s, and can interleave them with the field modification
+//! history of the record.
+//!
+//! ## Example
+//!
+//! ```rust, ignore
+//! use gleif_rs::{
+//!     client::GleifClient,
+//!     model::LeiRecord,
+//!     timeline::{EventCategory, Timeline},
+//! };
+//!
+//! let client = GleifClient::new();
+//! let record: LeiRecord = client.lei_record_by_id("851WYGNLUQLFZBSYGB56").await?;
+//! let modifications = client
+//!     .field_modifications("851WYGNLUQLFZBSYGB56")
+//!     .page_size(200)
+//!     .send_all()
+//!     .await?;
+//! let timeline = Timeline::from_record(&record.data).with_modifications(modifications);
+//! for event in timeline.category(EventCategory::MergersAndAcquisitions) {
+//!     println!("{}: {:?}", event.event.effective_date, event.event.r#type);
+//! }
+//! for event in timeline.pending() {
+//!     println!("Pending: {:?}", event.event.r#type);
+//! }
+//! ```
+//!
+//! [`EventGroup`]: crate::model::lei_record::EventGroup
+
+use crate::{
+    field::Field,
+    model::{
+        enums::{EventStatus, EventType, GroupType},
+        field_modification::FieldModification,
+        lei_record::{AffectedFields, Event, LeiRecord},
+    },
+};
+use chrono::{DateTime, Utc};
+
+/// Broad classification of legal entity event types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EventCategory {
+    /// Changes of the legal name or of other names.
+    NameChange,
+    /// Changes of the legal or headquarters address.
+    AddressChange,
+    /// Changes of the legal form.
+    LegalFormChange,
+    /// Mergers, acquisitions, absorptions, demergers, spin-offs and break-ups.
+    MergersAndAcquisitions,
+    /// Transformations between branches, subsidiaries and standalone funds.
+    Transformation,
+    /// Bankruptcy, insolvency, liquidation, voluntary arrangements and dissolution.
+    Liquidation,
+}
+
+impl EventCategory {
+    /// Returns the category of an event type.
+    #[must_use]
+    pub fn of(event_type: &EventType) -> Self {
+        match event_type {
+            EventType::ChangeLegalName | EventType::ChangeOtherNames => EventCategory::NameChange,
+            EventType::ChangeLegalAddress | EventType::ChangeHqAddress => {
+                EventCategory::AddressChange
+            }
+            EventType::ChangeLegalForm => EventCategory::LegalFormChange,
+            EventType::Demerger
+            | EventType::Spinoff
+            | EventType::Absorption
+            | EventType::AcquisitionBranch
+            | EventType::Breakup
+            | EventType::MergersAndAcquisitions => EventCategory::MergersAndAcquisitions,
+            EventType::TransformationBranchToSubsidiary
+            | EventType::TransformationSubsidiaryToBranch
+            | EventType::TransformationUmbrellaToStandalone => EventCategory::Transformation,
+            EventType::Bankruptcy
+            | EventType::Liquidation
+            | EventType::VoluntaryArrangement
+            | EventType::Insolvency
+            | EventType::Dissolution => EventCategory::Liquidation,
+        }
+    }
+}
+
+/// A legal entity event together with the group it was reported in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimelineEvent {
+    /// The event.
+    pub event: Event,
+    /// The type of the group the event belongs to.
+    pub group_type: GroupType,
+    /// The position of the group among the event groups of the record, shared by all events of a
+    /// multi-event group.
+    pub group: usize,
+}
+
+impl TimelineEvent {
+    /// Returns the category of the event.
+    #[must_use]
+    pub fn category(&self) -> EventCategory {
+        EventCategory::of(&self.event.r#type)
+    }
+
+    /// Returns `true` if the event is still in progress.
+    #[must_use]
+    pub fn is_pending(&self) -> bool {
+        self.event.status == EventStatus::InProgress
+    }
+
+    /// Returns `true` if the event has been completed.
+    #[must_use]
+    pub fn is_completed(&self) -> bool {
+        self.event.status == EventStatus::Completed
+    }
+
+    /// Returns the fields expected to change as a result of the event, with the [`Field`] backing each
+    /// `XPath` where the field catalogue knows it.
+    pub fn affected_fields(&self) -> impl Iterator<Item = (&AffectedFields, Option<Field>)> {
+        self.event
+            .affected_fields
+            .iter()
+            .flatten()
+            .map(|affected| (affected, Field::from_xpath(&affected.xpath)))
+    }
+}
+
+/// An entry of a [`Timeline`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimelineEntry {
+    /// A legal entity event, dated by its effective date.
+    Event(TimelineEvent),
+    /// A field modification, dated by its modification date.
+    Modification(FieldModification),
+}
+
+impl TimelineEntry {
+    /// Returns the date the entry is ordered by.
+    #[must_use]
+    pub fn date(&self) -> DateTime<Utc> {
+        match self {
+            TimelineEntry::Event(event) => event.event.effective_date,
+            TimelineEntry::Modification(modification) => modification.attributes.date,
+        }
+    }
+
+    /// Returns the fields affected by the entry that are known to the field catalogue.
+    #[must_use]
+    pub fn fields(&self) -> Vec<Field> {
+        match self {
+            TimelineEntry::Event(event) => event
+                .affected_fields()
+                .filter_map(|(_, field)| field)
+                .collect(),
+            TimelineEntry::Modification(modification) => {
+                Field::from_xpath(&modification.attributes.field)
+                    .into_iter()
+                    .collect()
+            }
+        }
+    }
+}
+
+/// The legal entity events of a record, optionally interleaved with its field modifications, in
+/// chronological order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Timeline {
+    lei: String,
+    entries: Vec<TimelineEntry>,
+}
+
+impl Timeline {
+    /// Builds the timeline of the legal entity events reported in a record.
+    #[must_use]
+    pub fn from_record(record: &LeiRecord) -> Self {
+        let entries = record
+            .attributes
+            .entity
+            .event_groups
+            .iter()
+            .enumerate()
+            .flat_map(|(group, events)| {
+                events.events.iter().map(move |event| {
+                    TimelineEntry::Event(TimelineEvent {
+                        event: event.clone(),
+                        group_type: events.group_type.clone(),
+                        group,
+                    })
+                })
+            })
+            .collect();
+        let mut timeline = Self {
+            lei: record.attributes.lei.clone(),
+            entries,
+        };
+        timeline.sort();
+        timeline
+    }
+
+    /// Adds field modifications to the timeline.
+    ///
+    /// Modifications of other LEIs are ignored.
+    #[must_use]
+    pub fn with_modifications<I>(mut self, modifications: I) -> Self
+    where
+        I: IntoIterator<Item = FieldModification>,
+    {
+        self.entries.extend(
+            modifications
+                .into_iter()
+                .filter(|modification| modification.attributes.lei == self.lei)
+                .map(TimelineEntry::Modification),
+        );
+        self.sort();
+        self
+    }
+
+    /// Returns the LEI of the record the timeline belongs to.
+    #[must_use]
+    pub fn lei(&self) -> &str {
+        &self.lei
+    }
+
+    /// Orders the entries by date; events on the same date are ordered by their recorded date.
+    fn sort(&mut self) {
+        self.entries.sort_by_key(|entry| {
+            let recorded = match entry {
+                TimelineEntry::Event(event) => event.event.recorded_date,
+                TimelineEntry::Modification(modification) => modification.attributes.date,
+            };
+            (entry.date(), recorded)
+        });
+    }
+
+    /// Returns all entries, oldest first.
+    #[must_use]
+    pub fn entries(&self) -> &[TimelineEntry] {
+        &self.entries
+    }
+
+    /// Returns the number of entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the timeline has no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the legal entity events, oldest first.
+    pub fn events(&self) -> impl Iterator<Item = &TimelineEvent> {
+        self.entries.iter().filter_map(|entry| match entry {
+            TimelineEntry::Event(event) => Some(event),
+            TimelineEntry::Modification(_) => None,
+        })
+    }
+
+    /// Returns the field modifications, oldest first.
+    pub fn modifications(&self) -> impl Iterator<Item = &FieldModification> {
+        self.entries.iter().filter_map(|entry| match entry {
+            TimelineEntry::Modification(modification) => Some(modification),
+            TimelineEntry::Event(_) => None,
+        })
+    }
+
+    /// Returns the events still in progress.
+    pub fn pending(&self) -> impl Iterator<Item = &TimelineEvent> {
+        self.events().filter(|event| event.is_pending())
+    }
+
+    /// Returns the completed events.
+    pub fn completed(&self) -> impl Iterator<Item = &TimelineEvent> {
+        self.events().filter(|event| event.is_completed())
+    }
+
+    /// Returns the events of the given category.
+    pub fn category(&self, category: EventCategory) -> impl Iterator<Item = &TimelineEvent> {
+        self.events()
+            .filter(move |event| event.category() == category)
+    }
+
+    /// Returns the events grouped as reported, in the order of their earliest event.
+    #[must_use]
+    pub fn groups(&self) -> Vec<Vec<&TimelineEvent>> {
+        let mut groups: Vec<Vec<&TimelineEvent>> = Vec::new();
+        for event in self.events() {
+            match groups
+                .iter_mut()
+                .find(|group| group[0].group == event.group)
+            {
+                Some(group) => group.push(event),
+                None => groups.push(vec![event]),
+            }
+        }
+        groups
+    }
+
+    /// Returns the entries dated within the given range, both ends included.
+    pub fn between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> impl Iterator<Item = &TimelineEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| (from..=to).contains(&entry.date()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::read;
+    use chrono::TimeZone;
+
+    /// Reads the children of a sample entity that reports a legal and a headquarters address change.
+    fn record_with_events() -> LeiRecord {
+        let children: Vec<LeiRecord> =
+            read("tests/data/lei_records/direct-children_529900GRZ2BQY5ZM9N49.json");
+        children
+            .into_iter()
+            .find(|child| child.attributes.lei == "724500HW9I3CIW5B8N93")
+            .expect("Missing sample record")
+    }
+
+    #[test]
+    fn test_timeline_from_record() {
+        let timeline = Timeline::from_record(&record_with_events());
+        assert_eq!(timeline.len(), 4);
+        assert!(
+            timeline
+                .entries()
+                .windows(2)
+                .all(|pair| pair[0].date() <= pair[1].date())
+        );
+        assert_eq!(timeline.completed().count(), 4);
+        assert_eq!(timeline.pending().count(), 0);
+        assert_eq!(timeline.category(EventCategory::AddressChange).count(), 4);
+        assert_eq!(timeline.category(EventCategory::NameChange).count(), 0);
+        assert_eq!(timeline.groups().len(), 4);
+        let from = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        assert_eq!(timeline.between(from, to).count(), 2);
+    }
+
+    #[test]
+    fn test_timeline_with_modifications() {
+        let lei = "851WYGNLUQLFZBSYGB56";
+        let record: LeiRecord = read(&format!(
+            "tests/data/lei_records/single_lei_record_{lei}.json"
+        ));
+        let modifications: Vec<FieldModification> = read(&format!(
+            "tests/data/field_modifications/field_modifications_{lei}.json"
+        ));
+        let count = modifications.len();
+        let mut other = modifications[0].clone();
+        other.attributes.lei = "529900GRZ2BQY5ZM9N49".to_string();
+
+        let timeline = Timeline::from_record(&record)
+            .with_modifications(modifications)
+            .with_modifications([other]);
+        assert_eq!(timeline.lei(), lei);
+        assert_eq!(timeline.len(), count + 1);
+        assert_eq!(timeline.modifications().count(), count);
+        assert_eq!(
+            timeline
+                .category(EventCategory::MergersAndAcquisitions)
+                .count(),
+            1
+        );
+        assert!(
+            timeline
+                .entries()
+                .windows(2)
+                .all(|pair| pair[0].date() <= pair[1].date())
+        );
+        assert!(
+            timeline
+                .modifications()
+                .all(|modification| modification.attributes.lei == lei)
+        );
+    }
+
+    #[test]
+    fn test_affected_fields() {
+        let mut record = record_with_events();
+        let event = &mut record.attributes.entity.event_groups[0].events[0];
+        event.r#type = EventType::ChangeLegalName;
+        event.status = EventStatus::InProgress;
+        event.affected_fields = Some(vec![
+            AffectedFields {
+                value: "New Name B.V.".to_string(),
+                xpath: "/lei:LEIData/lei:LEIRecords/lei:LEIRecord/lei:Entity/lei:LegalName"
+                    .to_string(),
+            },
+            AffectedFields {
+                value: "Other".to_string(),
+                xpath: "/lei:Unknown".to_string(),
+            },
+        ]);
+        let timeline = Timeline::from_record(&record);
+        let pending: Vec<_> = timeline.pending().collect();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].category(), EventCategory::NameChange);
+        let fields: Vec<_> = pending[0]
+            .affected_fields()
+            .map(|(_, field)| field)
+            .collect();
+        assert_eq!(fields, [Some(Field::EntityLegalName), None]);
+        let entry = timeline
+            .entries()
+            .iter()
+            .find(|entry| matches!(entry, TimelineEntry::Event(event) if event.is_pending()))
+            .unwrap();
+        assert_eq!(entry.fields(), [Field::EntityLegalName]);
+    }
+}
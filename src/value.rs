@@ -66,11 +66,26 @@
 //! assert_eq!(flag, ConformityFlag::NonConforming);
 //! ```
 //!
+//! ## 3. Reading Field Values from Records
+//!
+//! [`FieldValue`] is the dynamically typed value returned when reading a [`Field`](crate::field::Field)
+//! from a deserialized record, e.g. via [`LeiRecord::get`](crate::model::lei_record::LeiRecord::get).
+//!
+//! ```rust
+//! use gleif_rs::value::FieldValue;
+//!
+//! let value = FieldValue::List(vec!["DEUTDEFFXXX".to_string(), "DEUTDEFF".to_string()]);
+//! assert_eq!(value.to_string(), "DEUTDEFFXXX,DEUTDEFF");
+//! assert!(value.contains("DEUTDEFF"));
+//! ```
+//!
 //! By utilizing the enums in this module, you enhance the robustness and clarity
 //! of your interactions with the GLEIF API, catching potential errors related to field values
 //! at compile time or through controlled parsing.
 
 use crate::error::{GleifError, ParseErrorKind};
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Serialize;
 use std::{fmt, str::FromStr};
 
 // Re-exporting the enums for external use
@@ -222,6 +237,122 @@ impl FromStr for ConformityFlag {
     }
 }
 
+/// A value read from a record via a [`Field`](crate::field::Field).
+///
+/// Scalar text (including enum values in their canonical API representation) is returned as
+/// [`FieldValue::Text`], timestamps as [`FieldValue::Date`], and multi-valued fields such as BICs or
+/// other names as [`FieldValue::List`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FieldValue {
+    /// A single text value.
+    Text(String),
+    /// A date/time value.
+    Date(DateTime<Utc>),
+    /// A list of text values.
+    List(Vec<String>),
+}
+
+impl FieldValue {
+    /// Returns the value as a string slice if it is [`FieldValue::Text`].
+    #[must_use]
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            FieldValue::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a date/time if it is [`FieldValue::Date`].
+    #[must_use]
+    pub const fn as_date(&self) -> Option<&DateTime<Utc>> {
+        match self {
+            FieldValue::Date(date) => Some(date),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a slice of strings if it is [`FieldValue::List`].
+    #[must_use]
+    pub fn as_list(&self) -> Option<&[String]> {
+        match self {
+            FieldValue::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the value equals `needle`, or, for lists, if any element equals `needle`.
+    ///
+    /// Dates are compared using their RFC 3339 representation.
+    #[must_use]
+    pub fn contains(&self, needle: &str) -> bool {
+        match self {
+            FieldValue::Text(text) => text == needle,
+            FieldValue::Date(_) => self.to_string() == needle,
+            FieldValue::List(list) => list.iter().any(|item| item == needle),
+        }
+    }
+
+    /// Private helper turning an optional string into a text value.
+    pub(crate) fn text(value: Option<&String>) -> Option<Self> {
+        value.map(|text| FieldValue::Text(text.clone()))
+    }
+
+    /// Private helper turning a list of strings into a list value, omitting empty lists.
+    pub(crate) fn list<I, S>(values: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let list: Vec<String> = values.into_iter().map(Into::into).collect();
+        (!list.is_empty()).then_some(FieldValue::List(list))
+    }
+
+    /// Private helper turning a serde enum into its canonical API string.
+    pub(crate) fn variant<T: Serialize>(value: &T) -> Option<Self> {
+        match serde_json::to_value(value) {
+            Ok(serde_json::Value::String(text)) => Some(FieldValue::Text(text)),
+            _ => None,
+        }
+    }
+}
+
+// Dates use RFC 3339 and lists are comma-separated, matching how the API accepts multiple filter values.
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldValue::Text(text) => f.write_str(text),
+            FieldValue::Date(date) => {
+                f.write_str(&date.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+            }
+            FieldValue::List(list) => f.write_str(&list.join(",")),
+        }
+    }
+}
+
+impl From<String> for FieldValue {
+    fn from(value: String) -> Self {
+        FieldValue::Text(value)
+    }
+}
+
+impl From<&str> for FieldValue {
+    fn from(value: &str) -> Self {
+        FieldValue::Text(value.to_string())
+    }
+}
+
+impl From<DateTime<Utc>> for FieldValue {
+    fn from(value: DateTime<Utc>) -> Self {
+        FieldValue::Date(value)
+    }
+}
+
+impl From<Vec<String>> for FieldValue {
+    fn from(value: Vec<String>) -> Self {
+        FieldValue::List(value)
+    }
+}
+
 #[cfg(test)]
 mod display_tests {
     use super::*;
@@ -252,4 +383,28 @@ mod display_tests {
         assert_eq!(flag.as_str(), "NON_CONFORMING");
         assert_eq!(ConformityFlag::from_str("NON_CONFORMING").unwrap(), flag);
     }
+
+    #[test]
+    fn field_value_display_and_accessors() {
+        let text = FieldValue::from("ISSUED");
+        assert_eq!(text.as_text(), Some("ISSUED"));
+        assert!(text.contains("ISSUED"));
+
+        let date = FieldValue::from(
+            DateTime::parse_from_rfc3339("2012-06-06T15:53:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        );
+        assert_eq!(date.to_string(), "2012-06-06T15:53:00Z");
+        assert!(date.as_date().is_some());
+
+        let list = FieldValue::from(vec!["A".to_string(), "B".to_string()]);
+        assert_eq!(list.to_string(), "A,B");
+        assert!(list.contains("B"));
+        assert!(!list.contains("C"));
+        assert_eq!(
+            FieldValue::variant(&EntityCategory::Fund),
+            Some(FieldValue::from("FUND"))
+        );
+    }
 }
@@ -0,0 +1,387 @@
+//! Generator for the field catalogue in `src/field/catalog.rs`.
+//!
+//! The catalogue is built from a saved response of the GLEIF `fields` endpoint. Entries are sorted by
+//! field name and followed by the relationship record filters, which the endpoint does not list. The
+//! output is emitted in `rustfmt` layout so that the generated file passes `cargo fmt --check` as is.
+
+use gleif_rs::{
+    error::Result,
+    field::{Operator, ValueKind},
+    model::FieldList,
+};
+use std::{fmt::Write, fs, path::Path, str::FromStr};
+
+/// Location of the saved `fields` endpoint response, relative to the project root.
+pub const FIELDS_PATH: &str = "tests/data/fields/fields_all.json";
+
+/// Location of the generated catalogue, relative to the project root.
+pub const CATALOG_PATH: &str = "src/field/catalog.rs";
+
+/// Maximum line width used by `rustfmt`.
+const MAX_WIDTH: usize = 100;
+
+/// Maximum width of an array literal that `rustfmt` keeps on a single line.
+const ARRAY_WIDTH: usize = 60;
+
+/// Variant names that do not follow the default naming scheme.
+const VARIANT_OVERRIDES: &[(&str, &str)] = &[
+    ("entity.registeredAs", "EntityBusinessRegisterNumber"),
+    (
+        "entity.headquartersAddress.addresslines",
+        "EntityHqAddressAddressLines",
+    ),
+];
+
+/// Variant name prefixes that are shortened.
+const VARIANT_PREFIXES: &[(&str, &str)] = &[("EntityHeadquartersAddress", "EntityHqAddress")];
+
+/// Operators supported by date and number fields.
+const COMPARABLE_OPERATORS: &[&str] = &[
+    "MATCH",
+    "NOT_MATCH",
+    "GREATER_THAN",
+    "GREATER_THAN_OR_EQUAL",
+    "LESS_THAN",
+    "LESS_THAN_OR_EQUAL",
+    "IN_RANGE",
+];
+
+/// Operators supported by enumerated relationship fields.
+const SET_OPERATORS: &[&str] = &["MATCH", "NOT_MATCH", "IN"];
+
+/// Fields accepted by the API that are not part of the `fields` endpoint.
+const EXTRA_FIELDS: &[ExtraField] = &[
+    ExtraField {
+        name: "entity.legalForm.id",
+        label: "Entity Legal Form ID",
+        kind: "RESOURCE",
+        operators: &[],
+        enum_values: &[],
+    },
+    ExtraField {
+        name: "relationship.startDate",
+        label: "Relationship Start Date",
+        kind: "DATE",
+        operators: COMPARABLE_OPERATORS,
+        enum_values: &[],
+    },
+    ExtraField {
+        name: "relationship.endDate",
+        label: "Relationship End Date",
+        kind: "DATE",
+        operators: COMPARABLE_OPERATORS,
+        enum_values: &[],
+    },
+    ExtraField {
+        name: "relationship.status",
+        label: "Relationship Status",
+        kind: "ENUM",
+        operators: SET_OPERATORS,
+        enum_values: &["ACTIVE", "INACTIVE", "NULL"],
+    },
+    ExtraField {
+        name: "relationship.type",
+        label: "Relationship Type",
+        kind: "ENUM",
+        operators: SET_OPERATORS,
+        enum_values: &[
+            "IS_DIRECTLY_CONSOLIDATED_BY",
+            "IS_ULTIMATELY_CONSOLIDATED_BY",
+            "IS_INTERNATIONAL_BRANCH_OF",
+            "IS_FUND-MANAGED_BY",
+            "IS_SUBFUND_OF",
+            "IS_FEEDER_TO",
+        ],
+    },
+];
+
+/// A field that is hand-maintained because the `fields` endpoint does not report it.
+///
+/// Extra fields are always sortable.
+struct ExtraField {
+    name: &'static str,
+    label: &'static str,
+    kind: &'static str,
+    operators: &'static [&'static str],
+    enum_values: &'static [&'static str],
+}
+
+/// A validated catalogue entry, ready to be emitted.
+struct Entry {
+    name: String,
+    label: String,
+    xpath: String,
+    kind: ValueKind,
+    sortable: bool,
+    operators: Vec<Operator>,
+    enum_values: Vec<String>,
+}
+
+impl Entry {
+    /// Creates an entry, validating the value kind and operators against the library enums.
+    fn new<S: AsRef<str>>(
+        name: &str,
+        label: &str,
+        xpath: &str,
+        kind: &str,
+        sortable: bool,
+        operators: &[S],
+        enum_values: &[S],
+    ) -> Result<Self> {
+        Ok(Self {
+            name: name.to_owned(),
+            label: label.to_owned(),
+            xpath: xpath.to_owned(),
+            kind: ValueKind::from_str(kind)?,
+            sortable,
+            operators: operators
+                .iter()
+                .map(|op| Operator::from_str(op.as_ref()))
+                .collect::<Result<_>>()?,
+            enum_values: enum_values.iter().map(|v| v.as_ref().to_owned()).collect(),
+        })
+    }
+
+    /// Name of the `Field` variant for this entry.
+    fn variant(&self) -> String {
+        if let Some((_, variant)) = VARIANT_OVERRIDES
+            .iter()
+            .find(|(name, _)| *name == self.name)
+        {
+            return (*variant).to_owned();
+        }
+        let variant: String = self.name.split('.').map(capitalize).collect();
+        VARIANT_PREFIXES
+            .iter()
+            .find_map(|(long, short)| {
+                variant
+                    .strip_prefix(long)
+                    .map(|rest| format!("{short}{rest}"))
+            })
+            .unwrap_or(variant)
+    }
+
+    /// Doc comment of the `Field` variant for this entry.
+    fn doc(&self) -> String {
+        if self.label.is_empty() {
+            format!("/// `{}`.", self.name)
+        } else {
+            format!("/// {} (`{}`).", self.label, self.name)
+        }
+    }
+
+    /// Catalogue entry of this field, with all strings written as escaped Rust literals.
+    fn info(&self) -> String {
+        let operators: Vec<String> = self
+            .operators
+            .iter()
+            .map(|op| format!("Operator::{op:?}"))
+            .collect();
+        let enum_values: Vec<String> = self
+            .enum_values
+            .iter()
+            .map(|value| format!("{value:?}"))
+            .collect();
+        let mut out = String::from("    FieldInfo {\n");
+        let _ = writeln!(out, "        name: {:?},", self.name);
+        let _ = writeln!(out, "        label: {:?},", self.label);
+        let _ = writeln!(out, "        xpath: {:?},", self.xpath);
+        let _ = writeln!(out, "        kind: ValueKind::{:?},", self.kind);
+        let _ = writeln!(out, "        sortable: {},", self.sortable);
+        write_slice(&mut out, 8, "operators", &operators);
+        write_slice(&mut out, 8, "enum_values", &enum_values);
+        out.push_str("    },\n");
+        out
+    }
+}
+
+/// Upper-cases the first character of a name segment.
+fn capitalize(segment: &str) -> String {
+    let mut chars = segment.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// Reads the `fields` endpoint response and returns the catalogue entries followed by the extras.
+fn load_entries(fields_path: &Path) -> Result<Vec<Entry>> {
+    let list: FieldList = serde_json::from_str(&fs::read_to_string(fields_path)?)?;
+    let mut attributes: Vec<_> = list
+        .data
+        .iter()
+        .map(|field| &field.attributes)
+        .filter(|attributes| !attributes.field.is_empty())
+        .collect();
+    attributes.sort_by(|a, b| a.field.cmp(&b.field));
+
+    let mut entries = attributes
+        .into_iter()
+        .map(|a| {
+            Entry::new(
+                &a.field,
+                &a.label,
+                a.xpath.as_deref().unwrap_or_default(),
+                &a.data_type,
+                a.sortable,
+                a.operators.as_deref().unwrap_or_default(),
+                a.enum_values.as_deref().unwrap_or_default(),
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+    for extra in EXTRA_FIELDS {
+        entries.push(Entry::new(
+            extra.name,
+            extra.label,
+            "",
+            extra.kind,
+            true,
+            extra.operators,
+            extra.enum_values,
+        )?);
+    }
+    Ok(entries)
+}
+
+/// Writes `key: &[items],` at the given indent, wrapping one item per line like `rustfmt` does.
+fn write_slice(out: &mut String, indent: usize, key: &str, items: &[String]) {
+    let pad = " ".repeat(indent);
+    let array = format!("[{}]", items.join(", "));
+    if array.len() <= ARRAY_WIDTH && indent + key.len() + array.len() + 4 <= MAX_WIDTH {
+        let _ = writeln!(out, "{pad}{key}: &{array},");
+        return;
+    }
+    let _ = writeln!(out, "{pad}{key}: &[");
+    for item in items {
+        let _ = writeln!(out, "{pad}    {item},");
+    }
+    let _ = writeln!(out, "{pad}],");
+}
+
+/// Generates the contents of `src/field/catalog.rs` from the given `fields` endpoint response.
+///
+/// # Errors
+///
+/// Returns an error if the response cannot be read or deserialized, or if it contains a value kind or
+/// operator unknown to the library.
+pub fn generate(fields_path: &Path) -> Result<String> {
+    let entries = load_entries(fields_path)?;
+    let mut out = String::new();
+
+    out.push_str(
+        "//! Field catalogue of the GLEIF API, derived from the `fields` endpoint.
+//!
+//! The variants, names and metadata in this file mirror `tests/data/fields/fields_all.json`, followed by
+//! the relationship record filters, which are not part of the catalogue.
+//!
+//! This file is generated by `cargo xtask codegen`. Do not edit it by hand.
+
+use super::{Operator, ValueKind};
+
+/// Enum for known GLEIF API field names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Field {
+",
+    );
+    let catalogue_len = entries.len() - EXTRA_FIELDS.len();
+    for (i, entry) in entries.iter().enumerate() {
+        if i == catalogue_len {
+            out.push_str(
+                "    // Relationship record filters and aliases (not part of the field catalogue)\n",
+            );
+        }
+        let _ = writeln!(out, "    {}", entry.doc());
+        let _ = writeln!(out, "    {},", entry.variant());
+    }
+    out.push_str(
+        "}
+
+/// Private catalogue entry describing a [`Field`].
+pub(super) struct FieldInfo {
+    pub(super) name: &'static str,
+    pub(super) label: &'static str,
+    pub(super) xpath: &'static str,
+    pub(super) kind: ValueKind,
+    pub(super) sortable: bool,
+    pub(super) operators: &'static [Operator],
+    pub(super) enum_values: &'static [&'static str],
+}
+
+/// Private catalogue of all fields, indexed by the [`Field`] discriminant.
+pub(super) const CATALOG: &[FieldInfo] = &[
+",
+    );
+    for entry in &entries {
+        out.push_str(&entry.info());
+    }
+    out.push_str(
+        "];
+
+impl Field {
+    /// All known fields, in declaration order.
+    pub const ALL: &'static [Field] = &[
+",
+    );
+    for entry in &entries {
+        let _ = writeln!(out, "        Field::{},", entry.variant());
+    }
+    out.push_str("    ];\n}\n");
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project_root;
+
+    #[test]
+    fn test_catalog_is_up_to_date() {
+        let root = project_root();
+        let generated = generate(&root.join(FIELDS_PATH)).expect("Failed to generate catalogue");
+        let current =
+            fs::read_to_string(root.join(CATALOG_PATH)).expect("Failed to read catalogue");
+        assert!(
+            current == generated,
+            "{CATALOG_PATH} is stale, run `cargo xtask codegen` to regenerate it"
+        );
+    }
+
+    #[test]
+    fn test_variant_names() {
+        let entry = |name: &str| {
+            Entry::new::<&str>(name, "", "", "STRING", false, &[], &[]).expect("Invalid entry")
+        };
+        assert_eq!(entry("entity.legalName").variant(), "EntityLegalName");
+        assert_eq!(
+            entry("entity.headquartersAddress.city").variant(),
+            "EntityHqAddressCity"
+        );
+        assert_eq!(
+            entry("entity.registeredAs").variant(),
+            "EntityBusinessRegisterNumber"
+        );
+        assert_eq!(entry("entity.legalName").doc(), "/// `entity.legalName`.");
+    }
+
+    #[test]
+    fn test_info_escapes_strings() {
+        let entry = Entry::new::<&str>(
+            "relationship.qualifiers",
+            "Qualifier \"Dimension\"",
+            "/rr:Qualifier[rr:QualifierDimension=\"ACCOUNTING_STANDARD\"]",
+            "STRING",
+            false,
+            &[],
+            &["A\\B"],
+        )
+        .expect("Invalid entry");
+        let info = entry.info();
+        assert!(info.contains(r#"label: "Qualifier \"Dimension\"","#));
+        assert!(
+            info.contains(
+                r#"xpath: "/rr:Qualifier[rr:QualifierDimension=\"ACCOUNTING_STANDARD\"]","#
+            )
+        );
+        assert!(info.contains(r#"enum_values: &["A\\B"],"#));
+    }
+}
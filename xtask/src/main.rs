@@ -0,0 +1,65 @@
+//! Development tasks for `gleif-rs`.
+//!
+//! Run with `cargo xtask <task>` from anywhere inside the repository.
+//!
+//! ## Tasks
+//!
+//! - `codegen`: Regenerate `src/field/catalog.rs` from `tests/data/fields/fields_all.json`.
+//! - `codegen --check`: Fail if the checked-in catalogue differs from the generated one.
+
+mod codegen;
+
+use gleif_rs::error::{GleifError, ParseErrorKind, Result};
+use std::{env, fs, path::PathBuf, process::ExitCode};
+
+/// Root directory of the `gleif-rs` package, i.e. the parent of the `xtask` directory.
+fn project_root() -> PathBuf {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir
+        .parent()
+        .map_or(manifest_dir.clone(), PathBuf::from)
+}
+
+/// Regenerates the field catalogue, or only compares it when `check` is set.
+fn codegen(check: bool) -> Result<()> {
+    let root = project_root();
+    let target = root.join(codegen::CATALOG_PATH);
+    let generated = codegen::generate(&root.join(codegen::FIELDS_PATH))?;
+    let current = fs::read_to_string(&target).unwrap_or_default();
+    if current == generated {
+        println!("{} is up to date", codegen::CATALOG_PATH);
+        return Ok(());
+    }
+    if check {
+        return Err(GleifError::ParseError {
+            kind: ParseErrorKind::Value,
+            message: format!(
+                "{} is stale, run `cargo xtask codegen` to regenerate it",
+                codegen::CATALOG_PATH
+            ),
+        });
+    }
+    fs::write(&target, generated)?;
+    println!("Regenerated {}", codegen::CATALOG_PATH);
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args.as_slice() {
+        ["codegen"] => codegen(false),
+        ["codegen", "--check"] => codegen(true),
+        _ => {
+            eprintln!("Usage: cargo xtask codegen [--check]");
+            return ExitCode::FAILURE;
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}